@@ -0,0 +1,131 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::{ConvertFormat, CperSeverity};
+use intel_crashlog::cper::CperBuilder;
+use intel_crashlog::cper::header::ErrorSeverity;
+use intel_crashlog::metadata::Time;
+use intel_crashlog::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// Parses a timestamp formatted as `YYYY-MM-DDTHH:MM` (or `YYYY-MM-DD HH:MM`).
+pub(crate) fn parse_timestamp(s: &str) -> Result<Time, String> {
+    let invalid = || format!("invalid timestamp `{s}`: expected YYYY-MM-DDTHH:MM");
+    let (date, time) = s.split_once(['T', ' ']).ok_or_else(invalid)?;
+    let mut date = date.splitn(3, '-');
+    let mut time = time.splitn(2, ':');
+    let next = |part: Option<&str>| part.and_then(|part| part.parse().ok()).ok_or_else(invalid);
+
+    let time = Time {
+        year: date
+            .next()
+            .and_then(|year| year.parse().ok())
+            .ok_or_else(invalid)?,
+        month: next(date.next())?,
+        day: next(date.next())?,
+        hour: next(time.next())?,
+        minute: next(time.next())?,
+    };
+
+    let leap_year = time.year.is_multiple_of(4)
+        && (!time.year.is_multiple_of(100) || time.year.is_multiple_of(400));
+    let days = match time.month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap_year => 29,
+        2 => 28,
+        _ => return Err(format!("invalid timestamp `{s}`: month out of range")),
+    };
+    if !(1..=days).contains(&time.day) {
+        return Err(format!("invalid timestamp `{s}`: day out of range"));
+    }
+    if time.hour > 23 || time.minute > 59 {
+        return Err(format!("invalid timestamp `{s}`: time out of range"));
+    }
+    Ok(time)
+}
+
+fn cper<T: CollateralTree>(
+    cm: &mut CollateralManager<T>,
+    crashlog: &CrashLog,
+    severity: CperSeverity,
+) -> Vec<u8> {
+    let severity = match severity {
+        CperSeverity::Auto => crashlog.severity(cm).into(),
+        CperSeverity::Fatal => ErrorSeverity::Fatal,
+        CperSeverity::Recoverable => ErrorSeverity::Recoverable,
+        CperSeverity::Corrected => ErrorSeverity::Corrected,
+        CperSeverity::Informational => ErrorSeverity::Informational,
+    };
+    CperBuilder::new()
+        .severity(severity)
+        .crashlog(crashlog)
+        .build()
+        .to_bytes()
+}
+
+/// Returns the usual extension of the files stored in the given format.
+fn extension(format: ConvertFormat) -> &'static str {
+    match format {
+        ConvertFormat::Cper => "cper",
+        ConvertFormat::Bert => "bert",
+        ConvertFormat::Raw => "bin",
+    }
+}
+
+/// Writes the Crash Log into a file using the given format.
+pub(crate) fn write<T: CollateralTree>(
+    cm: &mut CollateralManager<T>,
+    crashlog: &CrashLog,
+    path: &Path,
+    format: ConvertFormat,
+    severity: CperSeverity,
+) -> Result<(), Error> {
+    let bytes = match format {
+        ConvertFormat::Cper => cper(cm, crashlog, severity),
+        ConvertFormat::Bert => crashlog.to_bert(),
+        ConvertFormat::Raw => crashlog
+            .regions
+            .iter()
+            .flat_map(|region| region.to_bytes())
+            .collect(),
+    };
+
+    println!("{}", path.display());
+    Ok(
+        std::fs::write(path, bytes)
+            .inspect_err(|err| log::error!("Failed to write file: {err}"))?,
+    )
+}
+
+pub fn convert<T: CollateralTree>(
+    cm: &mut CollateralManager<T>,
+    input_file: &Path,
+    output_file: Option<&Path>,
+    to: ConvertFormat,
+    severity: CperSeverity,
+    timestamp: Option<&Time>,
+) -> Result<(), Error> {
+    let mut crashlog = CrashLog::from_slice(&std::fs::read(input_file)?)?;
+    if let Some(timestamp) = timestamp {
+        crashlog.metadata.time = Some(timestamp.clone());
+    }
+
+    let path = match output_file {
+        Some(output_file) => PathBuf::from(output_file),
+        None => {
+            let path = input_file.with_extension(extension(to));
+            if path == input_file {
+                input_file.with_extension(format!("converted.{}", extension(to)))
+            } else {
+                path
+            }
+        }
+    };
+
+    if path.exists() && std::fs::canonicalize(&path)? == std::fs::canonicalize(input_file)? {
+        log::error!("The output file cannot be the input file");
+        return Err(std::io::Error::from(std::io::ErrorKind::AlreadyExists).into());
+    }
+    write(cm, &crashlog, &path, to, severity)
+}
@@ -1,15 +1,118 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use intel_crashlog::compression::{self, Format};
+use intel_crashlog::cper::Cper;
+use intel_crashlog::node::ChildOrder;
 use intel_crashlog::prelude::*;
+use serde::Serialize;
 use std::path::Path;
 
+use crate::OutputFormat;
+use crate::tree::{self, TreeWriter};
+
+fn write_output<O: std::io::Write, V: Serialize>(
+    output: O,
+    value: &V,
+    format: OutputFormat,
+) -> Result<(), Error> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_writer_pretty(output, value)?),
+        OutputFormat::Yaml => Ok(serde_yaml_ng::to_writer(output, value)?),
+        OutputFormat::Tree | OutputFormat::Csv => {
+            unreachable!("the tree and CSV formats are not serializable")
+        }
+    }
+}
+
+/// Options controlling the content and the format of the decoded output
+pub(crate) struct DecodeOutput<'a> {
+    /// Paths of the sub-trees to output, all of them if empty
+    pub selected: &'a [String],
+    /// Paths of the sub-trees removed from the output
+    pub excluded: &'a [String],
+    pub descriptions: bool,
+    /// Lists the children in the order of the decode definitions instead of alphabetically
+    pub definition_order: bool,
+    pub format: OutputFormat,
+}
+
 pub fn decode<T: CollateralTree, O: std::io::Write>(
     cm: &mut CollateralManager<T>,
     input: &Path,
-    output: O,
+    options: &DecodeOutput,
+    mut output: O,
 ) -> Result<(), Error> {
     let crashlog = CrashLog::from_slice(&std::fs::read(input)?)?;
-    let nodes = crashlog.decode(cm);
-    Ok(serde_json::to_writer_pretty(output, &nodes)?)
+    let mut nodes = crashlog.decode(cm);
+    log::debug!("Decode definition cache: {}", cm.cache_stats());
+    if !options.selected.is_empty() {
+        nodes.retain(options.selected);
+    }
+    nodes.prune(|path, _| options.excluded.iter().any(|excluded| excluded == path));
+
+    let format = options.format;
+    if format == OutputFormat::Tree {
+        let writer = TreeWriter {
+            descriptions: options.descriptions,
+            colors: tree::use_colors(),
+        };
+        return writer.write(output, &nodes);
+    }
+    if format == OutputFormat::Csv {
+        return Ok(output.write_all(nodes.to_flat_csv().as_bytes())?);
+    }
+
+    let order = if options.definition_order {
+        ChildOrder::Insertion
+    } else {
+        ChildOrder::Sorted
+    };
+    if options.descriptions {
+        write_output(output, &nodes.with_descriptions().with_order(order), format)
+    } else {
+        write_output(output, &nodes.with_order(order), format)
+    }
+}
+
+pub fn decode_cper<O: std::io::Write>(
+    input: &Path,
+    format: OutputFormat,
+    mut output: O,
+) -> Result<(), Error> {
+    let bytes = std::fs::read(input)?;
+    let cpers = if Format::detect(&bytes).is_some() {
+        compression::decompress(&bytes)?
+            .iter()
+            .flat_map(|file| Cper::find_all(file))
+            .collect()
+    } else {
+        Cper::find_all(&bytes)
+    };
+    if cpers.is_empty() {
+        log::warn!("No CPER record found in {}", input.display());
+    }
+    let nodes: Vec<Node> = cpers.iter().map(Cper::decode).collect();
+    if format == OutputFormat::Tree {
+        let writer = TreeWriter {
+            descriptions: false,
+            colors: tree::use_colors(),
+        };
+        for node in nodes.iter() {
+            writer.write(&mut output, node)?;
+        }
+        return Ok(());
+    }
+    if format == OutputFormat::Csv {
+        // The records are listed under a section per CPER record.
+        let mut root = Node::root();
+        for (i, node) in nodes.into_iter().enumerate() {
+            let mut section = Node::section(&format!("record{i}"));
+            section.merge(node);
+            root.add(section);
+        }
+        return Ok(output.write_all(root.to_flat_csv().as_bytes())?);
+    }
+
+    write_output(output, &nodes, format)
 }
@@ -0,0 +1,124 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use intel_crashlog::node::child_path;
+use intel_crashlog::prelude::*;
+use serde::Serialize;
+use std::path::Path;
+
+/// Difference between the register trees decoded from two Crash Logs
+#[derive(Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+enum Difference {
+    /// The node only exists in the second Crash Log.
+    Added {
+        path: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+    },
+    /// The node only exists in the first Crash Log.
+    Removed {
+        path: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+    },
+    /// The value of the field differs between the two Crash Logs.
+    Changed {
+        path: String,
+        old: String,
+        new: String,
+    },
+}
+
+fn walk(old: &Node, new: &Node, path: &str, differences: &mut Vec<Difference>) {
+    match (old.formatted_value(), new.formatted_value()) {
+        (Some(old), Some(new)) if old != new => differences.push(Difference::Changed {
+            path: path.to_string(),
+            old,
+            new,
+        }),
+        (Some(_), Some(_)) | (None, None) => (),
+        (old, new) => {
+            // A field has been replaced by a section (or conversely).
+            differences.push(Difference::Removed {
+                path: path.to_string(),
+                value: old,
+            });
+            differences.push(Difference::Added {
+                path: path.to_string(),
+                value: new,
+            });
+            return;
+        }
+    }
+
+    for child in old.children() {
+        let path = child_path(path, child);
+        match new.get(&child.name) {
+            Some(other) => walk(child, other, &path, differences),
+            None => differences.push(Difference::Removed {
+                value: child.formatted_value(),
+                path,
+            }),
+        }
+    }
+
+    for child in new
+        .children()
+        .filter(|child| old.get(&child.name).is_none())
+    {
+        differences.push(Difference::Added {
+            path: child_path(path, child),
+            value: child.formatted_value(),
+        })
+    }
+}
+
+fn write_node<O: std::io::Write>(
+    output: &mut O,
+    prefix: char,
+    path: &str,
+    value: Option<&str>,
+) -> Result<(), Error> {
+    match value {
+        Some(value) => writeln!(output, "{prefix} {path}: {value}")?,
+        None => writeln!(output, "{prefix} {path}")?,
+    }
+    Ok(())
+}
+
+fn write_text<O: std::io::Write>(differences: &[Difference], mut output: O) -> Result<(), Error> {
+    for difference in differences {
+        match difference {
+            Difference::Added { path, value } => {
+                write_node(&mut output, '+', path, value.as_deref())?
+            }
+            Difference::Removed { path, value } => {
+                write_node(&mut output, '-', path, value.as_deref())?
+            }
+            Difference::Changed { path, old, new } => writeln!(output, "~ {path}: {old} -> {new}")?,
+        }
+    }
+    Ok(())
+}
+
+pub fn diff<T: CollateralTree, O: std::io::Write>(
+    cm: &mut CollateralManager<T>,
+    old: &Path,
+    new: &Path,
+    json: bool,
+    output: O,
+) -> Result<(), Error> {
+    let old = CrashLog::from_slice(&std::fs::read(old)?)?.decode(cm);
+    let new = CrashLog::from_slice(&std::fs::read(new)?)?.decode(cm);
+
+    let mut differences = Vec::new();
+    walk(&old, &new, "", &mut differences);
+    log::info!("Found {} differences", differences.len());
+
+    if json {
+        Ok(serde_json::to_writer_pretty(output, &differences)?)
+    } else {
+        write_text(&differences, output)
+    }
+}
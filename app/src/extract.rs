@@ -1,56 +1,120 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
-#![allow(unused_assignments)]
-
+use intel_crashlog::extract::{Availability, SourceRegistry, UefiVariable};
 use intel_crashlog::prelude::*;
+use intel_crashlog::state::ExtractionState;
 use std::path::{Path, PathBuf};
 
-pub fn extract(output_path: Option<&Path>) {
-    let mut result: Result<Vec<CrashLog>, Error> = Err(Error::NoCrashLogFound);
+fn report_insufficient_privileges(err: &Error) {
+    // Always printed regardless of the verbosity as the user can act on it.
+    eprintln!("\nerror: {err}\n");
+}
 
-    #[cfg(target_os = "windows")]
-    {
-        result = CrashLog::from_windows_event_logs(None);
+fn report_unavailable_sources(registry: &SourceRegistry) {
+    for (source, availability) in registry.probe_all() {
+        if let Availability::Unavailable(reason) = availability {
+            log::error!("{}: {reason}", source.name());
+        }
     }
-    #[cfg(target_os = "linux")]
+}
+
+/// Returns the path of the file the `i`-th of `count` Crash Logs is written to.
+fn crashlog_path(
+    output_path: Option<&Path>,
+    crashlog: &CrashLog,
+    i: usize,
+    count: usize,
+) -> PathBuf {
+    let mut path = if let Some(output_path) = output_path {
+        let mut path = output_path.to_path_buf();
+        if output_path.is_dir() {
+            path.push(format!("{}.crashlog", crashlog.metadata))
+        }
+        path
+    } else {
+        PathBuf::from(format!("{}.crashlog", crashlog.metadata))
+    };
+
+    if count > 1
+        && let Some(filename) = path.file_stem()
     {
-        result = Ok([CrashLog::from_acpi_sysfs(), CrashLog::from_pmt_sysfs()]
-            .into_iter()
-            .filter_map(|crashlog| crashlog.ok())
-            .collect::<Vec<CrashLog>>());
+        path.set_file_name(format!(
+            "{}-{i}.crashlog",
+            PathBuf::from(filename).display()
+        ))
     }
+    path
+}
 
-    match result {
-        Ok(crashlogs) => {
-            if crashlogs.is_empty() {
-                log::error!("{}", Error::NoCrashLogFound);
-            }
-
-            for (i, crashlog) in crashlogs.iter().enumerate() {
-                let mut path = if let Some(output_path) = output_path {
-                    let mut path = output_path.to_path_buf();
-                    if output_path.is_dir() {
-                        path.push(format!("{}.crashlog", crashlog.metadata))
+pub fn extract(
+    output_path: Option<&Path>,
+    state_path: Option<&Path>,
+    uefi_variables: &[UefiVariable],
+) -> Result<(), Error> {
+    let mut registry = SourceRegistry::default();
+    for variable in uefi_variables {
+        registry.register(variable.clone());
+    }
+    let crashlogs: Vec<CrashLog> = registry
+        .extract_all()
+        .into_iter()
+        .filter_map(|(source, crashlogs)| {
+            crashlogs
+                .inspect_err(|err| {
+                    if let Error::InsufficientPrivileges(..) = err {
+                        report_insufficient_privileges(err)
+                    } else {
+                        log::debug!("{}: {err}", source.name())
                     }
-                    path
-                } else {
-                    PathBuf::from(format!("{}.crashlog", crashlog.metadata))
-                };
-
-                if crashlogs.len() > 1
-                    && let Some(filename) = path.file_stem()
-                {
-                    path.set_file_name(format!(
-                        "{}-{i}.crashlog",
-                        PathBuf::from(filename).display()
-                    ))
-                }
-
-                println!("{}", path.display());
-                std::fs::write(path, crashlog.to_bytes()).expect("Failed to write Crash Log file")
-            }
+                })
+                .ok()
+        })
+        .flatten()
+        .collect();
+
+    let mut state = state_path.map(ExtractionState::load).transpose()?;
+    let crashlogs = match &state {
+        Some(state) => {
+            let count = crashlogs.len();
+            // The same Crash Log can be exposed by several sources.
+            let mut batch = ExtractionState::default();
+            let crashlogs: Vec<CrashLog> = crashlogs
+                .into_iter()
+                .filter(|crashlog| state.is_new(crashlog) && batch.mark(crashlog))
+                .collect();
+            log::info!(
+                "Skipping {} Crash Logs already extracted",
+                count - crashlogs.len()
+            );
+            crashlogs
         }
-        Err(err) => log::error!("Failed to extract Crash Log: {err}"),
+        None => crashlogs,
+    };
+
+    if crashlogs.is_empty() && state.is_none() {
+        log::error!("{}", Error::NoCrashLogFound);
+        report_unavailable_sources(&registry);
+        return Ok(());
+    }
+
+    // The Crash Logs are only recorded as extracted once they have been written, so the ones that
+    // cannot be written are extracted again by the next run.
+    let mut written = Ok(());
+    for (i, crashlog) in crashlogs.iter().enumerate() {
+        let path = crashlog_path(output_path, crashlog, i, crashlogs.len());
+        println!("{}", path.display());
+        written = std::fs::write(path, crashlog.to_bytes());
+        if written.is_err() {
+            break;
+        }
+        if let Some(state) = state.as_mut() {
+            state.mark(crashlog);
+        }
+    }
+
+    if let (Some(state), Some(state_path)) = (state, state_path) {
+        state.save(state_path)?;
     }
+    Ok(written?)
 }
@@ -0,0 +1,143 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use intel_crashlog::prelude::*;
+use regex::Regex;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Expression matched against the value of the register fields
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) struct ValueFilter {
+    comparison: Comparison,
+    value: u64,
+}
+
+impl ValueFilter {
+    fn matches(&self, value: i128) -> bool {
+        let expected = i128::from(self.value);
+        match self.comparison {
+            Comparison::Eq => value == expected,
+            Comparison::Ne => value != expected,
+            Comparison::Lt => value < expected,
+            Comparison::Le => value <= expected,
+            Comparison::Gt => value > expected,
+            Comparison::Ge => value >= expected,
+        }
+    }
+}
+
+impl FromStr for ValueFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (comparison, value) = [
+            ("==", Comparison::Eq),
+            ("!=", Comparison::Ne),
+            ("<=", Comparison::Le),
+            (">=", Comparison::Ge),
+            ("<", Comparison::Lt),
+            (">", Comparison::Gt),
+            ("=", Comparison::Eq),
+        ]
+        .into_iter()
+        .find_map(|(prefix, comparison)| Some((comparison, s.strip_prefix(prefix)?)))
+        .unwrap_or((Comparison::Eq, s));
+
+        let value = value.trim();
+        let value = match value.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => value.parse(),
+        }
+        .map_err(|err| format!("invalid value `{value}`: {err}"))?;
+
+        Ok(ValueFilter { comparison, value })
+    }
+}
+
+/// Criteria used to select the register fields
+pub(crate) struct Filter<'a> {
+    pub name: Option<&'a Regex>,
+    pub value: Option<ValueFilter>,
+    pub nonzero: bool,
+}
+
+/// Returns the numerical value of a node compared against the [ValueFilter], if any, and whether
+/// the value is zero (or empty).
+fn node_value(kind: &NodeType) -> Option<(Option<i128>, bool)> {
+    Some(match kind {
+        NodeType::Field { value } => (Some(i128::from(*value)), *value == 0),
+        NodeType::Signed { value } => (Some(i128::from(*value)), *value == 0),
+        NodeType::Bool { value } => (Some(i128::from(*value)), !*value),
+        NodeType::Bytes { value } => {
+            // The wide fields can only be compared when their value fits in 64 bits.
+            let narrow = value.iter().skip(8).all(|byte| *byte == 0).then(|| {
+                value
+                    .iter()
+                    .take(8)
+                    .rev()
+                    .fold(0, |acc, byte| (acc << 8) | i128::from(*byte))
+            });
+            (narrow, value.iter().all(|byte| *byte == 0))
+        }
+        NodeType::String { value } => (None, value.is_empty()),
+        NodeType::Root | NodeType::Section | NodeType::Record | NodeType::Truncated => return None,
+    })
+}
+
+impl Filter<'_> {
+    fn matches(&self, path: &str, node: &Node) -> bool {
+        let Some((value, zero)) = node_value(&node.kind) else {
+            return false;
+        };
+        self.name.is_none_or(|name| name.is_match(path))
+            && self
+                .value
+                .is_none_or(|filter| value.is_some_and(|value| filter.matches(value)))
+            && (!self.nonzero || !zero)
+    }
+}
+
+/// Returns true if at least one field of the register tree matches the filter.
+pub(crate) fn contains(node: &Node, filter: &Filter) -> bool {
+    node.walk().any(|(path, node)| filter.matches(&path, node))
+}
+
+fn walk<O: std::io::Write>(node: &Node, filter: &Filter, output: &mut O) -> Result<(), Error> {
+    for (path, node) in node.walk() {
+        if !filter.matches(&path, node) {
+            continue;
+        }
+        let Some(value) = node.formatted_value() else {
+            continue;
+        };
+        if let Some(name) = &node.value_name {
+            writeln!(output, "{path}: {value} ({name})")?;
+        } else {
+            writeln!(output, "{path}: {value}")?;
+        }
+    }
+    Ok(())
+}
+
+pub fn grep<T: CollateralTree, O: std::io::Write>(
+    cm: &mut CollateralManager<T>,
+    input: &Path,
+    filter: &Filter,
+    mut output: O,
+) -> Result<(), Error> {
+    let crashlog = CrashLog::from_slice(&std::fs::read(input)?)?;
+    let nodes = crashlog.decode(cm);
+    walk(&nodes, filter, &mut output)
+}
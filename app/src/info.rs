@@ -5,61 +5,105 @@ use super::InfoFormat;
 use intel_crashlog::prelude::*;
 use std::path::Path;
 
-fn compact<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Result<(), Error> {
-    let crashlog = CrashLog::from_slice(&std::fs::read(input)?)?;
-
-    println!("  #   Record Type      Rev.  Product  Size   Skt  Checksum  Die      ");
-    println!("----- ---------------- ----- -------- ------ ---- --------- ---------");
-    for (i, region) in crashlog.regions.iter().enumerate() {
-        for (j, record) in region.records.iter().enumerate() {
-            let product = if let Ok(product) = record.header.product(cm) {
-                let variant = record.header.variant(cm).unwrap_or("all");
-                format!("{product}/{variant}")
-            } else {
-                format!("{:#05x}", record.header.product_id())
-            };
-
-            let record_type = if let Ok(record_type) = record.header.record_type() {
-                record_type.into()
-            } else {
-                format!("{:#04x}", record.header.version.record_type)
-            };
+/// Fields of a record, formatted for the tables
+struct Row {
+    record_type: String,
+    revision: u32,
+    product: String,
+    size: usize,
+    socket_id: u8,
+    checksum: &'static str,
+    timestamp: String,
+    reason: String,
+    complete: &'static str,
+    die: String,
+}
 
-            let checksum = record
+impl Row {
+    fn new<T: CollateralTree>(cm: &CollateralManager<T>, record: &Record) -> Self {
+        let product = if let Ok(product) = record.header.product(cm) {
+            let variant = record.header.variant(cm).unwrap_or("all");
+            format!("{product}/{variant}")
+        } else {
+            format!("{:#05x}", record.header.product_id())
+        };
+
+        let record_type = if let Ok(record_type) = record.header.record_type() {
+            record_type.into()
+        } else {
+            format!("{:#04x}", record.header.version.record_type)
+        };
+
+        let die = if let Some(die_id) = record.header.die(cm) {
+            die_id.to_string()
+        } else {
+            record
+                .header
+                .die_id()
+                .map(|die_id| die_id.to_string())
+                .unwrap_or_default()
+        };
+
+        Row {
+            record_type,
+            revision: record.header.revision(),
+            product,
+            size: record.header.record_size(),
+            socket_id: record.header.socket_id(),
+            checksum: record
                 .checksum()
-                .map_or("", |check| if check { "Valid" } else { "Invalid" });
-
-            let die = if let Some(die_id) = record.header.die(cm) {
-                die_id
-            } else {
-                &record
-                    .header
-                    .die_id()
-                    .map(|die_id| die_id.to_string())
-                    .unwrap_or_default()
-            };
+                .map_or("", |check| if check { "Valid" } else { "Invalid" }),
+            timestamp: record
+                .header
+                .timestamp()
+                .map(|timestamp| format!("{timestamp:#018x}"))
+                .unwrap_or_default(),
+            reason: record
+                .header
+                .reason()
+                .map(|reason| format!("{reason:#010x}"))
+                .unwrap_or_default(),
+            complete: record
+                .header
+                .collection_complete()
+                .map_or("", |complete| if complete { "Yes" } else { "No" }),
+            die,
+        }
+    }
+}
 
+fn compact<T: CollateralTree>(cm: &CollateralManager<T>, crashlog: &CrashLog) {
+    println!(
+        "  #   Record Type      Rev.  Product  Size   Skt  Checksum  Timestamp          Reason     \
+         Complete Die      "
+    );
+    println!(
+        "----- ---------------- ----- -------- ------ ---- --------- ------------------ ---------- \
+         -------- ---------"
+    );
+    for (i, region) in crashlog.regions.iter().enumerate() {
+        for (j, record) in region.records.iter().enumerate() {
+            let row = Row::new(cm, record);
             println!(
-                "{:>2}-{:<2} {:<16} {:>5} {:<8} {:>6} {:>4} {:<9} {}",
+                "{:>2}-{:<2} {:<16} {:>5} {:<8} {:>6} {:>4} {:<9} {:<18} {:<10} {:<8} {}",
                 i,
                 j,
-                record_type,
-                record.header.revision(),
-                product,
-                record.header.record_size(),
-                record.header.socket_id(),
-                checksum,
-                die
+                row.record_type,
+                row.revision,
+                row.product,
+                row.size,
+                row.socket_id,
+                row.checksum,
+                row.timestamp,
+                row.reason,
+                row.complete,
+                row.die
             );
         }
     }
-
-    Ok(())
 }
 
-fn markdown<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Result<(), Error> {
-    let crashlog = CrashLog::from_slice(&std::fs::read(input)?)?;
-
+fn markdown<T: CollateralTree>(cm: &CollateralManager<T>, crashlog: &CrashLog) {
     // Column widths
     let region_idx_width = 8;
     let record_idx_width = 8;
@@ -69,6 +113,9 @@ fn markdown<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Resul
     let size_width = 10;
     let skt_width = 8;
     let checksum_width = 12;
+    let timestamp_width = 18;
+    let reason_width = 10;
+    let complete_width = 8;
     let die_width = 10;
 
     // Header
@@ -81,7 +128,10 @@ fn markdown<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Resul
          | {6:<size_width$} \
          | {7:<skt_width$} \
          | {8:<checksum_width$} \
-         | {9:<die_width$} \
+         | {9:<timestamp_width$} \
+         | {10:<reason_width$} \
+         | {11:<complete_width$} \
+         | {12:<die_width$} \
          |\n\
          | {0:-<region_idx_width$} \
          | {0:-<record_idx_width$} \
@@ -91,6 +141,9 @@ fn markdown<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Resul
          | {0:-<size_width$} \
          | {0:-<skt_width$} \
          | {0:-<checksum_width$} \
+         | {0:-<timestamp_width$} \
+         | {0:-<reason_width$} \
+         | {0:-<complete_width$} \
          | {0:-<die_width$} \
          |",
         "",
@@ -102,40 +155,26 @@ fn markdown<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Resul
         "Size",
         "Socket",
         "Checksum",
+        "Timestamp",
+        "Reason",
+        "Complete",
         "Die",
     );
 
     for (i, region) in crashlog.regions.iter().enumerate() {
         for (j, record) in region.records.iter().enumerate() {
-            let product = if let Ok(product) = record.header.product(cm) {
-                let variant = record.header.variant(cm).unwrap_or("all");
-                format!("{product}/{variant}")
-            } else {
-                format!("{:#05x}", record.header.product_id())
-            };
-
-            let record_type = if let Ok(record_type) = record.header.record_type() {
-                record_type.into()
-            } else {
-                format!("{:#04x}", record.header.version.record_type)
-            };
-
-            let checksum = record
-                .checksum()
-                .map_or("", |check| if check { "Valid" } else { "Invalid" });
-
-            let die = if let Some(die_id) = record.header.die(cm) {
-                die_id
-            } else {
-                &record
-                    .header
-                    .die_id()
-                    .map(|die_id| die_id.to_string())
-                    .unwrap_or_default()
-            };
-            let revision = record.header.revision();
-            let record_size = record.header.record_size();
-            let socket_id = record.header.socket_id();
+            let Row {
+                record_type,
+                revision,
+                product,
+                size,
+                socket_id,
+                checksum,
+                timestamp,
+                reason,
+                complete,
+                die,
+            } = Row::new(cm, record);
 
             // Populate the table
             println!(
@@ -144,15 +183,33 @@ fn markdown<T: CollateralTree>(cm: &CollateralManager<T>, input: &Path) -> Resul
                  | {record_type:<record_type_width$} \
                  | {revision:<revision_width$} \
                  | {product:<product_width$} \
-                 | {record_size:<size_width$} \
+                 | {size:<size_width$} \
                  | {socket_id:<skt_width$} \
                  | {checksum:<checksum_width$} \
+                 | {timestamp:<timestamp_width$} \
+                 | {reason:<reason_width$} \
+                 | {complete:<complete_width$} \
                  | {die:<die_width$} \
                  |"
             );
         }
     }
-    Ok(())
+}
+
+fn read(input: &Path) -> Result<CrashLog, Error> {
+    CrashLog::from_slice(&std::fs::read(input)?)
+}
+
+/// Prints the records of a Crash Log that has already been loaded.
+pub(crate) fn info_crashlog<T: CollateralTree>(
+    cm: &CollateralManager<T>,
+    crashlog: &CrashLog,
+    format: InfoFormat,
+) {
+    match format {
+        InfoFormat::Compact => compact(cm, crashlog),
+        InfoFormat::Markdown => markdown(cm, crashlog),
+    }
 }
 
 pub(crate) fn info<T, P>(cm: &CollateralManager<T>, input_files: &[P], format: InfoFormat)
@@ -166,8 +223,9 @@ where
                 if input_files.len() > 1 {
                     println!("\n{}:\n", input_file.as_ref().display());
                 }
-                if let Err(err) = compact(cm, input_file.as_ref()) {
-                    log::error!("Error: {err}")
+                match read(input_file.as_ref()) {
+                    Ok(crashlog) => compact(cm, &crashlog),
+                    Err(err) => log::error!("Error: {err}"),
                 }
             }
         }
@@ -175,9 +233,12 @@ where
             for input_file in input_files {
                 println!("\n## `{}`\n", input_file.as_ref().display());
 
-                if let Err(err) = markdown(cm, input_file.as_ref()) {
-                    log::warn!("Error: {err}");
-                    println!("\n```\n{err}\n```");
+                match read(input_file.as_ref()) {
+                    Ok(crashlog) => markdown(cm, &crashlog),
+                    Err(err) => {
+                        log::warn!("Error: {err}");
+                        println!("\n```\n{err}\n```");
+                    }
                 }
             }
         }
@@ -1,23 +1,53 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+mod convert;
 mod decode;
+mod diff;
 mod extract;
+mod grep;
 mod info;
+mod minimize;
+mod pack;
+mod session;
+mod split;
+mod tree;
+mod triage;
 mod unpack;
+mod watch;
 
 use clap::{Parser, Subcommand, ValueEnum};
 use env_logger::Env;
+use intel_crashlog::analysis::FailureClass;
+use intel_crashlog::collateral::{
+    ArchiveTree, BundleTree, EmbeddedTree, FileSystemTree, OverlayTree,
+};
+use intel_crashlog::extract::UefiVariable;
+use intel_crashlog::header::record_types;
 use intel_crashlog::prelude::*;
+use intel_crashlog::record::DecodeOptions;
 use log::LevelFilter;
-use std::path::PathBuf;
+use regex::Regex;
+use std::path::{Path, PathBuf};
 
 #[derive(Parser)]
 #[command(version, about = "Extract and decode Intel Crash Log records.")]
 struct Cli {
-    /// Path to the collateral tree. If not specified, the builtin collateral tree will be used.
-    #[arg(short, long, value_name = "dir")]
-    collateral_tree: Option<PathBuf>,
+    /// Path to the collateral tree, either a directory or a zip, tar, or tar.gz archive. Can be
+    /// repeated to stack several collateral trees, the first ones taking precedence. If not
+    /// specified, the builtin collateral tree will be used.
+    #[arg(short, long, value_name = "path")]
+    collateral_tree: Vec<PathBuf>,
+
+    /// Path to a collateral bundle exported by the decode command. Takes precedence over the
+    /// collateral tree.
+    #[arg(short = 'b', long, value_name = "file")]
+    collateral_bundle: Option<PathBuf>,
+
+    /// Path to the Ed25519 public key (32 raw bytes) used to verify the signed manifests of the
+    /// collateral tree. The collateral items that cannot be verified are refused.
+    #[arg(short = 'k', long, value_name = "file")]
+    verifying_key: Option<PathBuf>,
 
     /// Sets the verbosity of the logging messages
     /// -v: Warning, -vv: Info, -vvv: Debug, -vvvv: Trace
@@ -35,33 +65,442 @@ pub(crate) enum InfoFormat {
     Markdown,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, ValueEnum)]
+pub(crate) enum OutputFormat {
+    #[default]
+    Json,
+    Yaml,
+    /// Indented tree, colorized when printed to a terminal
+    Tree,
+    /// One row per field with its path, value, and description
+    Csv,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub(crate) enum ConvertFormat {
+    /// UEFI Common Platform Error Record
+    Cper,
+    /// ACPI Boot Error Record Table followed by the Boot Error Region
+    Bert,
+    /// Crash Log regions without any header
+    Raw,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, ValueEnum)]
+pub(crate) enum CperSeverity {
+    /// Derived from the machine check banks decoded from the records
+    Auto,
+    #[default]
+    Fatal,
+    Recoverable,
+    Corrected,
+    Informational,
+}
+
+/// Class of failure identified by the triage (see [FailureClass])
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub(crate) enum Finding {
+    ThreeStrikeTimeout,
+    Ierr,
+    Mcerr,
+    MachineCheck,
+    FirmwareHang,
+}
+
+impl From<Finding> for FailureClass {
+    fn from(finding: Finding) -> Self {
+        match finding {
+            Finding::ThreeStrikeTimeout => FailureClass::ThreeStrikeTimeout,
+            Finding::Ierr => FailureClass::Ierr,
+            Finding::Mcerr => FailureClass::Mcerr,
+            Finding::MachineCheck => FailureClass::MachineCheck,
+            Finding::FirmwareHang => FailureClass::FirmwareHang,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, ValueEnum)]
+pub(crate) enum SplitBy {
+    #[default]
+    Socket,
+    Die,
+}
+
 #[derive(Subcommand)]
 enum Command {
     /// Extract the Crash Log records from the platform
-    Extract { output_path: Option<PathBuf> },
-    /// Decode Crash Log records into JSON
-    Decode { input_file: PathBuf },
+    Extract {
+        /// Only writes the Crash Logs that haven't been extracted before, according to the state
+        /// stored in the given file. The file is created if needed.
+        #[arg(short, long, value_name = "file")]
+        state: Option<PathBuf>,
+        /// Also reads the Crash Logs stored in the given UEFI variable, identified by its name and
+        /// vendor GUID (e.g. `CrashLog-8be4df61-93ca-11d2-aa0d-00e098032b8c`)
+        #[arg(
+            short,
+            long = "uefi-variable",
+            value_name = "name-guid",
+            value_parser = parse_uefi_variable
+        )]
+        uefi_variables: Vec<UefiVariable>,
+        output_path: Option<PathBuf>,
+    },
+    /// Continuously extract the new Crash Log records from the platform
+    Watch {
+        /// Interval between two extractions in seconds
+        #[arg(short, long, value_name = "seconds", default_value_t = 60)]
+        interval: u64,
+        /// Skips the Crash Logs that have been extracted before, according to the state stored
+        /// in the given file. The file is created if needed.
+        #[arg(short, long, value_name = "file")]
+        state: Option<PathBuf>,
+        /// Also writes the decoded register tree of each new Crash Log into a JSON file
+        #[arg(short, long)]
+        decode: bool,
+        /// Runs the given program on each new Crash Log. The program receives the path of the
+        /// Crash Log file (followed by the path of the JSON file when `--decode` is set) as
+        /// arguments, and the name of the extraction source in the `ICLG_SOURCE` variable.
+        #[arg(short, long, value_name = "program")]
+        exec: Option<PathBuf>,
+        /// Directory where the Crash Logs are written, in files named after the extraction time
+        output_dir: PathBuf,
+    },
+    /// Decode Crash Log records into JSON, YAML, or a tree
+    Decode {
+        /// Decodes the records of the given type using a custom decode definition file instead of
+        /// the collateral tree (e.g. `MCA=layout.csv`)
+        #[arg(
+            short,
+            long = "layout",
+            value_name = "record_type=file.csv",
+            value_parser = parse_layout
+        )]
+        layouts: Vec<(String, PathBuf)>,
+        /// Exports the collateral items used by the decoder into a bundle that can be loaded
+        /// later with `--collateral-bundle`
+        #[arg(short, long, value_name = "file")]
+        export_collateral: Option<PathBuf>,
+        /// Adds a `raw` node to the records containing the bytes that are not covered by the
+        /// decode definitions
+        #[arg(long)]
+        raw: bool,
+        /// Decodes the available fields of the incomplete records and marks the missing ones
+        /// as `truncated`
+        #[arg(long)]
+        tolerant: bool,
+        /// Only decodes the records of the given type (e.g. `MCA`). Can be repeated.
+        #[arg(long = "record-type", value_name = "type")]
+        record_types: Vec<String>,
+        /// Only decodes the records generated by the given socket. Can be repeated.
+        #[arg(long = "socket", value_name = "id")]
+        sockets: Vec<u8>,
+        /// Only decodes the records generated by the given die. Can be repeated.
+        #[arg(long = "die", value_name = "id")]
+        dies: Vec<u8>,
+        /// Only outputs the sub-tree located at the given path (e.g. `mca.core0`). Can be
+        /// repeated.
+        #[arg(long = "select", value_name = "path")]
+        selected: Vec<String>,
+        /// Removes the sub-tree located at the given path from the output (e.g. to redact
+        /// sensitive fields). Can be repeated.
+        #[arg(long = "exclude", value_name = "path")]
+        excluded: Vec<String>,
+        /// Includes the descriptions of the fields in the output
+        #[arg(short, long)]
+        descriptions: bool,
+        /// Lists the fields of the JSON and YAML output in the order of the decode definitions
+        /// instead of alphabetically
+        #[arg(long)]
+        definition_order: bool,
+        /// Format of the decoded output
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::default())]
+        format: OutputFormat,
+        /// Decodes the whole CPER records found in the input file (headers, section descriptors,
+        /// and section bodies) into an array instead of the Crash Log records
+        #[arg(long)]
+        cper: bool,
+        input_file: PathBuf,
+    },
+    /// Print the decoded register fields matching the search criteria
+    Grep {
+        /// Regular expression matched against the path of the fields
+        #[arg(short, long, value_name = "regex")]
+        name: Option<Regex>,
+        /// Expression matched against the value of the fields (e.g. `0x10`, `!=0`, `>=0x100`)
+        #[arg(long, value_name = "expr", allow_hyphen_values = true)]
+        value: Option<grep::ValueFilter>,
+        /// Only print the fields with a non-zero value
+        #[arg(long)]
+        nonzero: bool,
+        input_file: PathBuf,
+    },
+    /// Identify the likely class of failure (e.g. three-strike timeout, machine check) captured
+    /// in the input files
+    Triage { input_files: Vec<PathBuf> },
+    /// Compare the decoded register fields of two Crash Logs
+    Diff {
+        /// Prints the differences as a JSON array
+        #[arg(long)]
+        json: bool,
+        old_file: PathBuf,
+        new_file: PathBuf,
+    },
+    /// Reduce the input file to the smallest Crash Log that still contains the register fields
+    /// matching the search criteria, fails to decode, or leads to the given triage finding
+    Minimize {
+        /// Regular expression matched against the path of the fields
+        #[arg(short, long, value_name = "regex")]
+        name: Option<Regex>,
+        /// Expression matched against the value of the fields (e.g. `0x10`, `!=0`, `>=0x100`)
+        #[arg(long, value_name = "expr", allow_hyphen_values = true)]
+        value: Option<grep::ValueFilter>,
+        /// Only consider the fields with a non-zero value
+        #[arg(long)]
+        nonzero: bool,
+        /// Preserve the failure to decode at least one of the records instead of matching fields
+        #[arg(long, conflicts_with_all = ["name", "value", "nonzero", "finding"])]
+        decode_failure: bool,
+        /// Preserve the given triage finding instead of matching fields
+        #[arg(long, value_enum, conflicts_with_all = ["name", "value", "nonzero"])]
+        finding: Option<Finding>,
+        input_file: PathBuf,
+    },
     /// List the Crash Log records stored in the input file
     Info {
         #[arg(short, long, value_enum, default_value_t = InfoFormat::default())]
         format: InfoFormat,
         input_files: Vec<PathBuf>,
     },
+    /// Convert a Crash Log between the CPER, BERT, and raw formats
+    Convert {
+        /// Format of the output file
+        #[arg(short, long, value_enum)]
+        to: ConvertFormat,
+        /// Severity of the CPER record
+        #[arg(short, long, value_enum, default_value_t = CperSeverity::default())]
+        severity: CperSeverity,
+        /// Timestamp of the CPER record (e.g. `2025-01-31T12:34`)
+        #[arg(long, value_name = "time", value_parser = convert::parse_timestamp)]
+        timestamp: Option<intel_crashlog::metadata::Time>,
+        /// Path of the output file, which must differ from the input file. Defaults to the input
+        /// file with the extension of the output format (`<stem>.converted.<ext>` if the input
+        /// file already has this extension).
+        #[arg(short, long, value_name = "file")]
+        output: Option<PathBuf>,
+        input_file: PathBuf,
+    },
     /// Unpack the Crash Log records stored in the input file
     Unpack { input_files: Vec<PathBuf> },
+    /// Combine the regions stored in the input files (e.g. produced by `unpack`) into a single
+    /// Crash Log
+    Pack {
+        /// Format of the output file
+        #[arg(short, long, value_enum, default_value_t = ConvertFormat::Cper)]
+        to: ConvertFormat,
+        /// Severity of the CPER record
+        #[arg(short, long, value_enum, default_value_t = CperSeverity::default())]
+        severity: CperSeverity,
+        /// Path of the output file
+        #[arg(short, long, value_name = "file")]
+        output: PathBuf,
+        /// Remove the records found in several input files
+        #[arg(long)]
+        dedup: bool,
+        #[arg(required = true)]
+        input_files: Vec<PathBuf>,
+    },
+    /// Split the Crash Log records stored in the input file into one file per socket or die
+    Split {
+        #[arg(short, long, value_enum, default_value_t = SplitBy::default())]
+        by: SplitBy,
+        input_files: Vec<PathBuf>,
+    },
+    /// Manage the bundles grouping the Crash Logs captured during the same incident
+    Session {
+        #[command(subcommand)]
+        command: SessionCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum SessionCommand {
+    /// Create a session bundle from the captured Crash Logs
+    Create {
+        /// Metadata shared by all the captures (e.g. `incident=1234`)
+        #[arg(
+            short,
+            long = "metadata",
+            value_name = "key=value",
+            value_parser = parse_metadata
+        )]
+        metadata: Vec<(String, String)>,
+        output_dir: PathBuf,
+        /// Captured Crash Logs and their source (e.g. `acpi=acpi.crashlog`)
+        #[arg(required = true, value_name = "source=file", value_parser = parse_capture)]
+        captures: Vec<(String, PathBuf)>,
+    },
+    /// List the Crash Log records stored in the session bundle
+    Info {
+        #[arg(short, long, value_enum, default_value_t = InfoFormat::default())]
+        format: InfoFormat,
+        dir: PathBuf,
+    },
+    /// Decode the Crash Log records stored in the session bundle into JSON
+    Decode { dir: PathBuf },
+}
+
+fn parse_layout(s: &str) -> Result<(String, PathBuf), String> {
+    let (record_type, path) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected <record_type>=<file.csv>, got `{s}`"))?;
+    if record_types::from_name(record_type).is_none() {
+        return Err(format!("unknown record type `{record_type}`"));
+    }
+    Ok((record_type.to_string(), PathBuf::from(path)))
+}
+
+fn parse_metadata(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected <key>=<value>, got `{s}`"))?;
+    Ok((key.to_string(), value.to_string()))
+}
+
+fn parse_uefi_variable(s: &str) -> Result<UefiVariable, String> {
+    s.parse()
+        .map_err(|_| format!("expected <name>-<vendor GUID>, got `{s}`"))
+}
+
+fn parse_capture(s: &str) -> Result<(String, PathBuf), String> {
+    let (source, path) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected <source>=<file>, got `{s}`"))?;
+    Ok((source.to_string(), PathBuf::from(path)))
 }
 
 impl Command {
     fn run<T: CollateralTree>(&self, mut cm: CollateralManager<T>) -> Result<(), Error> {
         match self {
-            Command::Extract { output_path } => extract::extract(output_path.as_deref()),
-            Command::Decode { input_file } => {
-                decode::decode(&mut cm, input_file, std::io::stdout().lock())?
+            Command::Extract {
+                state,
+                uefi_variables,
+                output_path,
+            } => extract::extract(output_path.as_deref(), state.as_deref(), uefi_variables)?,
+            Command::Watch {
+                interval,
+                state,
+                decode,
+                exec,
+                output_dir,
+            } => watch::watch(
+                &mut cm,
+                output_dir,
+                *interval,
+                state.as_deref(),
+                &watch::Actions {
+                    decode: *decode,
+                    exec: exec.as_deref(),
+                },
+            )?,
+            Command::Decode {
+                layouts,
+                export_collateral,
+                raw,
+                tolerant,
+                record_types,
+                sockets,
+                dies,
+                selected,
+                excluded,
+                descriptions,
+                definition_order,
+                format,
+                cper,
+                input_file,
+            } => {
+                if *cper {
+                    return decode::decode_cper(input_file, *format, std::io::stdout().lock());
+                }
+                for (record_type, path) in layouts {
+                    cm.override_layout(record_type, std::fs::read(path)?)?;
+                }
+                cm.set_decode_options(DecodeOptions {
+                    raw: *raw,
+                    tolerant: *tolerant,
+                    record_types: record_types.clone(),
+                    sockets: sockets.clone(),
+                    dies: dies.clone(),
+                });
+                let options = decode::DecodeOutput {
+                    selected,
+                    excluded,
+                    descriptions: *descriptions,
+                    definition_order: *definition_order,
+                    format: *format,
+                };
+                decode::decode(&mut cm, input_file, &options, std::io::stdout().lock())?;
+                if let Some(path) = export_collateral {
+                    std::fs::write(path, cm.export_bundle()?)?
+                }
+            }
+            Command::Grep {
+                name,
+                value,
+                nonzero,
+                input_file,
+            } => {
+                let filter = grep::Filter {
+                    name: name.as_ref(),
+                    value: *value,
+                    nonzero: *nonzero,
+                };
+                grep::grep(&mut cm, input_file, &filter, std::io::stdout().lock())?
+            }
+            Command::Triage { input_files } => triage::triage(&mut cm, input_files),
+            Command::Diff {
+                json,
+                old_file,
+                new_file,
+            } => diff::diff(&mut cm, old_file, new_file, *json, std::io::stdout().lock())?,
+            Command::Minimize {
+                name,
+                value,
+                nonzero,
+                decode_failure,
+                finding,
+                input_file,
+            } => {
+                let behavior = if *decode_failure {
+                    minimize::Behavior::DecodeFailure
+                } else if let Some(finding) = finding {
+                    minimize::Behavior::Finding((*finding).into())
+                } else {
+                    minimize::Behavior::Match(grep::Filter {
+                        name: name.as_ref(),
+                        value: *value,
+                        nonzero: *nonzero,
+                    })
+                };
+                minimize::minimize(&mut cm, input_file, &behavior)?
             }
             Command::Info {
                 input_files,
                 format,
             } => info::info(&cm, input_files, *format),
+            Command::Convert {
+                to,
+                severity,
+                timestamp,
+                output,
+                input_file,
+            } => convert::convert(
+                &mut cm,
+                input_file,
+                output.as_deref(),
+                *to,
+                *severity,
+                timestamp.as_ref(),
+            )?,
             Command::Unpack { input_files } => {
                 for input_file in input_files {
                     if let Err(err) = unpack::unpack(input_file) {
@@ -69,17 +508,87 @@ impl Command {
                     }
                 }
             }
+            Command::Pack {
+                to,
+                severity,
+                output,
+                dedup,
+                input_files,
+            } => pack::pack(&mut cm, input_files, output, *to, *severity, *dedup)?,
+            Command::Split { input_files, by } => {
+                for input_file in input_files {
+                    if let Err(err) = split::split(input_file, *by) {
+                        log::error!("Error: {err}")
+                    }
+                }
+            }
+            Command::Session { command } => match command {
+                SessionCommand::Create {
+                    metadata,
+                    output_dir,
+                    captures,
+                } => session::create(output_dir, metadata, captures)?,
+                SessionCommand::Info { format, dir } => session::info(&cm, dir, *format)?,
+                SessionCommand::Decode { dir } => {
+                    session::decode(&mut cm, dir, std::io::stdout().lock())?
+                }
+            },
         }
         Ok(())
     }
 }
 
+fn open_collateral_tree(path: &Path) -> Result<Box<dyn CollateralTree>, Error> {
+    Ok(if path.is_file() {
+        Box::new(ArchiveTree::from_slice(&std::fs::read(path)?)?)
+    } else {
+        Box::new(FileSystemTree::new(path))
+    })
+}
+
+fn collateral_manager<T: CollateralTree>(
+    tree: T,
+    verifying_key: Option<&[u8; 32]>,
+) -> Result<CollateralManager<T>, Error> {
+    match verifying_key {
+        Some(key) => CollateralManager::with_verifying_key(tree, key),
+        None => CollateralManager::new(tree),
+    }
+}
+
 fn run(cli: Cli) -> Result<(), Error> {
-    if let Some(collateral_tree) = cli.collateral_tree {
+    let verifying_key: Option<[u8; 32]> = match cli.verifying_key {
+        Some(path) => Some(
+            std::fs::read(path)?
+                .try_into()
+                .map_err(|_| Error::InvalidVerifyingKey)?,
+        ),
+        None => None,
+    };
+    let verifying_key = verifying_key.as_ref();
+
+    if let Some(collateral_bundle) = cli.collateral_bundle {
+        let bundle = BundleTree::from_slice(&std::fs::read(collateral_bundle)?)?;
+        let layout_overrides: Vec<(String, Vec<u8>)> = bundle
+            .layout_overrides()
+            .map(|(record_type, layout)| (record_type.to_string(), layout.to_vec()))
+            .collect();
+        let mut cm = collateral_manager(bundle, verifying_key)?;
+        for (record_type, layout) in layout_overrides {
+            cm.override_layout(&record_type, layout)?;
+        }
+        cli.command.run(cm)?
+    } else if !cli.collateral_tree.is_empty() {
+        let trees = cli
+            .collateral_tree
+            .iter()
+            .map(|path| open_collateral_tree(path))
+            .collect::<Result<Vec<_>, Error>>()?;
         cli.command
-            .run(CollateralManager::file_system_tree(&collateral_tree)?)?
+            .run(collateral_manager(OverlayTree::new(trees), verifying_key)?)?
     } else {
-        cli.command.run(CollateralManager::embedded_tree()?)?
+        cli.command
+            .run(collateral_manager(EmbeddedTree::new(), verifying_key)?)?
     }
     Ok(())
 }
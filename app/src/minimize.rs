@@ -0,0 +1,65 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::grep::{self, Filter};
+use intel_crashlog::analysis::FailureClass;
+use intel_crashlog::prelude::*;
+use intel_crashlog::record::DecodeWarning;
+use std::path::{Path, PathBuf};
+
+/// Behavior preserved by the minimization
+pub(crate) enum Behavior<'a> {
+    /// The decoded register tree contains fields matching the search criteria.
+    Match(Filter<'a>),
+    /// At least one record cannot be decoded.
+    DecodeFailure,
+    /// The triage identifies a failure of the given class.
+    Finding(FailureClass),
+}
+
+impl Behavior<'_> {
+    fn reproduces<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+        crashlog: &CrashLog,
+    ) -> bool {
+        match self {
+            Behavior::Match(filter) => grep::contains(&crashlog.decode(cm), filter),
+            Behavior::DecodeFailure => crashlog
+                .decode_with_report(cm)
+                .1
+                .warnings()
+                .any(|warning| matches!(warning, DecodeWarning::DecodeFailed(_))),
+            Behavior::Finding(class) => crashlog
+                .triage(cm)
+                .iter()
+                .any(|finding| finding.class == *class),
+        }
+    }
+}
+
+pub fn minimize<T: CollateralTree>(
+    cm: &mut CollateralManager<T>,
+    input_file: &Path,
+    behavior: &Behavior,
+) -> Result<(), Error> {
+    let crashlog = CrashLog::from_slice(&std::fs::read(input_file)?)?;
+    let Some(minimized) = crashlog.minimize(|crashlog| behavior.reproduces(cm, crashlog)) else {
+        log::error!(
+            "The behavior to preserve cannot be observed in {}",
+            input_file.display()
+        );
+        return Ok(());
+    };
+
+    let mut path = PathBuf::from(input_file);
+    if let Some(filename) = path.file_stem() {
+        path.set_file_name(format!(
+            "{}_min.crashlog",
+            PathBuf::from(filename).display()
+        ))
+    }
+
+    println!("{}", path.display());
+    Ok(std::fs::write(&path, minimized.to_bytes())?)
+}
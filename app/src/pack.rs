@@ -0,0 +1,35 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::{ConvertFormat, CperSeverity, convert};
+use intel_crashlog::prelude::*;
+use std::path::{Path, PathBuf};
+
+pub fn pack<T: CollateralTree>(
+    cm: &mut CollateralManager<T>,
+    input_files: &[PathBuf],
+    output_file: &Path,
+    to: ConvertFormat,
+    severity: CperSeverity,
+    dedup: bool,
+) -> Result<(), Error> {
+    let mut crashlog = CrashLog::default();
+    for input_file in input_files {
+        let mut regions = CrashLog::from_slice(&std::fs::read(input_file)?)
+            .inspect_err(|err| log::error!("Cannot read {}: {err}", input_file.display()))?
+            .regions;
+        log::debug!(
+            "Read {} regions from {}",
+            regions.len(),
+            input_file.display()
+        );
+        crashlog.regions.append(&mut regions);
+    }
+
+    if dedup {
+        let removed = crashlog.dedup();
+        log::info!("Removed {removed} duplicated records");
+    }
+
+    convert::write(cm, &crashlog, output_file, to, severity)
+}
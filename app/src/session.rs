@@ -0,0 +1,66 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::InfoFormat;
+use crate::info;
+use intel_crashlog::prelude::*;
+use intel_crashlog::session::Session;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub fn create(
+    output_dir: &Path,
+    metadata: &[(String, String)],
+    captures: &[(String, PathBuf)],
+) -> Result<(), Error> {
+    let mut session = Session::default();
+    session.metadata.extend(metadata.iter().cloned());
+
+    for (source, path) in captures {
+        let crashlog = CrashLog::from_slice(&std::fs::read(path)?)?;
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or(source);
+        session.add(name, source, crashlog);
+    }
+
+    session.write(output_dir)?;
+    println!("{}", output_dir.display());
+    Ok(())
+}
+
+pub fn info<T: CollateralTree>(
+    cm: &CollateralManager<T>,
+    dir: &Path,
+    format: InfoFormat,
+) -> Result<(), Error> {
+    let session = Session::read(dir)?;
+
+    for (key, value) in session.metadata.iter() {
+        println!("{key}: {value}");
+    }
+
+    for capture in session.captures.iter() {
+        match format {
+            InfoFormat::Compact => println!("\n{} ({}):\n", capture.name, capture.source),
+            InfoFormat::Markdown => println!("\n## `{}` ({})\n", capture.name, capture.source),
+        }
+        info::info_crashlog(cm, &capture.crashlog, format);
+    }
+    Ok(())
+}
+
+pub fn decode<T: CollateralTree, O: std::io::Write>(
+    cm: &mut CollateralManager<T>,
+    dir: &Path,
+    output: O,
+) -> Result<(), Error> {
+    let session = Session::read(dir)?;
+    let nodes: BTreeMap<&str, Node> = session
+        .captures
+        .iter()
+        .map(|capture| (capture.name.as_str(), capture.crashlog.decode(cm)))
+        .collect();
+    Ok(serde_json::to_writer_pretty(output, &nodes)?)
+}
@@ -0,0 +1,43 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::SplitBy;
+use intel_crashlog::prelude::*;
+use intel_crashlog::record::SplitKey;
+use std::path::{Path, PathBuf};
+
+pub fn split(input_file: &Path, by: SplitBy) -> Result<(), Error> {
+    let crashlog = CrashLog::from_slice(&std::fs::read(input_file)?)?;
+    let key = match by {
+        SplitBy::Socket => SplitKey::Socket,
+        SplitBy::Die => SplitKey::Die,
+    };
+
+    for ((socket_id, die_id), crashlog) in crashlog.split_by(key) {
+        let mut path = PathBuf::from(input_file);
+        if let Some(filename) = path.file_stem() {
+            let suffix = match die_id {
+                Some(die_id) => format!("socket{socket_id}_die{die_id}"),
+                None => format!("socket{socket_id}"),
+            };
+            path.set_file_name(format!(
+                "{}_{suffix}.crashlog",
+                PathBuf::from(filename).display()
+            ))
+        }
+
+        // The regions stored in the Box records are already part of the Box payloads.
+        let bytes: Vec<u8> = crashlog
+            .regions
+            .iter()
+            .flat_map(|region| region.records.iter())
+            .filter(|record| record.context.parent_header.is_none())
+            .flat_map(|record| record.data.iter().copied())
+            .collect();
+
+        println!("{}", path.display());
+        std::fs::write(&path, bytes)
+            .inspect_err(|err| log::error!("Failed to write file: {err}"))?;
+    }
+    Ok(())
+}
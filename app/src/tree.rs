@@ -0,0 +1,68 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use intel_crashlog::prelude::*;
+use std::io::IsTerminal;
+
+const BOLD: &str = "\x1b[1m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Returns true if the tree can be colorized (i.e. the standard output is a terminal and the
+/// `NO_COLOR` variable is not set).
+pub(crate) fn use_colors() -> bool {
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+/// Prints the register tree as an indented list of nodes.
+pub(crate) struct TreeWriter {
+    /// Prints the descriptions of the nodes after their value
+    pub descriptions: bool,
+    /// Colorizes the output using ANSI escape codes
+    pub colors: bool,
+}
+
+impl TreeWriter {
+    fn paint(&self, color: &'static str, text: &str) -> String {
+        if self.colors {
+            format!("{color}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn write_node<O: std::io::Write>(
+        &self,
+        output: &mut O,
+        node: &Node,
+        depth: usize,
+    ) -> Result<(), Error> {
+        let indent = "  ".repeat(depth);
+        let mut line = match node.formatted_value() {
+            Some(value) => format!("{indent}{} = {}", node.name, self.paint(GREEN, &value)),
+            None => format!("{indent}{}", self.paint(BOLD, &node.name)),
+        };
+        if let Some(name) = &node.value_name {
+            line.push_str(&format!(" ({})", self.paint(YELLOW, name)));
+        }
+        if self.descriptions && !node.description.is_empty() {
+            line.push_str(&format!("  {}", self.paint(DIM, &node.description)));
+        }
+        writeln!(output, "{line}")?;
+
+        for child in node.children() {
+            self.write_node(output, child, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the children of the given node. The root node itself is not printed.
+    pub fn write<O: std::io::Write>(&self, mut output: O, root: &Node) -> Result<(), Error> {
+        for child in root.children() {
+            self.write_node(&mut output, child, 0)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use intel_crashlog::analysis::{self, mca};
+use intel_crashlog::prelude::*;
+use std::path::Path;
+
+fn triage_file<T: CollateralTree>(
+    cm: &mut CollateralManager<T>,
+    input: &Path,
+) -> Result<(), Error> {
+    let crashlog = CrashLog::from_slice(&std::fs::read(input)?)?;
+    let nodes = crashlog.decode(cm);
+    let findings = analysis::analyze(&nodes);
+    if findings.is_empty() {
+        println!("No failure identified");
+    }
+
+    for finding in findings {
+        println!("{}", finding.class);
+        for (path, value) in finding.evidence {
+            println!("  {path}: 0x{value:x}");
+        }
+    }
+
+    let threads = analysis::stuck_threads(&nodes);
+    if !threads.is_empty() {
+        println!("Stuck threads");
+        for thread in threads {
+            println!("  {thread}");
+        }
+    }
+
+    let banks = mca::interpret(&nodes);
+    if !banks.is_empty() {
+        println!("Machine check errors");
+        for (path, bank) in banks {
+            println!("  {path}: {bank}");
+        }
+    }
+    Ok(())
+}
+
+pub fn triage<T, P>(cm: &mut CollateralManager<T>, input_files: &[P])
+where
+    T: CollateralTree,
+    P: AsRef<Path>,
+{
+    for input_file in input_files {
+        let input_file = input_file.as_ref();
+        if input_files.len() > 1 {
+            println!("\n{}:\n", input_file.display());
+        }
+        if let Err(err) = triage_file(cm, input_file) {
+            log::error!("Error: {err}")
+        }
+    }
+}
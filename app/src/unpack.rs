@@ -29,6 +29,27 @@ fn unpack_evtx(evtx: &Path) -> Result<(), Error> {
     Ok(())
 }
 
+fn unpack_report(report: &Path) -> Result<(), Error> {
+    let bytes = std::fs::read(report)?;
+    let crashlogs = if let Some("dmp") = report.extension().and_then(|p| p.to_str()) {
+        CrashLog::from_windows_dump(&bytes)
+    } else {
+        CrashLog::from_windows_report(&bytes)
+    }
+    .inspect_err(|err| log::error!("Failed to unpack report file: {err}"))?;
+    let mut path = PathBuf::from(report);
+    for (i, crashlog) in crashlogs.iter().enumerate() {
+        if let Some(filename) = report.file_stem() {
+            path.set_file_name(format!(
+                "{}-{i}.crashlog",
+                PathBuf::from(filename).display()
+            ))
+        }
+        write_file(&path, &crashlog.to_bytes())?;
+    }
+    Ok(())
+}
+
 fn unpack_crashlog(input_file: &Path) -> Result<(), Error> {
     let crashlog = CrashLog::from_slice(&std::fs::read(input_file)?)?;
     let path_prefix = PathBuf::from(input_file);
@@ -53,5 +74,8 @@ pub fn unpack(input_file: &Path) -> Result<(), Error> {
             return unpack_evtx(input_file);
         }
     }
+    if let Some("dmp" | "wer") = input_file.extension().and_then(|p| p.to_str()) {
+        return unpack_report(input_file);
+    }
     unpack_crashlog(input_file)
 }
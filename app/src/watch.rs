@@ -0,0 +1,95 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use intel_crashlog::extract::Watcher;
+use intel_crashlog::prelude::*;
+use intel_crashlog::state::ExtractionState;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Actions performed on each new Crash Log
+pub(crate) struct Actions<'a> {
+    /// Writes the decoded register tree in a JSON file next to the Crash Log.
+    pub decode: bool,
+    /// Runs the given program with the path of the Crash Log (and of the decoded JSON file, if
+    /// any) as arguments.
+    pub exec: Option<&'a Path>,
+}
+
+fn decode<T: CollateralTree>(
+    cm: &mut CollateralManager<T>,
+    crashlog: &CrashLog,
+    path: &Path,
+) -> Result<PathBuf, Error> {
+    let path = path.with_extension("json");
+    let file = std::fs::File::create(&path)?;
+    serde_json::to_writer_pretty(file, &crashlog.decode(cm))?;
+    println!("{}", path.display());
+    Ok(path)
+}
+
+fn exec(program: &Path, source: &str, paths: &[PathBuf]) {
+    let status = Command::new(program)
+        .args(paths)
+        .env("ICLG_SOURCE", source)
+        .status();
+    match status {
+        Ok(status) if !status.success() => {
+            log::warn!("{} exited with {status}", program.display())
+        }
+        Ok(_) => (),
+        Err(err) => log::error!("Cannot run {}: {err}", program.display()),
+    }
+}
+
+pub fn watch<T: CollateralTree>(
+    cm: &mut CollateralManager<T>,
+    output_dir: &Path,
+    interval: u64,
+    state_path: Option<&Path>,
+    actions: &Actions,
+) -> Result<(), Error> {
+    let state = match state_path {
+        Some(state_path) => ExtractionState::load(state_path)?,
+        None => ExtractionState::default(),
+    };
+    let interval = Duration::from_secs(interval);
+    let mut watcher = Watcher::new(interval).with_state(state);
+    std::fs::create_dir_all(output_dir)?;
+
+    loop {
+        let crashlogs = watcher.poll();
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        for (i, (source, crashlog)) in crashlogs.iter().enumerate() {
+            log::info!("New Crash Log extracted from {source}");
+            let path = output_dir.join(format!("{timestamp}-{i}.crashlog"));
+            println!("{}", path.display());
+            std::fs::write(&path, crashlog.to_bytes())?;
+
+            let mut paths = vec![path];
+            if actions.decode {
+                match decode(cm, crashlog, &paths[0]) {
+                    Ok(path) => paths.push(path),
+                    Err(err) => log::error!("Failed to decode Crash Log: {err}"),
+                }
+            }
+
+            if let Some(program) = actions.exec {
+                exec(program, source, &paths);
+            }
+        }
+
+        if !crashlogs.is_empty()
+            && let Some(state_path) = state_path
+        {
+            watcher.state().save(state_path)?;
+        }
+
+        std::thread::sleep(interval);
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+extern crate cbindgen;
+
+use std::env;
+
+/// Functions that must be provided by the firmware linking the library.
+const PLATFORM_HOOKS: &str = "
+/* Provided by the firmware */
+void *crashlog_fw_alloc(size_t size, size_t align);
+void crashlog_fw_free(void *ptr);
+void crashlog_fw_panic(void);
+";
+
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    cargo_emit::rerun_if_changed!("src");
+
+    cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(cbindgen::Config {
+            language: cbindgen::Language::C,
+            include_guard: Some("CRASHLOG_FW_H".into()),
+            no_includes: true,
+            sys_includes: vec!["stdbool.h".into(), "stddef.h".into(), "stdint.h".into()],
+            after_includes: Some(PLATFORM_HOOKS.into()),
+            package_version: true,
+            ..cbindgen::Config::default()
+        })
+        .generate()
+        .map_or_else(
+            |error| match error {
+                cbindgen::Error::ParseSyntaxError { .. } => {}
+                e => panic!("{e:?}"),
+            },
+            |bindings| {
+                bindings.write_to_file("target/include/intel_crashlog_fw.h");
+            },
+        );
+}
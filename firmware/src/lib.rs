@@ -0,0 +1,259 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Freestanding C interface to the Intel Crash Log parsing functions.
+//!
+//! This library exposes the header parsing, the region walking, and the checksum validation
+//! logic of the `intel_crashlog` crate to BIOS and BMC firmware. It does not depend on the Rust
+//! standard library nor on the collateral manager, and aborts on panic.
+//!
+//! The firmware linking this library must provide the following functions:
+//!
+//! - `void *crashlog_fw_alloc(size_t size, size_t align)`: allocates memory.
+//! - `void crashlog_fw_free(void *ptr)`: releases the memory allocated by `crashlog_fw_alloc`.
+//! - `void crashlog_fw_panic(void)`: handles unrecoverable errors. Must not return.
+
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(test))]
+mod platform;
+#[cfg(test)]
+mod tests;
+
+use core::ffi::c_void;
+use core::slice;
+use intel_crashlog::header::Header;
+use intel_crashlog::record::Record;
+use intel_crashlog::region::Region;
+
+/// Status returned by the functions of this library.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrashLogFwStatus {
+    /// The operation completed successfully.
+    Success,
+    /// One of the pointers passed to the function is `NULL`.
+    InvalidArgument,
+    /// The buffer does not start with a valid Crash Log record header.
+    InvalidHeader,
+    /// The buffer starts with a termination marker.
+    TerminationMarker,
+}
+
+/// Result of the validation of the checksum of a Crash Log record.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrashLogFwChecksum {
+    /// The checksum of the record is valid.
+    Valid,
+    /// The checksum of the record is invalid.
+    Invalid,
+    /// The record does not have any checksum.
+    NotPresent,
+}
+
+/// Fields of a Crash Log record header.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrashLogFwHeader {
+    /// Raw value of the version field
+    pub version: u32,
+    /// Revision of the record
+    pub revision: u32,
+    /// Type of the header
+    pub header_type: u16,
+    /// Product ID
+    pub product_id: u32,
+    /// Record type
+    pub record_type: u8,
+    /// Indicates if the record has been consumed
+    pub consumed: bool,
+    /// Indicates if a checksum is present at the end of the record
+    pub checksum_present: bool,
+    /// Socket ID
+    pub socket_id: u8,
+    /// Size of the header in bytes
+    pub header_size: usize,
+    /// Size of the record in bytes
+    pub record_size: usize,
+    /// Offset of the extended section of the record in bytes. Zero if the record does not have
+    /// any extended section.
+    pub extended_record_offset: usize,
+}
+
+impl From<&Header> for CrashLogFwHeader {
+    fn from(header: &Header) -> Self {
+        CrashLogFwHeader {
+            version: header.version.as_u32(),
+            revision: header.version.revision,
+            header_type: header.version.header_type,
+            product_id: header.version.product_id,
+            record_type: header.version.record_type,
+            consumed: header.version.consumed,
+            checksum_present: header.version.cldic,
+            socket_id: header.socket_id(),
+            header_size: header.header_size(),
+            record_size: header.record_size(),
+            extended_record_offset: header.extended_record_offset().unwrap_or(0),
+        }
+    }
+}
+
+/// Crash Log record found while walking a region.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct CrashLogFwRecord {
+    /// Offset of the record in the region in bytes
+    pub offset: usize,
+    /// Number of bytes of the record available in the region
+    pub size: usize,
+    /// Indicates if the record is truncated
+    pub truncated: bool,
+    /// Result of the validation of the checksum of the record
+    pub checksum: CrashLogFwChecksum,
+    /// Header of the record
+    pub header: CrashLogFwHeader,
+}
+
+/// Function called for each record found by [`crashlog_fw_walk_region`].
+///
+/// The `record` pointer is only valid during the call. Returning `false` stops the walk.
+pub type CrashLogFwRecordCallback =
+    extern "C" fn(record: *const CrashLogFwRecord, context: *mut c_void) -> bool;
+
+impl From<Option<bool>> for CrashLogFwChecksum {
+    fn from(checksum: Option<bool>) -> Self {
+        match checksum {
+            Some(true) => CrashLogFwChecksum::Valid,
+            Some(false) => CrashLogFwChecksum::Invalid,
+            None => CrashLogFwChecksum::NotPresent,
+        }
+    }
+}
+
+fn read_header(data: &[u8]) -> Result<Header, CrashLogFwStatus> {
+    match Header::from_slice(data) {
+        Ok(Some(header)) => Ok(header),
+        Ok(None) => Err(CrashLogFwStatus::TerminationMarker),
+        Err(_) => Err(CrashLogFwStatus::InvalidHeader),
+    }
+}
+
+/// Parses the Crash Log record header stored at the beginning of the buffer pointed by `data`.
+///
+/// The fields of the header are written in the structure pointed by the `header` argument.
+///
+/// # Safety
+///
+/// The `data` pointer must point to a valid memory region of `size` bytes.
+///
+/// The `header` pointer must point to a writable [`CrashLogFwHeader`] structure.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crashlog_fw_parse_header(
+    data: *const u8,
+    size: usize,
+    header: *mut CrashLogFwHeader,
+) -> CrashLogFwStatus {
+    if data.is_null() || header.is_null() {
+        return CrashLogFwStatus::InvalidArgument;
+    }
+
+    let data = unsafe { slice::from_raw_parts(data, size) };
+    match read_header(data) {
+        Ok(parsed) => {
+            unsafe { *header = CrashLogFwHeader::from(&parsed) };
+            CrashLogFwStatus::Success
+        }
+        Err(status) => status,
+    }
+}
+
+/// Validates the checksum of the Crash Log record stored at the beginning of the buffer pointed
+/// by `data`.
+///
+/// The result of the validation is written in the enumeration pointed by the `checksum` argument.
+///
+/// # Safety
+///
+/// The `data` pointer must point to a valid memory region of `size` bytes.
+///
+/// The `checksum` pointer must point to a writable [`CrashLogFwChecksum`] enumeration.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crashlog_fw_validate_checksum(
+    data: *const u8,
+    size: usize,
+    checksum: *mut CrashLogFwChecksum,
+) -> CrashLogFwStatus {
+    if data.is_null() || checksum.is_null() {
+        return CrashLogFwStatus::InvalidArgument;
+    }
+
+    let data = unsafe { slice::from_raw_parts(data, size) };
+    match read_header(data) {
+        Ok(header) => {
+            let end = header.record_size().min(data.len());
+            let record = Record {
+                header,
+                data: data[..end].into(),
+                ..Default::default()
+            };
+            unsafe { *checksum = record.checksum().into() };
+            CrashLogFwStatus::Success
+        }
+        Err(status) => status,
+    }
+}
+
+/// Walks through the Crash Log records stored in the region pointed by `data`.
+///
+/// The `callback` function is called for each record found in the region with the `context`
+/// argument.
+///
+/// Returns the number of records visited. Zero is returned if one of the arguments is `NULL` or
+/// if the region does not contain any valid record.
+///
+/// # Safety
+///
+/// The `data` pointer must point to a valid memory region of `size` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crashlog_fw_walk_region(
+    data: *const u8,
+    size: usize,
+    callback: Option<CrashLogFwRecordCallback>,
+    context: *mut c_void,
+) -> usize {
+    let Some(callback) = callback else {
+        return 0;
+    };
+    if data.is_null() {
+        return 0;
+    }
+
+    let data = unsafe { slice::from_raw_parts(data, size) };
+    let Ok(region) = Region::from_slice(data) else {
+        return 0;
+    };
+
+    let mut offset = 0;
+    let mut visited = 0;
+    for record in region.records.iter() {
+        let record_size = record.header.record_size();
+        let info = CrashLogFwRecord {
+            offset,
+            size: record.data.len(),
+            truncated: record.data.len() < record_size,
+            checksum: record.checksum().into(),
+            header: CrashLogFwHeader::from(&record.header),
+        };
+
+        visited += 1;
+        if !callback(&info, context) {
+            break;
+        }
+        offset += record_size;
+    }
+
+    visited
+}
@@ -0,0 +1,38 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Services that must be provided by the firmware linking the library.
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::ffi::c_void;
+use core::panic::PanicInfo;
+
+unsafe extern "C" {
+    /// Allocates `size` bytes aligned on `align` bytes. Returns `NULL` if the memory cannot be
+    /// allocated.
+    fn crashlog_fw_alloc(size: usize, align: usize) -> *mut c_void;
+    /// Releases the memory previously allocated by `crashlog_fw_alloc`.
+    fn crashlog_fw_free(ptr: *mut c_void);
+    /// Called when the library encounters an unrecoverable error. Must not return.
+    fn crashlog_fw_panic() -> !;
+}
+
+struct PlatformAllocator;
+
+unsafe impl GlobalAlloc for PlatformAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { crashlog_fw_alloc(layout.size(), layout.align()) as *mut u8 }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+        unsafe { crashlog_fw_free(ptr as *mut c_void) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PlatformAllocator = PlatformAllocator;
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    unsafe { crashlog_fw_panic() }
+}
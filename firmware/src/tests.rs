@@ -0,0 +1,132 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::*;
+use core::ptr;
+
+const MCA: &[u8] = include_bytes!("../../lib/tests/samples/dummy_mca_rev1.crashlog");
+const AGENT: &[u8] = include_bytes!("../../lib/tests/samples/dummy_crashlog_agent_rev1.crashlog");
+
+fn parse_header(data: &[u8]) -> (CrashLogFwStatus, CrashLogFwHeader) {
+    let mut header = CrashLogFwHeader::default();
+    let status = unsafe { crashlog_fw_parse_header(data.as_ptr(), data.len(), &mut header) };
+    (status, header)
+}
+
+fn validate_checksum(data: &[u8]) -> (CrashLogFwStatus, CrashLogFwChecksum) {
+    let mut checksum = CrashLogFwChecksum::NotPresent;
+    let status = unsafe { crashlog_fw_validate_checksum(data.as_ptr(), data.len(), &mut checksum) };
+    (status, checksum)
+}
+
+/// Records visited by [`crashlog_fw_walk_region`] and number of records to visit
+struct Walk {
+    records: Vec<CrashLogFwRecord>,
+    limit: usize,
+}
+
+extern "C" fn collect(record: *const CrashLogFwRecord, context: *mut c_void) -> bool {
+    let walk = unsafe { &mut *(context as *mut Walk) };
+    walk.records.push(unsafe { *record });
+    walk.records.len() < walk.limit
+}
+
+fn walk_region(data: &[u8], limit: usize) -> (usize, Vec<CrashLogFwRecord>) {
+    let mut walk = Walk {
+        records: Vec::new(),
+        limit,
+    };
+    let context = &mut walk as *mut Walk as *mut c_void;
+    let visited =
+        unsafe { crashlog_fw_walk_region(data.as_ptr(), data.len(), Some(collect), context) };
+    (visited, walk.records)
+}
+
+#[test]
+fn parse_header_fields() {
+    let (status, header) = parse_header(MCA);
+    assert_eq!(status, CrashLogFwStatus::Success);
+    assert_eq!(header.version, 0x7e07a301);
+    assert_eq!(header.record_type, 0x3e);
+    assert_eq!(header.product_id, 0x7a);
+    assert_eq!(header.header_type, 3);
+    assert_eq!(header.revision, 1);
+    assert!(header.checksum_present);
+    assert!(!header.consumed);
+    assert_eq!(header.record_size, MCA.len());
+}
+
+#[test]
+fn parse_header_errors() {
+    assert_eq!(parse_header(&[0; 8]).0, CrashLogFwStatus::TerminationMarker);
+    // Truncated type 3 header
+    assert_eq!(parse_header(&MCA[..12]).0, CrashLogFwStatus::InvalidHeader);
+
+    let status = unsafe { crashlog_fw_parse_header(ptr::null(), 0, ptr::null_mut()) };
+    assert_eq!(status, CrashLogFwStatus::InvalidArgument);
+}
+
+#[test]
+fn checksum() {
+    // Store the checksum in the last dword of the record.
+    let mut record = MCA.to_vec();
+    let end = record.len() - 4;
+    let sum = record[..end]
+        .chunks_exact(4)
+        .map(|dword| u32::from_le_bytes(dword.try_into().unwrap()))
+        .fold(0, u32::wrapping_add);
+    record[end..].copy_from_slice(&sum.wrapping_neg().to_le_bytes());
+    assert_eq!(
+        validate_checksum(&record),
+        (CrashLogFwStatus::Success, CrashLogFwChecksum::Valid)
+    );
+
+    let mut corrupted = record.clone();
+    corrupted[0x10] ^= 1;
+    assert_eq!(
+        validate_checksum(&corrupted),
+        (CrashLogFwStatus::Success, CrashLogFwChecksum::Invalid)
+    );
+
+    // Clear the CLDIC bit of the version.
+    corrupted[3] &= !0x40;
+    assert_eq!(
+        validate_checksum(&corrupted),
+        (CrashLogFwStatus::Success, CrashLogFwChecksum::NotPresent)
+    );
+
+    let status = unsafe { crashlog_fw_validate_checksum(MCA.as_ptr(), MCA.len(), ptr::null_mut()) };
+    assert_eq!(status, CrashLogFwStatus::InvalidArgument);
+}
+
+#[test]
+fn walk() {
+    let region = [MCA, AGENT].concat();
+    let (visited, records) = walk_region(&region, usize::MAX);
+    assert_eq!(visited, 2);
+    assert_eq!(records[0].offset, 0);
+    assert_eq!(records[0].header.record_type, 0x3e);
+    assert_eq!(records[1].offset, MCA.len());
+    assert_eq!(records[1].size, AGENT.len());
+    assert_eq!(records[1].header.record_type, 0x1c);
+    assert!(!records[1].truncated);
+
+    // The walk stops when the callback returns false.
+    assert_eq!(walk_region(&region, 1).0, 1);
+
+    // The last record is truncated.
+    let (visited, records) = walk_region(&region[..region.len() - 4], usize::MAX);
+    assert_eq!(visited, 2);
+    assert_eq!(records[1].size, AGENT.len() - 4);
+    assert!(records[1].truncated);
+}
+
+#[test]
+fn walk_invalid_arguments() {
+    let visited =
+        unsafe { crashlog_fw_walk_region(MCA.as_ptr(), MCA.len(), None, ptr::null_mut()) };
+    assert_eq!(visited, 0);
+    let visited =
+        unsafe { crashlog_fw_walk_region(ptr::null(), 0, Some(collect), ptr::null_mut()) };
+    assert_eq!(visited, 0);
+}
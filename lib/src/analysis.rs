@@ -0,0 +1,286 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! First-pass analysis of the decoded Crash Log records.
+//!
+//! The register tree is inspected with a set of heuristics that identify the likely class of the
+//! failure captured in the Crash Log:
+//!
+//! - the machine check banks (`status` fields stored in the `bank*` sections of the `mca`
+//!   sub-trees) report valid uncorrected errors, and the three-strike timeouts are identified by
+//!   the internal timer error code;
+//! - the `ierr` and `mcerr` fields report the catastrophic errors signaled by the platform;
+//! - the timeout and watchdog fields report the hangs detected by the firmware.
+//!
+//! The errors reported by the machine check banks can be further interpreted using the [mca]
+//! module, and the threads stuck in a three-strike timeout are identified by [three_strike].
+
+pub mod mca;
+
+use crate::CrashLog;
+use crate::collateral::{CollateralManager, CollateralTree};
+use crate::header::record_types;
+use crate::node::{self, Node, NodeType};
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use core::fmt;
+use mca::ErrorCode;
+#[cfg(feature = "std")]
+use std::fmt;
+
+/// `MCi_STATUS.VAL`: the register contains valid error information
+const MCI_STATUS_VAL: u64 = 1 << 63;
+/// `MCi_STATUS.UC`: the error has not been corrected
+const MCI_STATUS_UC: u64 = 1 << 61;
+/// `MCi_STATUS.MCACOD`: architectural error code
+const MCI_STATUS_MCACOD: u64 = 0xffff;
+/// Error code reported when the core doesn't retire any instruction for three consecutive
+/// timer periods
+const MCACOD_INTERNAL_TIMER_ERROR: u64 = 0x0400;
+
+/// Class of failure identified in the Crash Log, sorted from the most to the least specific
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FailureClass {
+    /// A core has stopped retiring instructions (internal timer error).
+    ThreeStrikeTimeout,
+    /// An internal error (IERR) has been signaled.
+    Ierr,
+    /// A machine check error (MCERR) has been signaled.
+    Mcerr,
+    /// A machine check bank reports an uncorrected error.
+    MachineCheck,
+    /// The firmware has detected a hang (timeout or watchdog expiration).
+    FirmwareHang,
+}
+
+impl fmt::Display for FailureClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            FailureClass::ThreeStrikeTimeout => "Three-strike timeout",
+            FailureClass::Ierr => "IERR",
+            FailureClass::Mcerr => "MCERR",
+            FailureClass::MachineCheck => "Machine check",
+            FailureClass::FirmwareHang => "Firmware hang",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Failure identified in the Crash Log along with the fields supporting it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    /// Class of the failure
+    pub class: FailureClass,
+    /// Path and value of the fields supporting the finding
+    pub evidence: Vec<(String, u64)>,
+}
+
+fn classify(node: &Node, value: u64, in_bank: bool) -> Option<FailureClass> {
+    let name = node.name.as_str();
+    if in_bank {
+        if name != "status" || value & MCI_STATUS_VAL == 0 {
+            None
+        } else if value & MCI_STATUS_MCACOD == MCACOD_INTERNAL_TIMER_ERROR {
+            Some(FailureClass::ThreeStrikeTimeout)
+        } else if value & MCI_STATUS_UC != 0 {
+            Some(FailureClass::MachineCheck)
+        } else {
+            None
+        }
+    } else if value == 0 {
+        None
+    } else if name.contains("ierr") {
+        Some(FailureClass::Ierr)
+    } else if name.contains("mcerr") {
+        Some(FailureClass::Mcerr)
+    } else if name.ends_with("timeout") || name.contains("watchdog") || name.starts_with("wdt") {
+        Some(FailureClass::FirmwareHang)
+    } else {
+        None
+    }
+}
+
+fn walk(node: &Node, path: &str, in_mca: bool, findings: &mut Vec<Finding>) {
+    let in_mca = in_mca || node.name == "mca";
+    let in_bank = in_mca && node.name.starts_with("bank");
+
+    for child in node.children() {
+        let path = if path.is_empty() {
+            node::escape_name(&child.name).into_owned()
+        } else {
+            format!("{path}.{}", node::escape_name(&child.name))
+        };
+
+        let NodeType::Field { value } = child.kind else {
+            walk(child, &path, in_mca, findings);
+            continue;
+        };
+
+        let Some(class) = classify(child, value, in_bank) else {
+            continue;
+        };
+
+        match findings.iter_mut().find(|finding| finding.class == class) {
+            Some(finding) => finding.evidence.push((path, value)),
+            None => findings.push(Finding {
+                class,
+                evidence: vec![(path, value)],
+            }),
+        }
+    }
+}
+
+/// Identifies the failures reported in a register tree.
+///
+/// The findings are sorted from the most to the least specific class of failure.
+///
+/// ```
+/// use intel_crashlog::analysis::{self, FailureClass};
+/// use intel_crashlog::prelude::*;
+///
+/// let mut bank = Node::section("bank3");
+/// bank.add(Node::field("status", 0xbe000000e1840400));
+/// let mut mca = Node::section("mca");
+/// mca.add(bank);
+/// let mut root = Node::root();
+/// root.add(mca);
+///
+/// let findings = analysis::analyze(&root);
+/// assert_eq!(findings[0].class, FailureClass::ThreeStrikeTimeout);
+/// assert_eq!(
+///     findings[0].evidence,
+///     vec![("mca.bank3.status".to_string(), 0xbe000000e1840400)]
+/// );
+/// ```
+pub fn analyze(node: &Node) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    walk(node, "", false, &mut findings);
+    findings.sort_by_key(|finding| finding.class);
+    findings
+}
+
+/// Thread that stopped retiring instructions, as reported by a core record
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StuckThread {
+    /// Path to the section of the thread in the register tree
+    pub path: String,
+    /// Module, core, and thread IDs read from the `whoami` field of the record header, if defined
+    pub module_id: Option<u64>,
+    pub core_id: Option<u64>,
+    pub thread_id: Option<u64>,
+    /// Path to the machine check bank reporting the internal timer error
+    pub bank: String,
+    /// Linear instruction pointer (LIP) of the thread when the timeout occurred
+    pub lip: Option<u64>,
+}
+
+impl fmt::Display for StuckThread {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ids = [
+            ("module", self.module_id),
+            ("core", self.core_id),
+            ("thread", self.thread_id),
+        ];
+        let location: Vec<String> = ids
+            .iter()
+            .filter_map(|(level, id)| id.map(|id| format!("{level} {id}")))
+            .collect();
+        if location.is_empty() {
+            write!(f, "{}", self.path)?;
+        } else {
+            write!(f, "{} ({})", location.join(", "), self.path)?;
+        }
+        if let Some(lip) = self.lip {
+            write!(f, ": LIP 0x{lip:x}")?;
+        }
+        Ok(())
+    }
+}
+
+fn walk_threads(node: &Node, path: &str, threads: &mut Vec<StuckThread>) {
+    for child in node.children() {
+        let path = if path.is_empty() {
+            node::escape_name(&child.name).into_owned()
+        } else {
+            format!("{path}.{}", node::escape_name(&child.name))
+        };
+
+        if child.get_by_path("hdr.whoami").is_none() {
+            walk_threads(child, &path, threads);
+            continue;
+        }
+
+        let timer_error = mca::interpret(child)
+            .into_iter()
+            .find(|(_, bank)| bank.error_code() == ErrorCode::InternalTimer);
+        if let Some((bank, _)) = timer_error {
+            threads.push(StuckThread {
+                module_id: child.get_value_by_path("hdr.whoami.module_id"),
+                core_id: child.get_value_by_path("hdr.whoami.core_id"),
+                thread_id: child.get_value_by_path("hdr.whoami.thread_id"),
+                bank: format!("{path}.{bank}"),
+                lip: child.get_value_by_path("arch_state.lip"),
+                path,
+            });
+        }
+    }
+}
+
+/// Identifies the threads stuck in a three-strike timeout in a register tree.
+///
+/// The sections of the threads are recognized by the `hdr.whoami` field of the core records. A
+/// thread is stuck if one of its machine check banks reports an internal timer error: the
+/// thread has not retired any instruction for three consecutive timer periods.
+pub fn stuck_threads(node: &Node) -> Vec<StuckThread> {
+    let mut threads = Vec::new();
+    walk_threads(node, "", &mut threads);
+    threads
+}
+
+/// Decodes the core records (PCORE and ECORE) of the Crash Log and identifies the threads stuck
+/// in a three-strike timeout, along with their instruction pointer.
+///
+/// See [stuck_threads].
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::analysis;
+/// use intel_crashlog::prelude::*;
+///
+/// let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+/// let crashlog = CrashLog::from_slice(&data).unwrap();
+/// let mut cm = CollateralManager::embedded_tree().unwrap();
+///
+/// let threads = analysis::three_strike(&crashlog, &mut cm);
+/// let thread = threads
+///     .iter()
+///     .find(|thread| thread.path == "pcore.core0.thread0.thread")
+///     .unwrap();
+/// assert_eq!(thread.lip, Some(0xfffff80577036530));
+/// ```
+pub fn three_strike<T: CollateralTree>(
+    crashlog: &CrashLog,
+    cm: &mut CollateralManager<T>,
+) -> Vec<StuckThread> {
+    crashlog
+        .regions
+        .iter()
+        .flat_map(|region| region.records.iter())
+        .filter(|record| {
+            let record_type = record.header.version.record_type;
+            record_type == record_types::PCORE || record_type == record_types::ECORE
+        })
+        .flat_map(|record| stuck_threads(&record.decode(cm)))
+        .collect()
+}
+
+impl CrashLog {
+    /// Decodes the Crash Log and identifies the failures reported in the records.
+    ///
+    /// See [analyze].
+    pub fn triage<T: CollateralTree>(&self, cm: &mut CollateralManager<T>) -> Vec<Finding> {
+        analyze(&self.decode(cm))
+    }
+}
@@ -0,0 +1,596 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Interpretation of the architectural machine check registers.
+//!
+//! The `status`, `addr`, and `misc` fields stored in the `bank*` sections of the `mca` sub-trees
+//! hold the `MCi_STATUS`, `MCi_ADDR`, and `MCi_MISC` registers of the machine check banks. The
+//! architectural error code (MCACOD) of the status register is translated into an
+//! [ErrorCode] following the compound error code encoding of the Intel(R) 64 and IA-32
+//! Architectures Software Developer's Manual (Volume 3B, "Interpreting the MCA Error Codes").
+//! The model-specific error code (MSCOD) is reported as is.
+
+use crate::CrashLog;
+use crate::collateral::{CollateralManager, CollateralTree};
+use crate::node::{self, Node, NodeType};
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+#[cfg(not(feature = "std"))]
+use core::fmt;
+#[cfg(feature = "std")]
+use std::fmt;
+
+/// `MCi_STATUS.VAL`: the register contains valid error information
+const MCI_STATUS_VAL: u64 = 1 << 63;
+/// `MCi_STATUS.OVER`: an error occurred while the results of a previous error were still in the
+/// register
+const MCI_STATUS_OVER: u64 = 1 << 62;
+/// `MCi_STATUS.UC`: the error has not been corrected
+const MCI_STATUS_UC: u64 = 1 << 61;
+/// `MCi_STATUS.MISCV`: the `MCi_MISC` register contains additional information
+const MCI_STATUS_MISCV: u64 = 1 << 59;
+/// `MCi_STATUS.ADDRV`: the `MCi_ADDR` register contains the address of the error
+const MCI_STATUS_ADDRV: u64 = 1 << 58;
+/// `MCi_STATUS.PCC`: the state of the processor might have been corrupted
+const MCI_STATUS_PCC: u64 = 1 << 57;
+
+/// Cache level of the compound error codes (`LL`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheLevel {
+    L0,
+    L1,
+    L2,
+    Generic,
+}
+
+impl CacheLevel {
+    fn from_bits(bits: u16) -> Self {
+        match bits & 0x3 {
+            0 => CacheLevel::L0,
+            1 => CacheLevel::L1,
+            2 => CacheLevel::L2,
+            _ => CacheLevel::Generic,
+        }
+    }
+}
+
+impl fmt::Display for CacheLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            CacheLevel::L0 => "L0",
+            CacheLevel::L1 => "L1",
+            CacheLevel::L2 => "L2",
+            CacheLevel::Generic => "generic level",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Transaction type of the compound error codes (`TT`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionType {
+    Instruction,
+    Data,
+    Generic,
+}
+
+impl TransactionType {
+    fn from_bits(bits: u16) -> Option<Self> {
+        match bits & 0x3 {
+            0 => Some(TransactionType::Instruction),
+            1 => Some(TransactionType::Data),
+            2 => Some(TransactionType::Generic),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TransactionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            TransactionType::Instruction => "instruction",
+            TransactionType::Data => "data",
+            TransactionType::Generic => "generic",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Request type of the cache hierarchy and bus errors (`RRRR`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Request {
+    Generic,
+    Read,
+    Write,
+    DataRead,
+    DataWrite,
+    InstructionFetch,
+    Prefetch,
+    Eviction,
+    Snoop,
+}
+
+impl Request {
+    fn from_bits(bits: u16) -> Option<Self> {
+        match bits & 0xf {
+            0 => Some(Request::Generic),
+            1 => Some(Request::Read),
+            2 => Some(Request::Write),
+            3 => Some(Request::DataRead),
+            4 => Some(Request::DataWrite),
+            5 => Some(Request::InstructionFetch),
+            6 => Some(Request::Prefetch),
+            7 => Some(Request::Eviction),
+            8 => Some(Request::Snoop),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Request {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Request::Generic => "generic request",
+            Request::Read => "read",
+            Request::Write => "write",
+            Request::DataRead => "data read",
+            Request::DataWrite => "data write",
+            Request::InstructionFetch => "instruction fetch",
+            Request::Prefetch => "prefetch",
+            Request::Eviction => "eviction",
+            Request::Snoop => "snoop",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Participation of the processor in the bus and interconnect errors (`PP`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Participation {
+    /// The processor originated the request.
+    Source,
+    /// The processor responded to the request.
+    Responder,
+    /// The processor observed the error as a third party.
+    Observer,
+    Generic,
+}
+
+impl fmt::Display for Participation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Participation::Source => "source",
+            Participation::Responder => "responder",
+            Participation::Observer => "observer",
+            Participation::Generic => "generic participation",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Memory transaction type of the memory controller errors (`MMM`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoryTransaction {
+    Generic,
+    Read,
+    Write,
+    AddressCommand,
+    Scrubbing,
+}
+
+impl MemoryTransaction {
+    fn from_bits(bits: u16) -> Option<Self> {
+        match bits & 0x7 {
+            0 => Some(MemoryTransaction::Generic),
+            1 => Some(MemoryTransaction::Read),
+            2 => Some(MemoryTransaction::Write),
+            3 => Some(MemoryTransaction::AddressCommand),
+            4 => Some(MemoryTransaction::Scrubbing),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MemoryTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            MemoryTransaction::Generic => "generic transaction",
+            MemoryTransaction::Read => "read",
+            MemoryTransaction::Write => "write",
+            MemoryTransaction::AddressCommand => "address/command",
+            MemoryTransaction::Scrubbing => "scrubbing",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Architectural error code (MCACOD) reported in the `MCi_STATUS` register
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorCode {
+    NoError,
+    Unclassified,
+    MicrocodeRomParity,
+    External,
+    Frc,
+    InternalParity,
+    SmmHandlerCodeAccessViolation,
+    /// The core has not retired any instruction for three consecutive timer periods.
+    InternalTimer,
+    Io,
+    InternalUnclassified,
+    GenericCacheHierarchy {
+        level: CacheLevel,
+    },
+    Tlb {
+        transaction: TransactionType,
+        level: CacheLevel,
+    },
+    MemoryController {
+        transaction: MemoryTransaction,
+        /// Memory channel, `None` if not specified
+        channel: Option<u8>,
+    },
+    CacheHierarchy {
+        request: Request,
+        transaction: TransactionType,
+        level: CacheLevel,
+    },
+    BusInterconnect {
+        participation: Participation,
+        timeout: bool,
+        request: Request,
+        /// Indicates if the request targets the I/O space instead of the memory space
+        io: bool,
+        level: CacheLevel,
+    },
+    /// Error code that doesn't follow the architectural encoding
+    Unknown(u16),
+}
+
+impl ErrorCode {
+    /// Decodes an architectural error code. The correction report filtering bit (bit 12) is
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::analysis::mca::{CacheLevel, ErrorCode, Request, TransactionType};
+    ///
+    /// assert_eq!(ErrorCode::from_mcacod(0x0400), ErrorCode::InternalTimer);
+    /// assert_eq!(
+    ///     ErrorCode::from_mcacod(0x0136),
+    ///     ErrorCode::CacheHierarchy {
+    ///         request: Request::DataRead,
+    ///         transaction: TransactionType::Data,
+    ///         level: CacheLevel::L2,
+    ///     }
+    /// );
+    /// ```
+    pub fn from_mcacod(mcacod: u16) -> Self {
+        let code = mcacod & !(1 << 12);
+        let level = CacheLevel::from_bits(code);
+        let decoded = match code {
+            0x0000 => Some(ErrorCode::NoError),
+            0x0001 => Some(ErrorCode::Unclassified),
+            0x0002 => Some(ErrorCode::MicrocodeRomParity),
+            0x0003 => Some(ErrorCode::External),
+            0x0004 => Some(ErrorCode::Frc),
+            0x0005 => Some(ErrorCode::InternalParity),
+            0x0006 => Some(ErrorCode::SmmHandlerCodeAccessViolation),
+            0x0400 => Some(ErrorCode::InternalTimer),
+            0x0e0b => Some(ErrorCode::Io),
+            _ if code & 0xfc00 == 0x0400 => Some(ErrorCode::InternalUnclassified),
+            _ if code & 0xfffc == 0x000c => Some(ErrorCode::GenericCacheHierarchy { level }),
+            _ if code & 0xfff0 == 0x0010 => TransactionType::from_bits(code >> 2)
+                .map(|transaction| ErrorCode::Tlb { transaction, level }),
+            _ if code & 0xff80 == 0x0080 => {
+                MemoryTransaction::from_bits(code >> 4).map(|transaction| {
+                    ErrorCode::MemoryController {
+                        transaction,
+                        channel: (code & 0xf != 0xf).then_some((code & 0xf) as u8),
+                    }
+                })
+            }
+            _ if code & 0xff00 == 0x0100 => Request::from_bits(code >> 4).and_then(|request| {
+                TransactionType::from_bits(code >> 2).map(|transaction| ErrorCode::CacheHierarchy {
+                    request,
+                    transaction,
+                    level,
+                })
+            }),
+            _ if code & 0xf800 == 0x0800 => {
+                let participation = match (code >> 9) & 0x3 {
+                    0 => Participation::Source,
+                    1 => Participation::Responder,
+                    2 => Participation::Observer,
+                    _ => Participation::Generic,
+                };
+                Request::from_bits(code >> 4).map(|request| ErrorCode::BusInterconnect {
+                    participation,
+                    timeout: code & (1 << 8) != 0,
+                    request,
+                    io: (code >> 2) & 0x3 == 0x2,
+                    level,
+                })
+            }
+            _ => None,
+        };
+        decoded.unwrap_or(ErrorCode::Unknown(mcacod))
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorCode::NoError => write!(f, "No error"),
+            ErrorCode::Unclassified => write!(f, "Unclassified error"),
+            ErrorCode::MicrocodeRomParity => write!(f, "Microcode ROM parity error"),
+            ErrorCode::External => write!(f, "External error"),
+            ErrorCode::Frc => write!(f, "Functional redundancy check error"),
+            ErrorCode::InternalParity => write!(f, "Internal parity error"),
+            ErrorCode::SmmHandlerCodeAccessViolation => {
+                write!(f, "SMM handler code access violation")
+            }
+            ErrorCode::InternalTimer => write!(f, "Internal timer error"),
+            ErrorCode::Io => write!(f, "I/O error"),
+            ErrorCode::InternalUnclassified => write!(f, "Internal unclassified error"),
+            ErrorCode::GenericCacheHierarchy { level } => {
+                write!(f, "Cache hierarchy error ({level})")
+            }
+            ErrorCode::Tlb { transaction, level } => {
+                write!(f, "TLB error ({level} {transaction})")
+            }
+            ErrorCode::MemoryController {
+                transaction,
+                channel,
+            } => {
+                write!(f, "Memory controller error ({transaction}")?;
+                if let Some(channel) = channel {
+                    write!(f, ", channel {channel}")?;
+                }
+                write!(f, ")")
+            }
+            ErrorCode::CacheHierarchy {
+                request,
+                transaction,
+                level,
+            } => write!(f, "Cache hierarchy error ({level} {transaction} {request})"),
+            ErrorCode::BusInterconnect {
+                participation,
+                timeout,
+                request,
+                io,
+                level,
+            } => {
+                let space = if *io { "I/O" } else { "memory" };
+                write!(
+                    f,
+                    "Bus and interconnect error ({level} {space} {request}, {participation}"
+                )?;
+                if *timeout {
+                    write!(f, ", timeout")?;
+                }
+                write!(f, ")")
+            }
+            ErrorCode::Unknown(mcacod) => write!(f, "Unknown error code 0x{mcacod:04x}"),
+        }
+    }
+}
+
+/// Addressing mode of the `MCi_ADDR` register, reported in the `MCi_MISC` register
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressMode {
+    SegmentOffset,
+    Linear,
+    Physical,
+    Memory,
+    Generic,
+    Reserved(u8),
+}
+
+impl fmt::Display for AddressMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressMode::SegmentOffset => write!(f, "segment offset"),
+            AddressMode::Linear => write!(f, "linear address"),
+            AddressMode::Physical => write!(f, "physical address"),
+            AddressMode::Memory => write!(f, "memory address"),
+            AddressMode::Generic => write!(f, "address"),
+            AddressMode::Reserved(mode) => write!(f, "address (mode {mode})"),
+        }
+    }
+}
+
+/// Registers of a machine check bank
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BankError {
+    /// Value of the `MCi_STATUS` register
+    pub status: u64,
+    /// Value of the `MCi_ADDR` register, if decoded
+    pub addr: Option<u64>,
+    /// Value of the `MCi_MISC` register, if decoded
+    pub misc: Option<u64>,
+}
+
+impl BankError {
+    /// Indicates if the bank contains valid error information.
+    pub fn valid(&self) -> bool {
+        self.status & MCI_STATUS_VAL != 0
+    }
+
+    /// Indicates if the error has not been corrected.
+    pub fn uncorrected(&self) -> bool {
+        self.status & MCI_STATUS_UC != 0
+    }
+
+    /// Indicates if another error occurred before the bank was cleared.
+    pub fn overflow(&self) -> bool {
+        self.status & MCI_STATUS_OVER != 0
+    }
+
+    /// Indicates if the state of the processor might have been corrupted.
+    pub fn processor_context_corrupt(&self) -> bool {
+        self.status & MCI_STATUS_PCC != 0
+    }
+
+    /// Returns the architectural error code (`MCi_STATUS[15:0]`).
+    pub fn mcacod(&self) -> u16 {
+        self.status as u16
+    }
+
+    /// Returns the model-specific error code (`MCi_STATUS[31:16]`).
+    pub fn mscod(&self) -> u16 {
+        (self.status >> 16) as u16
+    }
+
+    /// Returns the classification of the architectural error code.
+    pub fn error_code(&self) -> ErrorCode {
+        ErrorCode::from_mcacod(self.mcacod())
+    }
+
+    /// Returns the content of the `MCi_MISC` register if valid.
+    fn valid_misc(&self) -> Option<u64> {
+        self.misc.filter(|_| self.status & MCI_STATUS_MISCV != 0)
+    }
+
+    /// Returns the addressing mode of the address reported in the bank, if known.
+    pub fn address_mode(&self) -> Option<AddressMode> {
+        let mode = match (self.valid_misc()? >> 6) & 0x7 {
+            0 => AddressMode::SegmentOffset,
+            1 => AddressMode::Linear,
+            2 => AddressMode::Physical,
+            3 => AddressMode::Memory,
+            7 => AddressMode::Generic,
+            mode => AddressMode::Reserved(mode as u8),
+        };
+        Some(mode)
+    }
+
+    /// Returns the address of the error, if valid. The bits below the least significant valid
+    /// bit reported in the `MCi_MISC` register are cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::analysis::mca::{AddressMode, BankError};
+    ///
+    /// let bank = BankError {
+    ///     status: 0xbc00000000010136,
+    ///     addr: Some(0x12345678),
+    ///     misc: Some(0x86),
+    /// };
+    /// assert_eq!(bank.address(), Some(0x12345640));
+    /// assert_eq!(bank.address_mode(), Some(AddressMode::Physical));
+    /// ```
+    pub fn address(&self) -> Option<u64> {
+        let addr = self.addr.filter(|_| self.status & MCI_STATUS_ADDRV != 0)?;
+        let lsb = self.valid_misc().map_or(0, |misc| misc & 0x3f);
+        Some(addr & (u64::MAX << lsb))
+    }
+
+    /// Reads the registers of a machine check bank from its section in the register tree.
+    /// Returns `None` if the section doesn't have any `status` field.
+    pub fn from_node(bank: &Node) -> Option<Self> {
+        let field = |name| match bank.get(name)?.kind {
+            NodeType::Field { value } => Some(value),
+            _ => None,
+        };
+        Some(BankError {
+            status: field("status")?,
+            addr: field("addr"),
+            misc: field("misc"),
+        })
+    }
+}
+
+impl fmt::Display for BankError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.valid() {
+            return write!(f, "No valid error");
+        }
+
+        let severity = if self.uncorrected() {
+            "uncorrected"
+        } else {
+            "corrected"
+        };
+        write!(f, "{} ({severity}", self.error_code())?;
+        if self.processor_context_corrupt() {
+            write!(f, ", processor context corrupt")?;
+        }
+        if self.overflow() {
+            write!(f, ", overflow")?;
+        }
+        write!(f, "), MSCOD 0x{:04x}", self.mscod())?;
+        if let Some(address) = self.address() {
+            let mode = self.address_mode().unwrap_or(AddressMode::Generic);
+            write!(f, ", {mode} 0x{address:x}")?;
+        }
+        Ok(())
+    }
+}
+
+fn walk(node: &Node, path: &str, in_mca: bool, banks: &mut Vec<(String, BankError)>) {
+    let in_mca = in_mca || node.name == "mca";
+
+    for child in node.children() {
+        let path = if path.is_empty() {
+            node::escape_name(&child.name).into_owned()
+        } else {
+            format!("{path}.{}", node::escape_name(&child.name))
+        };
+
+        if in_mca
+            && child.name.starts_with("bank")
+            && let Some(bank) = BankError::from_node(child)
+        {
+            if bank.valid() {
+                banks.push((path, bank));
+            }
+            continue;
+        }
+        walk(child, &path, in_mca, banks);
+    }
+}
+
+/// Returns the machine check banks of a register tree that report a valid error, along with the
+/// path to their section.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::analysis::mca::{self, ErrorCode};
+/// use intel_crashlog::prelude::*;
+///
+/// let mut bank = Node::section("bank3");
+/// bank.add(Node::field("status", 0xbe000000e1840400));
+/// let mut mca = Node::section("mca");
+/// mca.add(bank);
+/// let mut root = Node::root();
+/// root.add(mca);
+///
+/// let banks = mca::interpret(&root);
+/// assert_eq!(banks[0].0, "mca.bank3");
+/// assert_eq!(banks[0].1.error_code(), ErrorCode::InternalTimer);
+/// assert_eq!(
+///     banks[0].1.to_string(),
+///     "Internal timer error (uncorrected, processor context corrupt), MSCOD 0xe184"
+/// );
+/// ```
+pub fn interpret(node: &Node) -> Vec<(String, BankError)> {
+    let mut banks = Vec::new();
+    walk(node, "", false, &mut banks);
+    banks
+}
+
+impl CrashLog {
+    /// Decodes the Crash Log and interprets the machine check banks that report a valid error.
+    ///
+    /// See [interpret].
+    pub fn machine_check_errors<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+    ) -> Vec<(String, BankError)> {
+        interpret(&self.decode(cm))
+    }
+}
@@ -49,6 +49,34 @@ impl Bert {
         }
     }
 
+    /// Parses the [Bert] ACPI table stored in a byte slice.
+    #[cfg(any(
+        all(target_os = "linux", feature = "std"),
+        feature = "physical_memory",
+        test
+    ))]
+    pub(crate) fn from_slice(s: &[u8]) -> Option<Bert> {
+        if !s.starts_with(b"BERT") {
+            return None;
+        }
+
+        Some(Bert {
+            header: SdtHeader {
+                signature: Signature::BERT,
+                length: u32::from_le_bytes(s.get(4..8)?.try_into().ok()?),
+                revision: *s.get(8)?,
+                checksum: *s.get(9)?,
+                oem_id: s.get(10..16)?.try_into().ok()?,
+                oem_table_id: s.get(16..24)?.try_into().ok()?,
+                oem_revision: u32::from_le_bytes(s.get(24..28)?.try_into().ok()?),
+                creator_id: u32::from_le_bytes(s.get(28..32)?.try_into().ok()?),
+                creator_revision: u32::from_le_bytes(s.get(32..36)?.try_into().ok()?),
+            },
+            region_length: u32::from_le_bytes(s.get(36..40)?.try_into().ok()?),
+            region: u64::from_le_bytes(s.get(40..48)?.try_into().ok()?),
+        })
+    }
+
     #[cfg(all(target_os = "uefi", feature = "extraction"))]
     unsafe fn raw_berr_from_phys_mem(&self) -> &[u8] {
         unsafe { core::slice::from_raw_parts(self.region as *const _, self.region_length as usize) }
@@ -124,7 +124,7 @@ impl GenericErrorDataEntry {
         let cper_section = CperSectionBody::from_slice(
             header.section,
             s.get(header.size()..header.size() + header.error_data_length as usize)?,
-        )?;
+        );
         Some(Self {
             header,
             cper_section,
@@ -1,7 +1,7 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
-use super::Berr;
+use super::{Berr, Bert};
 use crate::CrashLog;
 
 #[test]
@@ -30,3 +30,14 @@ fn crashlog_bert() {
     let berr = berr.unwrap();
     assert_eq!(berr.entries.len(), 2);
 }
+
+#[test]
+fn bert_table() {
+    let bytes = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let bert = Bert::from_slice(&bytes).unwrap();
+    let region_length = bert.region_length;
+    assert!(region_length > 0);
+    assert_eq!(bert.to_bytes(), bytes[..48]);
+
+    assert!(Bert::from_slice(b"BERR").is_none());
+}
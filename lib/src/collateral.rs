@@ -9,27 +9,57 @@
 //! - [`PVSS`]: uniquely identifies a product.
 //! - [`ItemPath`]: defines the location of the item within a given [`PVSS`]
 
+#[cfg(feature = "archive_collateral_tree")]
+mod archive;
+#[cfg(feature = "serialize")]
+mod bundle;
+mod cache;
 #[cfg(feature = "embedded_collateral_tree")]
 mod embedded;
 #[cfg(feature = "fs_collateral_tree")]
 mod fs;
+mod memory;
+mod overlay;
 mod path;
 mod pvss;
+mod resolver;
 mod target_info;
+#[cfg(feature = "collateral_verification")]
+mod verification;
 
 use crate::Error;
-use crate::header::Header;
+use crate::header::{Header, record_types};
+use crate::node::Node;
+use crate::record::{DecodeDefinition, DecodeOptions, Record, RecordDecoder};
 use crate::utils::Map;
 #[cfg(not(feature = "std"))]
-use alloc::{string::ToString, vec::Vec};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use cache::{DefinitionCache, DefinitionKey};
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
+#[cfg(feature = "archive_collateral_tree")]
+pub use archive::ArchiveTree;
+#[cfg(feature = "serialize")]
+pub use bundle::BundleTree;
+pub use cache::CacheStats;
 #[cfg(feature = "embedded_collateral_tree")]
 pub use embedded::EmbeddedTree;
 #[cfg(feature = "fs_collateral_tree")]
 pub use fs::FileSystemTree;
+pub use memory::MemoryCollateralTree;
+pub use overlay::OverlayTree;
 pub use path::ItemPath;
 pub use pvss::PVSS;
+pub use resolver::CollateralResolver;
 pub use target_info::TargetInfo;
+#[cfg(feature = "collateral_verification")]
+pub use verification::{ManifestStatus, Verification};
 
 #[derive(Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 struct ItemIndex {
@@ -37,6 +67,32 @@ struct ItemIndex {
     path: ItemPath,
 }
 
+/// Returns the [`PVSS`] at which the items of `pvss` are looked up, by order of precedence.
+///
+/// The items specific to the stepping take precedence over the items defined for `all` the
+/// steppings. When the security level is `all`, the highest security level is looked up first.
+fn fallbacks(pvss: &PVSS) -> Vec<PVSS> {
+    let steppings = match pvss.stepping.as_str() {
+        "all" => &["all"][..],
+        stepping => &[stepping, "all"],
+    };
+    let security_levels = match pvss.security.as_str() {
+        "all" => &["red", "white", "green", "all"][..],
+        security => &[security, "all"],
+    };
+
+    steppings
+        .iter()
+        .flat_map(|stepping| {
+            security_levels.iter().map(move |security| PVSS {
+                stepping: stepping.to_string(),
+                security: security.to_string(),
+                ..pvss.clone()
+            })
+        })
+        .collect()
+}
+
 /// A trait representing a data structure that provides a direct access to the product-specific
 /// collateral files.
 pub trait CollateralTree {
@@ -46,11 +102,25 @@ pub trait CollateralTree {
     fn search(&self, path: &ItemPath) -> Result<Vec<PVSS>, Error>;
 }
 
+/// Callback invoked on the register tree of each record once decoded.
+///
+/// See [`CollateralManager::add_post_decode_hook`].
+pub type PostDecodeHook = fn(&mut Node, &Record);
+
 /// Manages the product-specific collateral files required to decode the Crash Log records.
 #[derive(Default)]
 pub struct CollateralManager<T: CollateralTree> {
     tree: T,
     items: Map<ItemIndex, Vec<u8>>,
+    layout_overrides: Map<String, Vec<u8>>,
+    post_decode_hooks: Vec<PostDecodeHook>,
+    decoders: Map<(u8, Option<u32>), Box<dyn RecordDecoder>>,
+    decode_options: DecodeOptions,
+    definitions: DefinitionCache,
+    resolver: Option<Box<dyn CollateralResolver>>,
+    unresolved: Vec<(u32, ItemPath)>,
+    #[cfg(feature = "collateral_verification")]
+    verification: Option<Verification>,
     /// Maps the Crash Log product IDs into a data structure that stores various information
     /// about the associated product.
     pub target_info: Map<u32, TargetInfo>,
@@ -59,13 +129,37 @@ pub struct CollateralManager<T: CollateralTree> {
 impl<T: CollateralTree> CollateralManager<T> {
     /// Creates a collateral manager for a given collateral `tree`.
     pub fn new(tree: T) -> Result<Self, Error> {
-        let mut cm = Self {
+        let mut cm = Self::empty(tree);
+        cm.update_target_info()?;
+        Ok(cm)
+    }
+
+    fn empty(tree: T) -> Self {
+        Self {
             tree,
             items: Map::default(),
+            layout_overrides: Map::default(),
+            post_decode_hooks: Vec::new(),
+            decoders: Map::default(),
+            decode_options: DecodeOptions::default(),
+            definitions: DefinitionCache::default(),
+            resolver: None,
+            unresolved: Vec::new(),
+            #[cfg(feature = "collateral_verification")]
+            verification: None,
             target_info: Map::default(),
-        };
-        cm.update_target_info()?;
-        Ok(cm)
+        }
+    }
+
+    /// Reads an item from the collateral tree, and verifies its content if the collateral
+    /// manager has been created with a verifying key.
+    fn read_item(&self, pvss: &PVSS, path: &ItemPath) -> Result<Vec<u8>, Error> {
+        let item = self.tree.get(pvss, path)?;
+        #[cfg(feature = "collateral_verification")]
+        if let Some(verification) = &self.verification {
+            verification.check(pvss, path, &item)?;
+        }
+        Ok(item)
     }
 
     /// Returns the content of an item from the collateral tree using the [`PVSS`] of the target.
@@ -93,7 +187,7 @@ impl<T: CollateralTree> CollateralManager<T> {
         };
 
         if !self.items.contains_key(&index) {
-            let item = self.tree.get(&index.pvss, &index.path)?;
+            let item = self.read_item(&index.pvss, &index.path)?;
             self.items.insert(index.clone(), item);
         }
 
@@ -103,8 +197,12 @@ impl<T: CollateralTree> CollateralManager<T> {
             .ok_or(Error::InternalError)
     }
 
-    /// Similar to [`CollateralManager::get_item_with_pvss`] but ignores the `security` specified
-    /// in the [`PVSS`] and returns the item with the highest security level.
+    /// Similar to [`CollateralManager::get_item_with_pvss`] but falls back to the items defined
+    /// for `all` the steppings and security levels.
+    ///
+    /// The items specific to the `stepping` of the [`PVSS`] take precedence. When the `security`
+    /// of the [`PVSS`] is `all`, the item with the highest security level is returned. Otherwise,
+    /// the item of the given security level is returned if present.
     ///
     /// ```
     /// use intel_crashlog::prelude::*;
@@ -138,21 +236,17 @@ impl<T: CollateralTree> CollateralManager<T> {
     }
 
     fn fetch_item(&mut self, index: &ItemIndex) -> Result<(), Error> {
-        let security_levels = ["red", "white", "green", "all"];
-
-        for security in security_levels {
-            let pvss = PVSS {
-                security: security.to_string(),
-                ..index.pvss.clone()
-            };
-            match self.tree.get(&pvss, &index.path) {
+        for pvss in fallbacks(&index.pvss) {
+            match self.read_item(&pvss, &index.path) {
                 Ok(item) => {
                     self.items.insert(index.clone(), item);
                     return Ok(());
                 }
-                Err(Error::MissingCollateral(_, item)) => {
-                    log::debug!("No {security} {item} defined")
+                Err(Error::MissingCollateral(pvss, item)) => {
+                    log::debug!("No {} {} {item} defined", pvss.stepping, pvss.security)
                 }
+                #[cfg(feature = "collateral_verification")]
+                Err(err @ Error::UnverifiedCollateral(..)) => return Err(err),
                 Err(err) => log::warn!("Unexpected error while fetching item: {err}"),
             }
         }
@@ -180,4 +274,207 @@ impl<T: CollateralTree> CollateralManager<T> {
     ) -> Result<&[u8], Error> {
         self.get_item_with_pvs(header.pvss(self)?, path)
     }
+
+    /// Overrides the decode definitions of the records of a given type.
+    ///
+    /// The `layout` is used to decode all the records of type `record_type` (example: `MCA`)
+    /// instead of the decode definitions stored in the collateral tree, regardless of the product.
+    /// See [`crate::record::Record::decode_with_csv`] for the expected format.
+    ///
+    /// Returns [`Error::InvalidRecordTypeName`] if `record_type` is not a known record type.
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// cm.override_layout("MCA", b"name;offset;size;description\nmca.foo;0;8;".to_vec())
+    ///     .unwrap();
+    /// assert!(cm.override_layout("FOO", Vec::new()).is_err());
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let root = crashlog.decode(&mut cm);
+    /// assert_eq!(root.get_by_path("mca.foo").unwrap().kind, NodeType::Field { value: 0x1 });
+    /// ```
+    pub fn override_layout(&mut self, record_type: &str, layout: Vec<u8>) -> Result<(), Error> {
+        let record_type = record_types::from_name(record_type)
+            .and_then(record_types::name_of)
+            .ok_or_else(|| Error::InvalidRecordTypeName(record_type.to_string()))?;
+        self.layout_overrides
+            .insert(record_type.to_string(), layout);
+        self.definitions.clear();
+        Ok(())
+    }
+
+    pub(crate) fn layout_override(&self, record_type: &str) -> Option<&[u8]> {
+        self.layout_overrides
+            .get(record_type)
+            .map(|layout| layout.as_ref())
+    }
+
+    /// Sets the options used to decode the records.
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeOptions;
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// cm.override_layout("MCA", b"name;offset;size;description\nmca.hdr;0;128;".to_vec())
+    ///     .unwrap();
+    /// cm.set_decode_options(DecodeOptions {
+    ///     raw: true,
+    ///     ..DecodeOptions::default()
+    /// });
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let root = crashlog.decode(&mut cm);
+    /// assert!(root.get_by_path("mca.raw").is_some());
+    /// ```
+    pub fn set_decode_options(&mut self, options: DecodeOptions) {
+        self.decode_options = options;
+    }
+
+    pub(crate) fn decode_options(&self) -> &DecodeOptions {
+        &self.decode_options
+    }
+
+    /// Sets the maximum number of resolved decode definitions kept in memory.
+    ///
+    /// The decode definitions loaded from the collateral tree are cached along with their
+    /// includes and bitfields for each kind of record (product ID, record type, revision, and
+    /// die), so that decoding many similar records parses them only once. When the cache is full,
+    /// the least recently used decode definition is evicted. A capacity of zero disables the
+    /// cache. The default capacity is 64.
+    ///
+    /// The cache is keyed by the stepping and security level of the product found in
+    /// [`CollateralManager::target_info`], and is cleared when the target info is reloaded, a
+    /// resolver is set, or a layout is overridden.
+    pub fn set_cache_capacity(&mut self, capacity: usize) {
+        self.definitions.set_capacity(capacity);
+    }
+
+    /// Returns the statistics of the decode definition cache.
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// let mut cm = CollateralManager::file_system_tree(Path::new("tests/collateral")).unwrap();
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    ///
+    /// crashlog.decode(&mut cm);
+    /// crashlog.decode(&mut cm);
+    ///
+    /// let stats = cm.cache_stats();
+    /// assert_eq!(stats.misses, 1);
+    /// assert_eq!(stats.hits, 1);
+    /// assert_eq!(stats.entries, 1);
+    /// ```
+    pub fn cache_stats(&self) -> CacheStats {
+        self.definitions.stats()
+    }
+
+    pub(crate) fn cached_definition(
+        &mut self,
+        header: &Header,
+        decode_def: &str,
+    ) -> Option<Arc<DecodeDefinition>> {
+        let key = self.definition_key(header, decode_def);
+        self.definitions.get(&key)
+    }
+
+    pub(crate) fn cache_definition(
+        &mut self,
+        header: &Header,
+        decode_def: &str,
+        definition: Arc<DecodeDefinition>,
+    ) {
+        let key = self.definition_key(header, decode_def);
+        self.definitions.insert(key, definition);
+    }
+
+    fn definition_key(&self, header: &Header, decode_def: &str) -> DefinitionKey {
+        let target_info = self.target_info.get(&header.product_id());
+        DefinitionKey::new(header, target_info, decode_def)
+    }
+
+    /// Registers a callback invoked on the register tree of each record once decoded.
+    ///
+    /// The hooks run in the order of registration, before the record tree is merged into the
+    /// Crash Log tree. They can be used to add derived fields, annotate or scrub the decoded
+    /// content.
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// cm.add_post_decode_hook(|node, record| {
+    ///     if let Some(mca) = node.get_mut("mca") {
+    ///         mca.add(Node::field("socket", record.header.socket_id().into()));
+    ///     }
+    /// });
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let root = crashlog.decode(&mut cm);
+    /// assert_eq!(root.get_value_by_path("mca.socket"), Some(0));
+    /// ```
+    pub fn add_post_decode_hook(&mut self, hook: PostDecodeHook) {
+        self.post_decode_hooks.push(hook);
+    }
+
+    pub(crate) fn post_decode_hooks(&self) -> &[PostDecodeHook] {
+        &self.post_decode_hooks
+    }
+
+    /// Registers a decoder for the records of type `record_type`.
+    ///
+    /// When `product_id` is `None`, the decoder handles the records of all the products that
+    /// don't have a decoder registered for their specific product ID. The registered decoders
+    /// take precedence over the decode definitions stored in the collateral tree, but not over
+    /// the layouts set with [`CollateralManager::override_layout`].
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::header::record_types;
+    /// use intel_crashlog::record::RecordDecoder;
+    ///
+    /// struct McaDecoder;
+    ///
+    /// impl RecordDecoder for McaDecoder {
+    ///     fn decode(&self, record: &Record) -> Result<Node, Error> {
+    ///         let mut mca = Node::record("mca");
+    ///         mca.add(Node::field("size", record.data.len() as u64));
+    ///         let mut root = Node::root();
+    ///         root.add(mca);
+    ///         Ok(root)
+    ///     }
+    /// }
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// cm.register_decoder(record_types::MCA, None, Box::new(McaDecoder));
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let root = crashlog.decode(&mut cm);
+    /// assert_eq!(root.get_value_by_path("mca.size"), Some(data.len() as u64));
+    /// ```
+    pub fn register_decoder(
+        &mut self,
+        record_type: u8,
+        product_id: Option<u32>,
+        decoder: Box<dyn RecordDecoder>,
+    ) {
+        self.decoders.insert((record_type, product_id), decoder);
+    }
+
+    pub(crate) fn decoder(&self, header: &Header) -> Option<&dyn RecordDecoder> {
+        let record_type = header.version.record_type;
+        self.decoders
+            .get(&(record_type, Some(header.product_id())))
+            .or_else(|| self.decoders.get(&(record_type, None)))
+            .map(|decoder| decoder.as_ref())
+    }
 }
@@ -0,0 +1,148 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemPath, PVSS};
+use crate::Error;
+use crate::compression::{self, Format};
+use crate::utils::Map;
+use std::io::{Cursor, Read};
+
+fn invalid_archive(err: impl std::fmt::Display) -> Error {
+    Error::InvalidArchive(err.to_string())
+}
+
+/// Provides access to a collateral tree stored in a zip or tar archive (optionally compressed
+/// with gzip).
+///
+/// The items of the collateral tree are loaded in memory from the archive, which doesn't need to
+/// be extracted to the file system. The files in the archive must follow the structure of the
+/// collateral tree (`PRODUCT/VARIANT/STEPPING/SECURITY/crashlog/ITEM_PATH`), which can be nested
+/// in a top-level directory.
+///
+/// A [`CollateralManager`] that uses a collateral archive can be created using the
+/// [`CollateralManager::archive_tree`] function.
+#[derive(Default)]
+pub struct ArchiveTree {
+    registry: Map<PVSS, Map<ItemPath, Vec<u8>>>,
+}
+
+impl ArchiveTree {
+    /// Loads the collateral tree stored in a zip, tar, or tar.gz archive.
+    pub fn from_slice(s: &[u8]) -> Result<Self, Error> {
+        let mut tree = Self::default();
+        match Format::detect(s) {
+            Some(Format::Zip) => tree.read_zip(s)?,
+            Some(Format::Gzip) => {
+                for tar in compression::decompress(s)? {
+                    tree.read_tar(&tar)?
+                }
+            }
+            None => tree.read_tar(s)?,
+        }
+
+        if tree.registry.is_empty() {
+            return Err(Error::InvalidArchive(
+                "no collateral item found in the archive".into(),
+            ));
+        }
+        Ok(tree)
+    }
+
+    fn read_zip(&mut self, s: &[u8]) -> Result<(), Error> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(s)).map_err(invalid_archive)?;
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i).map_err(invalid_archive)?;
+            if !file.is_file() {
+                continue;
+            }
+
+            let path = file.name().to_string();
+            let mut content = Vec::new();
+            file.read_to_end(&mut content).map_err(invalid_archive)?;
+            self.insert(&path, content);
+        }
+        Ok(())
+    }
+
+    fn read_tar(&mut self, s: &[u8]) -> Result<(), Error> {
+        let mut archive = tar::Archive::new(s);
+        for entry in archive.entries().map_err(invalid_archive)? {
+            let mut entry = entry.map_err(invalid_archive)?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let path = entry
+                .path()
+                .map_err(invalid_archive)?
+                .to_string_lossy()
+                .into_owned();
+            let mut content = Vec::new();
+            entry.read_to_end(&mut content).map_err(invalid_archive)?;
+            self.insert(&path, content);
+        }
+        Ok(())
+    }
+
+    /// Adds the file stored at the given path of the archive to the collateral tree.
+    fn insert(&mut self, path: &str, content: Vec<u8>) {
+        let components: Vec<&str> = path
+            .split(['/', '\\'])
+            .filter(|component| !component.is_empty() && *component != ".")
+            .collect();
+
+        // The item path starts after the first `crashlog` directory that follows the PVSS.
+        let Some(index) = (4..components.len()).find(|&i| components[i] == "crashlog") else {
+            log::debug!("Ignoring {path}: not in the collateral tree");
+            return;
+        };
+        if index + 1 == components.len() {
+            return;
+        }
+
+        let pvss = PVSS {
+            product: components[index - 4].into(),
+            variant: components[index - 3].into(),
+            stepping: components[index - 2].into(),
+            security: components[index - 1].into(),
+        };
+        let item = ItemPath::from(components[index + 1..].join("/").as_str());
+        self.registry.entry(pvss).or_default().insert(item, content);
+    }
+}
+
+impl CollateralTree for ArchiveTree {
+    fn get(&self, pvss: &PVSS, item: &ItemPath) -> Result<Vec<u8>, Error> {
+        self.registry
+            .get(pvss)
+            .and_then(|items| items.get(item))
+            .cloned()
+            .ok_or_else(|| Error::MissingCollateral(pvss.clone(), item.clone()))
+    }
+
+    fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        Ok(self
+            .registry
+            .iter()
+            .filter(|(_, items)| items.contains_key(item))
+            .map(|(pvss, _)| pvss.clone())
+            .collect())
+    }
+}
+
+impl CollateralManager<ArchiveTree> {
+    /// Creates a [`CollateralManager`] that uses a collateral tree stored in a zip, tar, or
+    /// tar.gz archive.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let archive = std::fs::read("collateral.tar.gz").unwrap();
+    /// let collateral_manager = CollateralManager::archive_tree(&archive);
+    /// ```
+    pub fn archive_tree(archive: &[u8]) -> Result<Self, Error> {
+        Self::new(ArchiveTree::from_slice(archive)?)
+    }
+}
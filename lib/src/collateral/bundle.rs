@@ -0,0 +1,215 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemPath, PVSS};
+use crate::Error;
+use crate::utils::Map;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde::{Deserialize, Serialize};
+
+/// Item of a collateral bundle
+#[derive(Serialize, Deserialize)]
+struct BundleItem {
+    product: String,
+    variant: String,
+    stepping: String,
+    security: String,
+    path: String,
+    content: BundleContent,
+}
+
+/// Content of a collateral bundle item. The items that are not valid UTF-8 are stored as an
+/// array of bytes.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum BundleContent {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+#[derive(Serialize, Deserialize)]
+struct Bundle {
+    items: Vec<BundleItem>,
+    /// Layouts registered with [`CollateralManager::override_layout`], indexed by record type
+    #[serde(default)]
+    layout_overrides: Map<String, BundleContent>,
+}
+
+/// Provides access to the subset of a collateral tree exported by
+/// [`CollateralManager::export_bundle`].
+///
+/// A [`CollateralManager`] that uses a collateral bundle can be created using the
+/// [`CollateralManager::bundle`] function.
+#[derive(Default)]
+pub struct BundleTree {
+    registry: Map<PVSS, Map<ItemPath, Vec<u8>>>,
+    layout_overrides: Map<String, Vec<u8>>,
+}
+
+impl BundleTree {
+    /// Loads a collateral bundle exported by [`CollateralManager::export_bundle`].
+    pub fn from_slice(s: &[u8]) -> Result<Self, Error> {
+        let bundle: Bundle = serde_json::from_slice(s)?;
+        let mut tree = Self::default();
+
+        for item in bundle.items {
+            let pvss = PVSS {
+                product: item.product,
+                variant: item.variant,
+                stepping: item.stepping,
+                security: item.security,
+            };
+            tree.registry
+                .entry(pvss)
+                .or_default()
+                .insert(item.path.as_str().into(), item.content.into());
+        }
+
+        tree.layout_overrides = bundle
+            .layout_overrides
+            .into_iter()
+            .map(|(record_type, layout)| (record_type, layout.into()))
+            .collect();
+
+        Ok(tree)
+    }
+
+    /// Returns the layouts overridden when the bundle was exported, indexed by record type.
+    ///
+    /// These layouts are registered automatically by [`CollateralManager::bundle`]. They must
+    /// be registered with [`CollateralManager::override_layout`] when the collateral manager is
+    /// created by other means.
+    pub fn layout_overrides(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.layout_overrides
+            .iter()
+            .map(|(record_type, layout)| (record_type.as_str(), layout.as_slice()))
+    }
+}
+
+impl CollateralTree for BundleTree {
+    fn get(&self, pvss: &PVSS, item: &ItemPath) -> Result<Vec<u8>, Error> {
+        self.registry
+            .get(pvss)
+            .and_then(|items| items.get(item))
+            .cloned()
+            .ok_or_else(|| Error::MissingCollateral(pvss.clone(), item.clone()))
+    }
+
+    fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        Ok(self
+            .registry
+            .iter()
+            .filter(|(_, items)| items.contains_key(item))
+            .map(|(pvss, _)| pvss.clone())
+            .collect())
+    }
+}
+
+impl<T: CollateralTree> CollateralManager<T> {
+    /// Exports the collateral items loaded so far into a bundle.
+    ///
+    /// When called after decoding a Crash Log, the bundle contains exactly the decode definitions
+    /// used by the decoder alongside the target information of the associated products. The
+    /// bundle can be stored with the decoded output and loaded later using
+    /// [`CollateralManager::bundle`] to reproduce the decode, even if the collateral tree has
+    /// changed in the meantime.
+    ///
+    /// The layouts registered with [`CollateralManager::override_layout`] are exported as well.
+    /// The items and layouts that are not valid UTF-8 are stored as arrays of bytes.
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let root = crashlog.decode(&mut cm);
+    ///
+    /// let bundle = cm.export_bundle().unwrap();
+    /// let mut cm = CollateralManager::bundle(&bundle).unwrap();
+    /// assert_eq!(crashlog.decode(&mut cm), root);
+    /// ```
+    pub fn export_bundle(&self) -> Result<Vec<u8>, Error> {
+        let mut items = Vec::new();
+        let mut products = Vec::new();
+
+        for (index, content) in self.items.iter() {
+            if !products.contains(&index.pvss.product) {
+                products.push(index.pvss.product.clone());
+            }
+            items.push(BundleItem::new(&index.pvss, &index.path, content));
+        }
+
+        let path = ItemPath::new(["target_info.json"]);
+        for pvss in self.tree.search(&path)? {
+            if products.contains(&pvss.product) {
+                let content = self.tree.get(&pvss, &path)?;
+                items.push(BundleItem::new(&pvss, &path, &content));
+            }
+        }
+
+        let layout_overrides = self
+            .layout_overrides
+            .iter()
+            .map(|(record_type, layout)| {
+                (record_type.clone(), BundleContent::from(layout.as_ref()))
+            })
+            .collect();
+
+        Ok(serde_json::to_vec_pretty(&Bundle {
+            items,
+            layout_overrides,
+        })?)
+    }
+}
+
+impl BundleItem {
+    fn new(pvss: &PVSS, path: &ItemPath, content: &[u8]) -> Self {
+        Self {
+            product: pvss.product.clone(),
+            variant: pvss.variant.clone(),
+            stepping: pvss.stepping.clone(),
+            security: pvss.security.clone(),
+            path: path.to_string(),
+            content: content.into(),
+        }
+    }
+}
+
+impl From<&[u8]> for BundleContent {
+    fn from(content: &[u8]) -> Self {
+        match str::from_utf8(content) {
+            Ok(text) => BundleContent::Text(text.to_string()),
+            Err(_) => BundleContent::Binary(content.to_vec()),
+        }
+    }
+}
+
+impl From<BundleContent> for Vec<u8> {
+    fn from(content: BundleContent) -> Self {
+        match content {
+            BundleContent::Text(text) => text.into_bytes(),
+            BundleContent::Binary(bytes) => bytes,
+        }
+    }
+}
+
+impl CollateralManager<BundleTree> {
+    /// Creates a [`CollateralManager`] that uses a collateral bundle exported by
+    /// [`CollateralManager::export_bundle`].
+    ///
+    /// The layouts overridden when the bundle was exported are registered again.
+    pub fn bundle(bundle: &[u8]) -> Result<Self, Error> {
+        let tree = BundleTree::from_slice(bundle)?;
+        let layout_overrides = tree.layout_overrides.clone();
+        let mut cm = Self::new(tree)?;
+        for (record_type, layout) in layout_overrides {
+            cm.override_layout(&record_type, layout)?;
+        }
+        Ok(cm)
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::TargetInfo;
+use crate::header::Header;
+use crate::record::DecodeDefinition;
+use crate::utils::Map;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+};
+#[cfg(not(feature = "std"))]
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{fmt, sync::Arc};
+
+/// Default number of decode definitions kept in the cache of a collateral manager
+pub(super) const DEFAULT_CAPACITY: usize = 64;
+
+/// Statistics of the decode definition cache of a [`super::CollateralManager`].
+///
+/// See [`super::CollateralManager::cache_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of decode definitions found in the cache
+    pub hits: u64,
+    /// Number of decode definitions that had to be loaded from the collateral tree
+    pub misses: u64,
+    /// Number of decode definitions removed from the cache to make room for new ones
+    pub evictions: u64,
+    /// Number of decode definitions currently stored in the cache
+    pub entries: usize,
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} evictions, {} entries",
+            self.hits, self.misses, self.evictions, self.entries
+        )
+    }
+}
+
+/// Identifies a decode definition resolved for a given kind of record.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) struct DefinitionKey {
+    product_id: u32,
+    record_type: u8,
+    revision: u32,
+    die: Option<u8>,
+    /// Stepping of the product, as defined in its target info
+    stepping: String,
+    /// Security level of the product, as defined in its target info
+    security: String,
+    /// Name of the decode definition (example: `layout.csv`)
+    decode_def: String,
+}
+
+impl DefinitionKey {
+    pub(super) fn new(header: &Header, target_info: Option<&TargetInfo>, decode_def: &str) -> Self {
+        Self {
+            product_id: header.product_id(),
+            record_type: header.version.record_type,
+            revision: header.revision(),
+            die: header.die_id(),
+            stepping: target_info
+                .map_or("all", |info| info.stepping.as_str())
+                .to_string(),
+            security: target_info
+                .map_or("all", |info| info.security.as_str())
+                .to_string(),
+            decode_def: decode_def.to_string(),
+        }
+    }
+}
+
+/// Least recently used cache of the decode definitions resolved from the collateral tree.
+pub(super) struct DefinitionCache {
+    capacity: usize,
+    /// Decode definitions along with the time of their last use
+    entries: Map<DefinitionKey, (Arc<DecodeDefinition>, u64)>,
+    clock: u64,
+    stats: CacheStats,
+}
+
+impl Default for DefinitionCache {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_CAPACITY,
+            entries: Map::default(),
+            clock: 0,
+            stats: CacheStats::default(),
+        }
+    }
+}
+
+impl DefinitionCache {
+    pub(super) fn get(&mut self, key: &DefinitionKey) -> Option<Arc<DecodeDefinition>> {
+        self.clock += 1;
+        match self.entries.get_mut(key) {
+            Some((definition, last_use)) => {
+                *last_use = self.clock;
+                self.stats.hits += 1;
+                Some(Arc::clone(definition))
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub(super) fn insert(&mut self, key: DefinitionKey, definition: Arc<DecodeDefinition>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if !self.entries.contains_key(&key) {
+            self.shrink_to(self.capacity - 1);
+        }
+        self.entries.insert(key, (definition, self.clock));
+    }
+
+    pub(super) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.shrink_to(capacity);
+    }
+
+    /// Removes all the decode definitions, which must be resolved again from the collateral
+    /// tree.
+    pub(super) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Evicts the least recently used decode definitions until at most `len` remain.
+    fn shrink_to(&mut self, len: usize) {
+        while self.entries.len() > len {
+            let Some(key) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_use))| *last_use)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.entries.remove(&key);
+            self.stats.evictions += 1;
+        }
+    }
+
+    pub(super) fn stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self.entries.len(),
+            ..self.stats
+        }
+    }
+}
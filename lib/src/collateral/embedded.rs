@@ -14,7 +14,8 @@ pub struct EmbeddedTree {
 }
 
 impl EmbeddedTree {
-    fn new() -> Self {
+    /// Loads the collateral tree embedded in the library.
+    pub fn new() -> Self {
         let mut tree = Self::default();
         include!(concat!(env!("OUT_DIR"), "/embedded_collateral_tree.rs"));
         tree
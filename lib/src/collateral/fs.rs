@@ -18,7 +18,8 @@ pub struct FileSystemTree {
 }
 
 impl FileSystemTree {
-    fn new(root: &Path) -> Self {
+    /// Provides access to the collateral tree located at `root`.
+    pub fn new(root: &Path) -> Self {
         Self {
             root: root.to_path_buf(),
         }
@@ -0,0 +1,90 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralTree, ItemPath, PVSS};
+use crate::Error;
+use crate::utils::Map;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Provides access to collateral items inserted programmatically.
+///
+/// This allows the collateral items to be fetched from any source (tests, databases, network
+/// stores...) without accessing the file system. A [`super::CollateralManager`] that uses the
+/// inserted items can be created using the [`super::CollateralManager::new`] function.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::collateral::{MemoryCollateralTree, PVSS};
+/// use intel_crashlog::prelude::*;
+///
+/// let pvss = PVSS {
+///     product: "XYZ".into(),
+///     ..PVSS::default()
+/// };
+/// let target_info = r#"{"product": "XYZ", "product_id": "0x7A"}"#;
+/// let layout = "name;offset;size;description\nmca.foo;0;8;";
+///
+/// let mut tree = MemoryCollateralTree::new();
+/// tree.add_item(pvss.clone(), "target_info.json", target_info)
+///     .add_item(pvss, "decode-defs/MCA/all/layout.csv", layout);
+///
+/// let mut cm = CollateralManager::new(tree).unwrap();
+/// assert_eq!(cm.target_info.get(&0x7A).unwrap().product, "XYZ");
+///
+/// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+/// let crashlog = CrashLog::from_slice(&data).unwrap();
+/// let root = crashlog.decode(&mut cm);
+/// assert_eq!(root.get_value_by_path("mca.foo"), Some(0x1));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoryCollateralTree {
+    registry: Map<PVSS, Map<ItemPath, Vec<u8>>>,
+}
+
+impl MemoryCollateralTree {
+    /// Creates an empty collateral tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts an item in the collateral tree, replacing the existing item stored at the same
+    /// `path` for the given `pvss`.
+    pub fn add_item(
+        &mut self,
+        pvss: PVSS,
+        path: impl Into<ItemPath>,
+        content: impl Into<Vec<u8>>,
+    ) -> &mut Self {
+        self.registry
+            .entry(pvss)
+            .or_default()
+            .insert(path.into(), content.into());
+        self
+    }
+
+    /// Removes an item from the collateral tree and returns its content.
+    pub fn remove_item(&mut self, pvss: &PVSS, path: &ItemPath) -> Option<Vec<u8>> {
+        self.registry.get_mut(pvss)?.remove(path)
+    }
+}
+
+impl CollateralTree for MemoryCollateralTree {
+    fn get(&self, pvss: &PVSS, item: &ItemPath) -> Result<Vec<u8>, Error> {
+        self.registry
+            .get(pvss)
+            .and_then(|items| items.get(item))
+            .cloned()
+            .ok_or_else(|| Error::MissingCollateral(pvss.clone(), item.clone()))
+    }
+
+    fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        Ok(self
+            .registry
+            .iter()
+            .filter(|(_, items)| items.contains_key(item))
+            .map(|(pvss, _)| pvss.clone())
+            .collect())
+    }
+}
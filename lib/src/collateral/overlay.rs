@@ -0,0 +1,80 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemPath, PVSS};
+use crate::Error;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+
+/// Provides access to an ordered stack of collateral trees.
+///
+/// The items are looked up in each tree in order, and the first tree that defines an item takes
+/// precedence over the following ones. This allows a collateral tree to be partially overridden,
+/// for instance to test local changes to the decode definitions without editing a released
+/// collateral bundle.
+///
+/// A [`CollateralManager`] that uses stacked collateral trees can be created using the
+/// [`CollateralManager::overlay`] function.
+#[derive(Default)]
+pub struct OverlayTree {
+    trees: Vec<Box<dyn CollateralTree>>,
+}
+
+impl OverlayTree {
+    /// Stacks the collateral `trees`, from the highest to the lowest precedence.
+    pub fn new(trees: Vec<Box<dyn CollateralTree>>) -> Self {
+        Self { trees }
+    }
+
+    /// Adds a collateral tree below the trees already in the stack.
+    pub fn push(&mut self, tree: impl CollateralTree + 'static) {
+        self.trees.push(Box::new(tree));
+    }
+}
+
+impl CollateralTree for OverlayTree {
+    fn get(&self, pvss: &PVSS, item: &ItemPath) -> Result<Vec<u8>, Error> {
+        for tree in self.trees.iter() {
+            match tree.get(pvss, item) {
+                Err(Error::MissingCollateral(..)) => continue,
+                res => return res,
+            }
+        }
+
+        Err(Error::MissingCollateral(pvss.clone(), item.clone()))
+    }
+
+    fn search(&self, item: &ItemPath) -> Result<Vec<PVSS>, Error> {
+        let mut hits: Vec<PVSS> = Vec::new();
+        for tree in self.trees.iter() {
+            for pvss in tree.search(item)? {
+                if !hits.contains(&pvss) {
+                    hits.push(pvss);
+                }
+            }
+        }
+        Ok(hits)
+    }
+}
+
+impl CollateralManager<OverlayTree> {
+    /// Creates a [`CollateralManager`] that looks up the collateral items in an ordered list of
+    /// collateral trees. The first tree that defines an item takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::collateral::{EmbeddedTree, FileSystemTree};
+    /// use intel_crashlog::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// let collateral_manager = CollateralManager::overlay(vec![
+    ///     Box::new(FileSystemTree::new(Path::new("tests/collateral"))),
+    ///     Box::new(EmbeddedTree::new()),
+    /// ]);
+    /// assert!(collateral_manager.is_ok());
+    /// ```
+    pub fn overlay(trees: Vec<Box<dyn CollateralTree>>) -> Result<Self, Error> {
+        Self::new(OverlayTree::new(trees))
+    }
+}
@@ -18,6 +18,22 @@ impl ItemPath {
     pub(crate) fn push(&mut self, element: &str) {
         self.0.push(element.into())
     }
+
+    /// Returns the path obtained by resolving the `relative` path (using `/` as separators) from
+    /// this path. The `..` segments refer to the parent of the current segment.
+    pub(crate) fn join(&self, relative: &str) -> ItemPath {
+        let mut path = self.clone();
+        for segment in relative.split('/') {
+            match segment {
+                "" | "." => (),
+                ".." => {
+                    let _ = path.0.pop();
+                }
+                segment => path.push(segment),
+            }
+        }
+        path
+    }
 }
 
 #[cfg(feature = "std")]
@@ -0,0 +1,170 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemIndex, ItemPath, PVSS, TargetInfo};
+use crate::Error;
+use crate::header::Header;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+
+/// Provides the collateral items missing from the collateral tree, for instance by downloading
+/// them from a remote service or by generating them.
+///
+/// Any closure taking a [`Header`] and an [`ItemPath`] implements this trait.
+///
+/// See [`CollateralManager::set_resolver`].
+pub trait CollateralResolver {
+    /// Returns the content of the item located at `path` for the product that generated the
+    /// record described by `header`, or `None` if the item cannot be resolved.
+    fn resolve(&mut self, header: &Header, path: &ItemPath) -> Option<Vec<u8>>;
+}
+
+impl<F: FnMut(&Header, &ItemPath) -> Option<Vec<u8>>> CollateralResolver for F {
+    fn resolve(&mut self, header: &Header, path: &ItemPath) -> Option<Vec<u8>> {
+        self(header, path)
+    }
+}
+
+impl<T: CollateralTree> CollateralManager<T> {
+    /// Registers a resolver invoked when the decode definitions of a record cannot be found in
+    /// the collateral tree.
+    ///
+    /// The resolver is asked for the missing decode definition at each of the locations where
+    /// it is looked up, until one is provided. If the product of the record is unknown, the
+    /// resolver is first asked for its `target_info.json` item. The resolved items are kept in
+    /// the collateral manager and the decoding is retried. The items that cannot be resolved are
+    /// not requested again for the same product.
+    ///
+    /// If the collateral manager has been created with
+    /// [`CollateralManager::with_verifying_key`], the resolved items must match the digests
+    /// stored in the verified manifests of the collateral tree. The other items are refused.
+    ///
+    /// ```
+    /// use intel_crashlog::collateral::{ItemPath, MemoryCollateralTree};
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut cm = CollateralManager::new(MemoryCollateralTree::new()).unwrap();
+    /// cm.set_resolver(|_header: &Header, path: &ItemPath| match path.to_string().as_str() {
+    ///     "target_info.json" => Some(br#"{"product": "XYZ", "product_id": "0x7A"}"#.to_vec()),
+    ///     "decode-defs/MCA/all/layout.csv" => {
+    ///         Some(b"name;offset;size;description\nmca.foo;0;8;".to_vec())
+    ///     }
+    ///     _ => None,
+    /// });
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let root = crashlog.decode(&mut cm);
+    /// assert_eq!(root.get_value_by_path("mca.foo"), Some(0x1));
+    /// ```
+    pub fn set_resolver(&mut self, resolver: impl CollateralResolver + 'static) {
+        self.resolver = Some(Box::new(resolver));
+        self.unresolved.clear();
+        self.definitions.clear();
+    }
+
+    /// Asks the resolver for the decode definition `decode_def` of the record described by
+    /// `header`. Returns `true` if the decode definition has been resolved.
+    pub(crate) fn resolve_decode_def(&mut self, header: &Header, decode_def: &str) -> bool {
+        let Some(mut resolver) = self.resolver.take() else {
+            return false;
+        };
+
+        let resolved = self.resolve_with(resolver.as_mut(), header, decode_def);
+        self.resolver = Some(resolver);
+        resolved
+    }
+
+    fn resolve_with(
+        &mut self,
+        resolver: &mut dyn CollateralResolver,
+        header: &Header,
+        decode_def: &str,
+    ) -> bool {
+        if !self.target_info.contains_key(&header.product_id()) {
+            let path = ItemPath::new(["target_info.json"]);
+            let target_info = self
+                .resolve_item(resolver, header, &path, |content| {
+                    let target_info = serde_json::from_slice::<TargetInfo>(content).ok()?;
+                    let pvss = PVSS {
+                        product: target_info.product.clone(),
+                        variant: target_info.variant.clone(),
+                        stepping: target_info.stepping.clone(),
+                        security: target_info.security.clone(),
+                    };
+                    Some((pvss, target_info))
+                })
+                .map(|(_, target_info)| target_info);
+            if let Some(target_info) = target_info {
+                self.insert_target_info(target_info);
+            }
+        }
+
+        let (Ok(pvss), Ok(paths)) = (header.pvss(self), header.decode_definitions_paths(self))
+        else {
+            return false;
+        };
+
+        for path in paths.iter().map(|path| path.join(decode_def)) {
+            let resolved = self.resolve_item(resolver, header, &path, |content| {
+                Some((pvss.clone(), content.to_vec()))
+            });
+            if let Some((_, content)) = resolved {
+                log::debug!("Resolved {path} for {pvss}");
+                self.items.insert(
+                    ItemIndex {
+                        pvss: pvss.clone(),
+                        path,
+                    },
+                    content,
+                );
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Asks the resolver for the item located at `path`, unless it has already failed to resolve
+    /// it for the product of the record. The content of the item is parsed with `parse`, which
+    /// also provides the [`PVSS`] of the item, and is verified before being returned.
+    fn resolve_item<V>(
+        &mut self,
+        resolver: &mut dyn CollateralResolver,
+        header: &Header,
+        path: &ItemPath,
+        parse: impl FnOnce(&[u8]) -> Option<(PVSS, V)>,
+    ) -> Option<(PVSS, V)> {
+        let key = (header.product_id(), path.clone());
+        if self.unresolved.contains(&key) {
+            return None;
+        }
+
+        let resolved = resolver.resolve(header, path).and_then(|content| {
+            let (pvss, value) = parse(&content)?;
+            self.verify_resolved(&pvss, path, &content)
+                .inspect_err(|err| log::warn!("Refusing resolved item: {err}"))
+                .ok()?;
+            Some((pvss, value))
+        });
+
+        if resolved.is_none() {
+            self.unresolved.push(key);
+        }
+        resolved
+    }
+
+    /// Checks the content of a resolved item against the verified manifests of the collateral
+    /// tree, if the collateral manager has been created with a verifying key.
+    #[cfg_attr(not(feature = "collateral_verification"), allow(unused_variables))]
+    fn verify_resolved(&self, pvss: &PVSS, path: &ItemPath, content: &[u8]) -> Result<(), Error> {
+        #[cfg(feature = "collateral_verification")]
+        if let Some(verification) = &self.verification {
+            return super::fallbacks(pvss)
+                .iter()
+                .find_map(|pvss| verification.check(pvss, path, content).ok())
+                .ok_or_else(|| Error::UnverifiedCollateral(Box::new(pvss.clone()), path.clone()));
+        }
+        Ok(())
+    }
+}
@@ -16,18 +16,37 @@ pub struct TargetInfo {
     /// Product ID used in the Crash Log headers
     pub product_id: String,
     /// Product variant
-    #[serde(default = "default_variant")]
+    #[serde(default = "default_all")]
     pub variant: String,
+    /// Product stepping. The collateral items specific to this stepping take precedence over the
+    /// items defined for `all` the steppings.
+    #[serde(default = "default_all")]
+    pub stepping: String,
+    /// Security level of the collateral items. When set to `all`, the items with the highest
+    /// security level available are used.
+    #[serde(default = "default_all")]
+    pub security: String,
     /// Die IDs/names
-    #[serde(default, deserialize_with = "deserialize_die_ids")]
+    #[serde(default, deserialize_with = "deserialize_indexed_names")]
     pub die_id: Map<u8, String>,
+    /// Names of the record collection triggers, indexed by their bit position in the `reason`
+    /// field of the record headers (see [crate::header::Header::reasons])
+    #[serde(default, deserialize_with = "deserialize_indexed_names")]
+    pub reason: Map<u8, String>,
+    /// Frequency of the counter used to timestamp the records in Hz
+    #[serde(default)]
+    pub timestamp_frequency: Option<u64>,
+    /// Unix time in seconds at which the counter used to timestamp the records started. Enables
+    /// the conversion of the timestamps into dates (see [crate::header::Header::time]).
+    #[serde(default)]
+    pub timestamp_epoch: Option<u64>,
 }
 
-fn default_variant() -> String {
+fn default_all() -> String {
     String::from("all")
 }
 
-fn deserialize_die_ids<'de, D>(deserializer: D) -> Result<Map<u8, String>, D::Error>
+fn deserialize_indexed_names<'de, D>(deserializer: D) -> Result<Map<u8, String>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -36,7 +55,7 @@ where
         .into_iter()
         .filter_map(|(key, value)| {
             key.parse::<u8>()
-                .inspect_err(|_| log::warn!("Invalid die ID value: {key}"))
+                .inspect_err(|_| log::warn!("Invalid index value: {key}"))
                 .ok()
                 .map(|k| (k, value))
         })
@@ -46,26 +65,36 @@ where
 impl<T: CollateralTree> CollateralManager<T> {
     pub(super) fn update_target_info(&mut self) -> Result<(), Error> {
         self.target_info.clear();
+        self.definitions.clear();
         let path = ItemPath::new(["target_info.json"]);
         for pvss in self.tree.search(&path)? {
-            let res = serde_json::from_slice::<TargetInfo>(&self.tree.get(&pvss, &path)?)
-                .inspect_err(|err| log::warn!("Invalid target info ({pvss}): {err}"));
-
-            let Ok(target_info) = res else {
+            let content = self.read_item(&pvss, &path);
+            #[cfg(feature = "collateral_verification")]
+            if let Err(err @ Error::UnverifiedCollateral(..)) = &content {
+                log::warn!("{err}");
                 continue;
-            };
-
-            let product_id = if target_info.product_id.starts_with("0x") {
-                u32::from_str_radix(&target_info.product_id[2..], 16)
-            } else {
-                target_info.product_id.parse()
-            };
+            }
+            let res = serde_json::from_slice::<TargetInfo>(&content?)
+                .inspect_err(|err| log::warn!("Invalid target info ({pvss}): {err}"));
 
-            if let Ok(product_id) = product_id {
-                log::trace!("Loading target info: {target_info:?}");
-                self.target_info.insert(product_id, target_info);
+            if let Ok(target_info) = res {
+                self.insert_target_info(target_info);
             }
         }
         Ok(())
     }
+
+    pub(super) fn insert_target_info(&mut self, target_info: TargetInfo) {
+        let product_id = if target_info.product_id.starts_with("0x") {
+            u32::from_str_radix(&target_info.product_id[2..], 16)
+        } else {
+            target_info.product_id.parse()
+        };
+
+        if let Ok(product_id) = product_id {
+            log::trace!("Loading target info: {target_info:?}");
+            self.target_info.insert(product_id, target_info);
+            self.definitions.clear();
+        }
+    }
 }
@@ -0,0 +1,191 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{CollateralManager, CollateralTree, ItemPath, PVSS};
+use crate::Error;
+use crate::utils::Map;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+};
+use ed25519_dalek::{Signature, VerifyingKey};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+const MANIFEST: &str = "manifest.json";
+const SIGNATURE: &str = "manifest.json.sig";
+
+#[derive(Deserialize)]
+struct Manifest {
+    pvss: String,
+    items: Map<String, String>,
+}
+
+/// Result of the verification of the manifest of a product
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManifestStatus {
+    /// The manifest is signed with the expected key.
+    Verified,
+    /// The manifest has no signature.
+    MissingSignature,
+    /// The signature of the manifest doesn't match the expected key.
+    InvalidSignature,
+    /// The manifest cannot be parsed.
+    Invalid(String),
+    /// The manifest has been signed for another product, whose [`PVSS`] is provided.
+    Mismatch(String),
+}
+
+/// Result of the verification of the manifests found in a collateral tree.
+///
+/// Each product of a collateral tree can provide a `manifest.json` item that lists the SHA-256
+/// digests of the collateral items of the product, indexed by item path. The manifest also
+/// stores the [`PVSS`] of the product, which must match the location of the manifest in the
+/// collateral tree:
+///
+/// ```json
+/// {
+///   "pvss": "XYZ/all/all/green",
+///   "items": {
+///     "target_info.json": "8c2f...",
+///     "decode-defs/MCA/1/layout.csv": "e3b0..."
+///   }
+/// }
+/// ```
+///
+/// The manifest is signed with Ed25519, and the 64-byte signature is stored in the
+/// `manifest.json.sig` item. When a [`CollateralManager`] is created with
+/// [`CollateralManager::with_verifying_key`], the items whose digest doesn't match a manifest
+/// signed with the given key are refused with [`Error::UnverifiedCollateral`].
+///
+/// See [`CollateralManager::verification`].
+#[derive(Debug, Default)]
+pub struct Verification {
+    manifests: Map<PVSS, ManifestStatus>,
+    digests: Map<PVSS, Map<ItemPath, [u8; 32]>>,
+}
+
+fn parse_digest(digest: &str) -> Option<[u8; 32]> {
+    let digest = digest.trim();
+    if digest.len() != 64 || !digest.is_ascii() {
+        return None;
+    }
+
+    let mut bytes = [0; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digest[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+impl Verification {
+    fn load<T: CollateralTree>(tree: &T, key: &VerifyingKey) -> Result<Self, Error> {
+        let mut verification = Self::default();
+        let manifest_path = ItemPath::from(MANIFEST);
+        let signature_path = ItemPath::from(SIGNATURE);
+
+        for pvss in tree.search(&manifest_path)? {
+            let manifest = tree.get(&pvss, &manifest_path)?;
+            let status = match tree.get(&pvss, &signature_path) {
+                Ok(signature) => match Self::check_manifest(&pvss, &manifest, &signature, key) {
+                    Ok(digests) => {
+                        verification.digests.insert(pvss.clone(), digests);
+                        ManifestStatus::Verified
+                    }
+                    Err(status) => status,
+                },
+                Err(Error::MissingCollateral(..)) => ManifestStatus::MissingSignature,
+                Err(err) => return Err(err),
+            };
+
+            if status != ManifestStatus::Verified {
+                log::warn!("Cannot verify the collateral manifest of {pvss}: {status:?}");
+            }
+            verification.manifests.insert(pvss, status);
+        }
+
+        Ok(verification)
+    }
+
+    fn check_manifest(
+        pvss: &PVSS,
+        manifest: &[u8],
+        signature: &[u8],
+        key: &VerifyingKey,
+    ) -> Result<Map<ItemPath, [u8; 32]>, ManifestStatus> {
+        let signature =
+            Signature::from_slice(signature).map_err(|_| ManifestStatus::InvalidSignature)?;
+        key.verify_strict(manifest, &signature)
+            .map_err(|_| ManifestStatus::InvalidSignature)?;
+
+        let manifest: Manifest = serde_json::from_slice(manifest)
+            .map_err(|err| ManifestStatus::Invalid(err.to_string()))?;
+        if manifest.pvss != pvss.to_string() {
+            return Err(ManifestStatus::Mismatch(manifest.pvss));
+        }
+        manifest
+            .items
+            .iter()
+            .map(|(path, digest)| {
+                parse_digest(digest)
+                    .map(|digest| (ItemPath::from(path.as_str()), digest))
+                    .ok_or_else(|| ManifestStatus::Invalid(format!("invalid digest for {path}")))
+            })
+            .collect()
+    }
+
+    /// Returns the status of the manifests found in the collateral tree, indexed by product.
+    pub fn manifests(&self) -> &Map<PVSS, ManifestStatus> {
+        &self.manifests
+    }
+
+    /// Returns `true` if the collateral tree provides at least one manifest and all the manifests
+    /// are signed with the expected key.
+    pub fn is_verified(&self) -> bool {
+        !self.manifests.is_empty()
+            && self
+                .manifests
+                .values()
+                .all(|status| *status == ManifestStatus::Verified)
+    }
+
+    /// Checks that the `content` of an item matches the digest stored in the verified manifest of
+    /// the product.
+    pub(super) fn check(&self, pvss: &PVSS, path: &ItemPath, content: &[u8]) -> Result<(), Error> {
+        let expected = self.digests.get(pvss).and_then(|digests| digests.get(path));
+        if expected.is_some_and(|expected| Sha256::digest(content).as_slice() == expected) {
+            Ok(())
+        } else {
+            Err(Error::UnverifiedCollateral(
+                Box::new(pvss.clone()),
+                path.clone(),
+            ))
+        }
+    }
+}
+
+impl<T: CollateralTree> CollateralManager<T> {
+    /// Creates a collateral manager for a given collateral `tree` that only uses the collateral
+    /// items listed in the manifests signed with the Ed25519 public `key`.
+    ///
+    /// The items that are not listed in a verified manifest, or whose content doesn't match the
+    /// digest stored in the manifest, are refused with [`Error::UnverifiedCollateral`]. The
+    /// status of the manifests is provided by [`CollateralManager::verification`].
+    pub fn with_verifying_key(tree: T, key: &[u8; 32]) -> Result<Self, Error> {
+        let key = VerifyingKey::from_bytes(key).map_err(|_| Error::InvalidVerifyingKey)?;
+        let verification = Verification::load(&tree, &key)?;
+
+        let mut cm = Self::empty(tree);
+        cm.verification = Some(verification);
+        cm.update_target_info()?;
+        Ok(cm)
+    }
+
+    /// Returns the result of the verification of the collateral manifests, or `None` if the
+    /// collateral manager has not been created with [`CollateralManager::with_verifying_key`].
+    pub fn verification(&self) -> Option<&Verification> {
+        self.verification.as_ref()
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Decompression of the gzip and zip archives the Crash Logs are commonly shipped in, and of the
+//! compressed record payloads.
+
+use crate::Error;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+/// Magic number at the beginning of the gzip files
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+/// Signature of the local file headers at the beginning of the zip files
+const ZIP_MAGIC: [u8; 4] = *b"PK\x03\x04";
+
+/// Maximum number of bytes produced by the decompression of an archive (all the files of the
+/// archive included) or of a compressed record payload. The Crash Logs are much smaller, so the
+/// larger outputs are decompression bombs.
+pub const MAX_DECOMPRESSED_SIZE: usize = 64 << 20;
+
+/// Maximum number of nested archives (e.g. a gzip file stored in a zip archive) decompressed by
+/// [crate::CrashLog::from_slice]
+pub const MAX_ARCHIVE_NESTING: usize = 4;
+
+/// Compressed file formats supported by this module
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Gzip,
+    Zip,
+}
+
+impl Format {
+    /// Detects the compression format of the given data from its magic number.
+    pub fn detect(s: &[u8]) -> Option<Self> {
+        if s.starts_with(&GZIP_MAGIC) {
+            Some(Format::Gzip)
+        } else if s.starts_with(&ZIP_MAGIC) {
+            Some(Format::Zip)
+        } else {
+            None
+        }
+    }
+}
+
+fn invalid_archive(err: impl std::fmt::Display) -> Error {
+    Error::InvalidArchive(err.to_string())
+}
+
+/// Reads the decompressed data, up to `limit` bytes.
+fn read_limited(reader: impl Read, limit: usize) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::new();
+    reader
+        .take(limit as u64 + 1)
+        .read_to_end(&mut data)
+        .map_err(invalid_archive)?;
+    if data.len() > limit {
+        return Err(Error::InvalidArchive(format!(
+            "decompressed data larger than {MAX_DECOMPRESSED_SIZE} bytes"
+        )));
+    }
+    Ok(data)
+}
+
+fn gunzip(s: &[u8]) -> Result<Vec<u8>, Error> {
+    read_limited(GzDecoder::new(s), MAX_DECOMPRESSED_SIZE)
+}
+
+fn unzip(s: &[u8]) -> Result<Vec<Vec<u8>>, Error> {
+    let mut archive = ZipArchive::new(Cursor::new(s)).map_err(invalid_archive)?;
+    let mut files = Vec::new();
+    let mut remaining = MAX_DECOMPRESSED_SIZE;
+    for i in 0..archive.len() {
+        let file = archive.by_index(i).map_err(invalid_archive)?;
+        if !file.is_file() {
+            continue;
+        }
+
+        log::debug!("Decompressing {}", file.name());
+        let data = read_limited(file, remaining)?;
+        remaining -= data.len();
+        files.push(data);
+    }
+    Ok(files)
+}
+
+/// Decompresses the content of a gzip or zip archive.
+///
+/// Returns the content of each file stored in the archive, in the order they appear in the
+/// archive. A gzip archive always contains a single file.
+///
+/// # Errors
+///
+/// Returns [Error::InvalidArchive] if the data is not a valid gzip or zip archive, or if the
+/// decompressed files are larger than [MAX_DECOMPRESSED_SIZE] bytes in total.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::compression::{self, Format};
+///
+/// let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+/// assert_eq!(Format::detect(&data), None);
+/// assert!(compression::decompress(&data).is_err());
+/// ```
+pub fn decompress(s: &[u8]) -> Result<Vec<Vec<u8>>, Error> {
+    match Format::detect(s) {
+        Some(Format::Gzip) => Ok(vec![gunzip(s)?]),
+        Some(Format::Zip) => unzip(s),
+        None => Err(Error::InvalidArchive("unknown compression format".into())),
+    }
+}
+
+/// Decompresses a zlib stream, such as the compressed payloads of the Crash Log records (see
+/// [crate::record::Context::payload_compressed]).
+///
+/// # Errors
+///
+/// Returns [Error::InvalidArchive] if the data is not a valid zlib stream, or if the decompressed
+/// data is larger than [MAX_DECOMPRESSED_SIZE] bytes.
+pub fn inflate(s: &[u8]) -> Result<Vec<u8>, Error> {
+    read_limited(ZlibDecoder::new(s), MAX_DECOMPRESSED_SIZE)
+}
@@ -1,8 +1,11 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
-#![allow(dead_code)]
+//! Provides access to the UEFI Common Platform Error Records (CPER).
 
+#![allow(dead_code, clippy::len_without_is_empty)]
+
+mod builder;
 pub mod descr;
 pub mod header;
 pub mod revision;
@@ -12,14 +15,19 @@ mod tests;
 mod utils;
 
 use crate::CrashLog;
+use crate::node::Node;
+#[cfg(feature = "collateral_manager")]
+use crate::severity::Severity;
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
+pub use builder::CperBuilder;
 use descr::{CperSectionDescriptor, SECTION_DESCRIPTOR_SIZE};
 use header::{CperHeader, RECORD_HEADER_SIZE};
 pub use section::{CperSection, CperSectionBody};
+use uguid::Guid;
 
 /// UEFI Common Platform Error Record (N)
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct Cper {
     /// CPER Record Header
     record_header: CperHeader,
@@ -41,7 +49,7 @@ impl Cper {
                 let body = CperSectionBody::from_slice(
                     descriptor.section_type,
                     slice.get(offset..end_offset)?,
-                )?;
+                );
                 Some(CperSection { descriptor, body })
             })
             .collect::<Vec<CperSection>>();
@@ -54,28 +62,53 @@ impl Cper {
         Some(cper)
     }
 
-    /// Create a CPER Section from a Crash Log.
-    pub fn from_raw_crashlog(crashlog: &CrashLog) -> Self {
-        let mut cper = Cper::default();
+    /// Searches for all the CPER records embedded at arbitrary offsets in a byte slice.
+    pub fn find_all(slice: &[u8]) -> Vec<Self> {
+        let mut cpers = Vec::new();
+        let mut cursor = 0;
 
-        cper.record_header.notification_type = header::notification_types::BOOT;
-        cper.record_header.error_severity = header::ErrorSeverity::Fatal;
+        while let Some(offset) = slice[cursor..]
+            .windows(4)
+            .position(|signature| signature == b"CPER")
+        {
+            let begin = cursor + offset;
+            cursor = begin + 4;
 
-        cper.record_header.timestamp = crashlog
-            .metadata
-            .time
-            .as_ref()
-            .map(header::Timestamp::from_crashlog_metadata);
+            let Some(header) = CperHeader::from_slice(&slice[begin..]) else {
+                continue;
+            };
+            let end = (begin + header.record_length as usize).min(slice.len());
+            let Some(cper) = Cper::from_slice(&slice[begin..end]) else {
+                continue;
+            };
 
-        for region in crashlog.regions.iter() {
-            let mut section = CperSection::from_crashlog_region(region);
-            section.descriptor.section_severity = descr::SectionSeverity::Fatal;
-            cper.append_section(section);
+            log::debug!("Found CPER record at offset {begin:#x}");
+            cpers.push(cper);
+            cursor = cursor.max(end);
         }
 
-        for extra_cper_section in crashlog.metadata.extra_cper_sections.iter() {
-            cper.append_section(CperSection::from_body(extra_cper_section.clone()));
+        cpers
+    }
+
+    /// Create a CPER Section from a Crash Log.
+    ///
+    /// See [CperBuilder] to customize the fields of the CPER header.
+    pub fn from_raw_crashlog(crashlog: &CrashLog) -> Self {
+        CperBuilder::new().crashlog(crashlog).build()
+    }
+
+    /// Create a CPER Section from a Crash Log using the given severity for each region.
+    ///
+    /// The severity of the CPER record is the highest severity of its sections.
+    #[cfg(feature = "collateral_manager")]
+    pub fn from_crashlog_with_severities(crashlog: &CrashLog, severities: &[Severity]) -> Self {
+        let mut cper = Self::from_raw_crashlog(crashlog);
+
+        for (section, severity) in cper.sections.iter_mut().zip(severities) {
+            section.descriptor.section_severity = (*severity).into();
         }
+        cper.record_header.error_severity =
+            severities.iter().max().copied().unwrap_or_default().into();
 
         cper
     }
@@ -87,6 +120,71 @@ impl Cper {
         self.normalize();
     }
 
+    /// Returns an iterator over the sections of the given type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::cper::Cper;
+    /// use intel_crashlog::cper::section::guids;
+    ///
+    /// let data = std::fs::read("tests/samples/cper.whea").unwrap();
+    /// let cper = Cper::from_slice(&data).unwrap();
+    ///
+    /// assert_eq!(cper.sections_by_guid(guids::FW_ERROR_RECORD).count(), 5);
+    /// assert_eq!(cper.sections_by_guid(guids::MEMORY_ERROR).count(), 0);
+    /// ```
+    pub fn sections_by_guid(&self, guid: Guid) -> impl Iterator<Item = &CperSection> {
+        self.sections
+            .iter()
+            .filter(move |section| section.descriptor.section_type == guid)
+    }
+
+    /// Only keeps the sections for which the predicate returns `true` and updates the header
+    /// fields accordingly.
+    pub fn retain_sections<F>(&mut self, f: F)
+    where
+        F: FnMut(&CperSection) -> bool,
+    {
+        self.sections.retain(f);
+        self.normalize();
+    }
+
+    /// Removes the sections of the given type from the CPER record and returns them.
+    pub fn remove_sections(&mut self, guid: Guid) -> Vec<CperSection> {
+        let (removed, kept): (Vec<_>, Vec<_>) = core::mem::take(&mut self.sections)
+            .into_iter()
+            .partition(|section| section.descriptor.section_type == guid);
+        self.sections = kept;
+        self.normalize();
+        removed
+    }
+
+    /// Returns a new CPER record sharing the same header, that only contains the sections of the
+    /// given types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::cper::Cper;
+    /// use intel_crashlog::cper::section::guids;
+    ///
+    /// let data = std::fs::read("tests/samples/cper.whea").unwrap();
+    /// let cper = Cper::from_slice(&data).unwrap();
+    ///
+    /// let selected = cper.select_sections(&[guids::FW_ERROR_RECORD]);
+    /// let selected = Cper::from_slice(&selected.to_bytes()).unwrap();
+    /// assert_eq!(selected.sections.len(), 5);
+    ///
+    /// let selected = cper.select_sections(&[guids::MEMORY_ERROR]);
+    /// assert_eq!(selected.sections.len(), 0);
+    /// ```
+    pub fn select_sections(&self, guids: &[Guid]) -> Cper {
+        let mut cper = self.clone();
+        cper.retain_sections(|section| guids.contains(&section.descriptor.section_type));
+        cper
+    }
+
     /// Updates the fields of the structures to reflect the actual binary layout of the CPER.
     fn normalize(&mut self) {
         self.record_header.section_count = self.sections.len() as u16;
@@ -103,6 +201,43 @@ impl Cper {
         self.record_header.normalize();
     }
 
+    /// Returns the register tree representation of the CPER record: its header, and the
+    /// descriptor and the body of each of its sections.
+    ///
+    /// The Crash Log regions embedded in the Firmware Error Records are decoded without relying
+    /// on the collateral tree. Use [`CrashLog::decode`] to get the full register tree of the
+    /// Crash Log records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::cper::Cper;
+    ///
+    /// let data = std::fs::read("tests/samples/cper.whea").unwrap();
+    /// let cper = Cper::from_slice(&data).unwrap();
+    ///
+    /// let root = cper.decode();
+    /// assert_eq!(root.get_value_by_path("cper.header.section_count"), Some(5));
+    /// assert!(root.get_by_path("cper.sections.section0.descriptor").is_some());
+    /// ```
+    pub fn decode(&self) -> Node {
+        let mut cper = Node::section("cper");
+        cper.add(Node::from(&self.record_header));
+
+        let mut sections = Node::section("sections");
+        for (i, section) in self.sections.iter().enumerate() {
+            let mut node = Node::section(&format!("section{i}"));
+            node.add(Node::from(&section.descriptor));
+            node.add(Node::from(&section.body));
+            sections.add(node);
+        }
+        cper.add(sections);
+
+        let mut root = Node::root();
+        root.add(cper);
+        root
+    }
+
     /// Serializes the CPER
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::new();
@@ -0,0 +1,156 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::Cper;
+use super::descr::SectionSeverity;
+use super::header::{self, CperHeader, ErrorSeverity, Timestamp};
+use super::section::CperSection;
+use crate::CrashLog;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use uguid::Guid;
+
+/// Builds a [Cper] record with custom header fields.
+///
+/// By default, the record is built with the `Fatal` severity and the `BOOT` notification type,
+/// like [`Cper::from_raw_crashlog`].
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::cper::CperBuilder;
+/// use intel_crashlog::cper::header::ErrorSeverity;
+/// use intel_crashlog::prelude::*;
+///
+/// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+/// let crashlog = CrashLog::from_slice(&data).unwrap();
+///
+/// let cper = CperBuilder::new()
+///     .severity(ErrorSeverity::Corrected)
+///     .record_id(42)
+///     .crashlog(&crashlog)
+///     .build();
+/// assert_eq!(cper.sections.len(), 1);
+/// ```
+pub struct CperBuilder {
+    header: CperHeader,
+    sections: Vec<CperSection>,
+    /// Indices of the sections storing the Crash Log regions
+    regions: Vec<usize>,
+}
+
+impl Default for CperBuilder {
+    fn default() -> Self {
+        Self {
+            header: CperHeader {
+                notification_type: header::notification_types::BOOT,
+                error_severity: ErrorSeverity::Fatal,
+                ..CperHeader::default()
+            },
+            sections: Vec::new(),
+            regions: Vec::new(),
+        }
+    }
+}
+
+impl CperBuilder {
+    /// Returns a new builder using the default header fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the severity of the record. The sections storing the Crash Log regions get the same
+    /// severity.
+    pub fn severity(mut self, severity: ErrorSeverity) -> Self {
+        self.header.error_severity = severity;
+        self
+    }
+
+    /// Sets the platform ID of the record.
+    pub fn platform_id(mut self, platform_id: Guid) -> Self {
+        self.header.platform_id = Some(platform_id);
+        self
+    }
+
+    /// Sets the partition ID of the record.
+    pub fn partition_id(mut self, partition_id: Guid) -> Self {
+        self.header.partition_id = Some(partition_id);
+        self
+    }
+
+    /// Sets the ID of the record creator.
+    pub fn creator_id(mut self, creator_id: Guid) -> Self {
+        self.header.creator_id = creator_id;
+        self
+    }
+
+    /// Sets the notification type of the record.
+    pub fn notification_type(mut self, notification_type: Guid) -> Self {
+        self.header.notification_type = notification_type;
+        self
+    }
+
+    /// Sets the record ID.
+    pub fn record_id(mut self, record_id: u64) -> Self {
+        self.header.record_id = record_id;
+        self
+    }
+
+    /// Sets the flags of the record (see [`header::flags`]).
+    pub fn flags(mut self, flags: u32) -> Self {
+        self.header.flags = flags;
+        self
+    }
+
+    /// Sets the timestamp of the record.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.header.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Adds the regions of a Crash Log to the record as Firmware Error Record sections, followed
+    /// by the extra CPER sections stored in its metadata. The timestamp of the record is taken
+    /// from the metadata of the Crash Log, if not set already.
+    pub fn crashlog(mut self, crashlog: &CrashLog) -> Self {
+        if self.header.timestamp.is_none() {
+            self.header.timestamp = crashlog
+                .metadata
+                .time
+                .as_ref()
+                .map(Timestamp::from_crashlog_metadata);
+        }
+
+        for region in crashlog.regions.iter() {
+            self.regions.push(self.sections.len());
+            self.sections
+                .push(CperSection::from_crashlog_region(region));
+        }
+
+        for extra_cper_section in crashlog.metadata.extra_cper_sections.iter() {
+            self.sections
+                .push(CperSection::from_body(extra_cper_section.clone()));
+        }
+        self
+    }
+
+    /// Adds a section to the record.
+    pub fn section(mut self, section: CperSection) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Builds the CPER record.
+    pub fn build(mut self) -> Cper {
+        let severity = SectionSeverity::from(self.header.error_severity as u32);
+        for &i in self.regions.iter() {
+            self.sections[i].descriptor.section_severity = severity;
+        }
+
+        let mut cper = Cper {
+            record_header: self.header,
+            sections: self.sections,
+        };
+        cper.normalize();
+        cper
+    }
+}
@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: MIT
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{format, string::String, vec::Vec};
 
 use super::revision::Revision;
+use crate::node::Node;
+#[cfg(feature = "collateral_manager")]
+use crate::severity::Severity;
 use uguid::Guid;
 
 /// cbindgen:ignore
@@ -38,6 +41,17 @@ pub enum SectionSeverity {
     Informational = 3,
 }
 
+#[cfg(feature = "collateral_manager")]
+impl From<Severity> for SectionSeverity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Informational => SectionSeverity::Informational,
+            Severity::Corrected => SectionSeverity::Corrected,
+            Severity::Fatal => SectionSeverity::Fatal,
+        }
+    }
+}
+
 impl From<u32> for SectionSeverity {
     fn from(value: u32) -> Self {
         match value {
@@ -141,3 +155,45 @@ impl CperSectionDescriptor {
         bytes
     }
 }
+
+impl From<&CperSectionDescriptor> for Node {
+    fn from(descriptor: &CperSectionDescriptor) -> Self {
+        let mut node = Node::section("descriptor");
+        node.add(Node::field(
+            "section_offset",
+            descriptor.section_offset as u64,
+        ));
+        node.add(Node::field(
+            "section_length",
+            descriptor.section_length as u64,
+        ));
+        node.add(Node::from(&descriptor.revision));
+        node.add(Node::field(
+            "validation_bits",
+            descriptor.validation_bits as u64,
+        ));
+        node.add(Node::field("flags", descriptor.flags as u64));
+        node.add(Node::string(
+            "section_type",
+            &format!("{}", descriptor.section_type),
+        ));
+        if let Some(fru_id) = descriptor.fru_id {
+            node.add(Node::string("fru_id", &format!("{fru_id}")));
+        }
+        node.add(Node::field(
+            "section_severity",
+            descriptor.section_severity as u32 as u64,
+        ));
+        if let Some(fru_text) = &descriptor.fru_text {
+            let end = fru_text
+                .iter()
+                .position(|&b| b == 0)
+                .unwrap_or(fru_text.len());
+            node.add(Node::string(
+                "fru_text",
+                &String::from_utf8_lossy(&fru_text[..end]),
+            ));
+        }
+        node
+    }
+}
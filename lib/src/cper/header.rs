@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: MIT
 
 #[cfg(not(feature = "std"))]
-use alloc::{vec, vec::Vec};
+use alloc::{format, vec, vec::Vec};
 
 use super::revision::Revision;
 use super::utils;
 use crate::metadata;
+use crate::node::Node;
+#[cfg(feature = "collateral_manager")]
+use crate::severity::Severity;
 use uguid::Guid;
 
 /// cbindgen:ignore
@@ -43,6 +46,17 @@ pub enum ErrorSeverity {
     Informational = 3,
 }
 
+#[cfg(feature = "collateral_manager")]
+impl From<Severity> for ErrorSeverity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Informational => ErrorSeverity::Informational,
+            Severity::Corrected => ErrorSeverity::Corrected,
+            Severity::Fatal => ErrorSeverity::Fatal,
+        }
+    }
+}
+
 impl From<u32> for ErrorSeverity {
     fn from(value: u32) -> Self {
         match value {
@@ -228,3 +242,59 @@ impl CperHeader {
         bytes
     }
 }
+
+impl From<&Timestamp> for Node {
+    fn from(timestamp: &Timestamp) -> Self {
+        let mut node = Node::section("timestamp");
+        node.add(Node::field("seconds", timestamp.seconds as u64));
+        node.add(Node::field("minutes", timestamp.minutes as u64));
+        node.add(Node::field("hours", timestamp.hours as u64));
+        node.add(Node::field("precise", timestamp.precise as u64));
+        node.add(Node::field("day", timestamp.day as u64));
+        node.add(Node::field("month", timestamp.month as u64));
+        node.add(Node::field("year", timestamp.year as u64));
+        node.add(Node::field("century", timestamp.century as u64));
+        node
+    }
+}
+
+impl From<&CperHeader> for Node {
+    fn from(header: &CperHeader) -> Self {
+        let mut node = Node::section("header");
+        node.add(Node::from(&header.revision));
+        node.add(Node::field("section_count", header.section_count as u64));
+        node.add(Node::field(
+            "error_severity",
+            header.error_severity as u32 as u64,
+        ));
+        node.add(Node::field(
+            "validation_bits",
+            header.validation_bits as u64,
+        ));
+        node.add(Node::field("record_length", header.record_length as u64));
+        if let Some(timestamp) = &header.timestamp {
+            node.add(Node::from(timestamp));
+        }
+        if let Some(platform_id) = header.platform_id {
+            node.add(Node::string("platform_id", &format!("{platform_id}")));
+        }
+        if let Some(partition_id) = header.partition_id {
+            node.add(Node::string("partition_id", &format!("{partition_id}")));
+        }
+        node.add(Node::string(
+            "creator_id",
+            &format!("{}", header.creator_id),
+        ));
+        node.add(Node::string(
+            "notification_type",
+            &format!("{}", header.notification_type),
+        ));
+        node.add(Node::field("record_id", header.record_id));
+        node.add(Node::field("flags", header.flags as u64));
+        node.add(Node::field(
+            "persistence_information",
+            header.persistence_information,
+        ));
+        node
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use crate::node::Node;
 #[cfg(not(feature = "std"))]
 use alloc::{fmt, vec, vec::Vec};
 #[cfg(feature = "std")]
@@ -35,3 +36,12 @@ impl Revision {
         vec![self.minor, self.major]
     }
 }
+
+impl From<&Revision> for Node {
+    fn from(revision: &Revision) -> Self {
+        let mut node = Node::section("revision");
+        node.add(Node::field("major", revision.major as u64));
+        node.add(Node::field("minor", revision.minor as u64));
+        node
+    }
+}
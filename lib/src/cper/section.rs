@@ -2,36 +2,67 @@
 // SPDX-License-Identifier: MIT
 
 pub mod fer;
+pub mod ia32x64;
+pub mod memory;
+pub mod pcie;
+pub mod processor;
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
 
 use super::descr::CperSectionDescriptor;
+use crate::node::Node;
 use crate::region::Region;
 use fer::FirmwareErrorRecord;
+use ia32x64::Ia32X64ProcessorErrorSection;
+use memory::MemoryErrorSection;
+use pcie::PcieErrorSection;
+use processor::ProcessorGenericErrorSection;
 use uguid::Guid;
 
 pub mod guids {
     //! GUIDs of the standard CPER sections
     use uguid::Guid;
     pub const FW_ERROR_RECORD: Guid = uguid::guid!("81212a96-09ed-4996-9471-8d729c8e69ed");
+    pub const IA32X64_PROCESSOR: Guid = uguid::guid!("dc3ea0b0-a144-4797-b95b-53fa242b6e1d");
+    pub const MEMORY_ERROR: Guid = uguid::guid!("a5bc1114-6f64-4ede-b863-3e83ed7c83b1");
+    pub const PCIE_ERROR: Guid = uguid::guid!("d995e954-bbc1-430f-ad91-b44dcb3c6f35");
+    pub const PROCESSOR_GENERIC: Guid = uguid::guid!("9876ccad-47b4-4bdb-b65e-16f193c4f3db");
 }
 
 /// One of the CPER section bodies defined in the UEFI 2.10 Specifications (N.2)
 #[derive(Clone)]
 pub enum CperSectionBody {
     FirmwareErrorRecord(FirmwareErrorRecord),
+    ProcessorGenericError(ProcessorGenericErrorSection),
+    Ia32X64ProcessorError(Ia32X64ProcessorErrorSection),
+    MemoryError(MemoryErrorSection),
+    PcieError(PcieErrorSection),
     Unknown(Guid, Vec<u8>),
 }
 
 impl CperSectionBody {
-    /// Parses a section from a slice.
-    pub fn from_slice(guid: uguid::Guid, s: &[u8]) -> Option<Self> {
-        Some(match guid {
+    /// Parses a section from a slice. The sections that cannot be parsed (e.g. truncated
+    /// sections) are kept as [CperSectionBody::Unknown].
+    pub fn from_slice(guid: uguid::Guid, s: &[u8]) -> Self {
+        let body = match guid {
             guids::FW_ERROR_RECORD => {
-                CperSectionBody::FirmwareErrorRecord(fer::FirmwareErrorRecord::from_slice(s)?)
+                fer::FirmwareErrorRecord::from_slice(s).map(CperSectionBody::FirmwareErrorRecord)
             }
-            _ => CperSectionBody::Unknown(guid, Vec::from(s)),
+            guids::PROCESSOR_GENERIC => ProcessorGenericErrorSection::from_slice(s)
+                .map(CperSectionBody::ProcessorGenericError),
+            guids::IA32X64_PROCESSOR => Ia32X64ProcessorErrorSection::from_slice(s)
+                .map(CperSectionBody::Ia32X64ProcessorError),
+            guids::MEMORY_ERROR => {
+                MemoryErrorSection::from_slice(s).map(CperSectionBody::MemoryError)
+            }
+            guids::PCIE_ERROR => PcieErrorSection::from_slice(s).map(CperSectionBody::PcieError),
+            _ => return CperSectionBody::Unknown(guid, Vec::from(s)),
+        };
+
+        body.unwrap_or_else(|| {
+            log::warn!("Invalid CPER section {guid}, keeping its raw content");
+            CperSectionBody::Unknown(guid, Vec::from(s))
         })
     }
 
@@ -39,6 +70,10 @@ impl CperSectionBody {
     pub fn guid(&self) -> Guid {
         match self {
             CperSectionBody::FirmwareErrorRecord(_) => guids::FW_ERROR_RECORD,
+            CperSectionBody::ProcessorGenericError(_) => guids::PROCESSOR_GENERIC,
+            CperSectionBody::Ia32X64ProcessorError(_) => guids::IA32X64_PROCESSOR,
+            CperSectionBody::MemoryError(_) => guids::MEMORY_ERROR,
+            CperSectionBody::PcieError(_) => guids::PCIE_ERROR,
             CperSectionBody::Unknown(guid, _) => *guid,
         }
     }
@@ -47,6 +82,10 @@ impl CperSectionBody {
     pub fn len(&self) -> usize {
         match self {
             CperSectionBody::FirmwareErrorRecord(fer) => fer.header.len() + fer.payload.len(),
+            CperSectionBody::ProcessorGenericError(section) => section.len(),
+            CperSectionBody::Ia32X64ProcessorError(section) => section.len(),
+            CperSectionBody::MemoryError(section) => section.len(),
+            CperSectionBody::PcieError(section) => section.len(),
             CperSectionBody::Unknown(_, data) => data.len(),
         }
     }
@@ -55,6 +94,10 @@ impl CperSectionBody {
     pub fn to_bytes(&self) -> Vec<u8> {
         let bytes = match self {
             CperSectionBody::FirmwareErrorRecord(fer) => fer.to_bytes(),
+            CperSectionBody::ProcessorGenericError(section) => section.to_bytes(),
+            CperSectionBody::Ia32X64ProcessorError(section) => section.to_bytes(),
+            CperSectionBody::MemoryError(section) => section.to_bytes(),
+            CperSectionBody::PcieError(section) => section.to_bytes(),
             CperSectionBody::Unknown(_, data) => data.clone(),
         };
 
@@ -64,6 +107,7 @@ impl CperSectionBody {
 }
 
 /// The descriptor and the body of the CPER Section.
+#[derive(Clone)]
 pub struct CperSection {
     pub descriptor: CperSectionDescriptor,
     pub body: CperSectionBody,
@@ -98,3 +142,21 @@ impl CperSection {
         bytes
     }
 }
+
+impl From<&CperSectionBody> for Node {
+    fn from(body: &CperSectionBody) -> Self {
+        match body {
+            CperSectionBody::FirmwareErrorRecord(fer) => Node::from(fer),
+            CperSectionBody::ProcessorGenericError(section) => Node::from(section),
+            CperSectionBody::Ia32X64ProcessorError(section) => Node::from(section),
+            CperSectionBody::MemoryError(section) => Node::from(section),
+            CperSectionBody::PcieError(section) => Node::from(section),
+            CperSectionBody::Unknown(guid, data) => {
+                let mut node = Node::section("unknown");
+                node.add(Node::string("guid", &format!("{guid}")));
+                node.add(Node::bytes("data", data));
+                node
+            }
+        }
+    }
+}
@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: MIT
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
 use uguid::Guid;
 
+use crate::node::Node;
 use crate::region::Region;
 
 pub mod guids {
@@ -99,3 +100,28 @@ impl FirmwareErrorRecord {
         bytes
     }
 }
+
+impl From<&FirmwareErrorRecord> for Node {
+    fn from(fer: &FirmwareErrorRecord) -> Self {
+        let mut node = Node::section("firmware_error_record");
+        node.add(Node::field("error_type", fer.header.error_type as u64));
+        node.add(Node::field("revision", fer.header.revision as u64));
+        node.add(Node::field(
+            "record_identifier",
+            fer.header.record_identifier,
+        ));
+        node.add(Node::string("guid", &format!("{}", fer.header.guid)));
+        node.add(Node::field("payload_length", fer.payload.len() as u64));
+
+        if fer.header.guid == guids::RECORD_ID_CRASHLOG
+            && let Ok(region) = Region::from_slice(&fer.payload)
+        {
+            let mut crashlog = Node::section("crashlog");
+            for record in region.records.iter() {
+                crashlog.merge(record.decode_without_cm());
+            }
+            node.add(crashlog);
+        }
+        node
+    }
+}
@@ -0,0 +1,387 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::node::Node;
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+use uguid::Guid;
+
+/// cbindgen:ignore
+pub const HEADER_SIZE: usize = 64;
+/// cbindgen:ignore
+pub const ERROR_INFO_SIZE: usize = 64;
+/// cbindgen:ignore
+pub const CONTEXT_INFO_HEADER_SIZE: usize = 16;
+
+pub mod guids {
+    //! GUIDs used to identify the type of the processor error information structures
+    use uguid::Guid;
+    pub const CACHE_CHECK: Guid = uguid::guid!("a55701f5-e3ef-43de-ac72-249b573fad2c");
+    pub const TLB_CHECK: Guid = uguid::guid!("fc06b535-5e1f-4562-9f25-0a3b9adb63c3");
+    pub const BUS_CHECK: Guid = uguid::guid!("1cf3f8b3-c5b1-49a2-aa59-5eef92ffa63c");
+    pub const MS_CHECK: Guid = uguid::guid!("48ab7f57-dc34-4f6c-a7d3-b0b5b0a74314");
+}
+
+/// cbindgen:ignore
+pub mod register_context_types {
+    pub const UNCLASSIFIED: u16 = 0;
+    pub const MSR: u16 = 1;
+    pub const IA32_EXECUTION: u16 = 2;
+    pub const X64_EXECUTION: u16 = 3;
+    pub const FXSAVE: u16 = 4;
+    pub const IA32_DEBUG: u16 = 5;
+    pub const X64_DEBUG: u16 = 6;
+    pub const MEMORY_MAPPED: u16 = 7;
+}
+
+/// cbindgen:ignore
+mod validation {
+    pub const LOCAL_APIC_ID: u64 = 1 << 0;
+    pub const CPUID_INFO: u64 = 1 << 1;
+    pub const ERROR_INFO_COUNT_SHIFT: u64 = 2;
+    pub const CONTEXT_INFO_COUNT_SHIFT: u64 = 8;
+    pub const COUNT_MASK: u64 = 0x3f;
+
+    pub const CHECK_INFO: u64 = 1 << 0;
+    pub const TARGET_ID: u64 = 1 << 1;
+    pub const REQUESTOR_ID: u64 = 1 << 2;
+    pub const RESPONDER_ID: u64 = 1 << 3;
+    pub const INSTRUCTION_POINTER: u64 = 1 << 4;
+}
+
+/// Address of the IA32_MC0_CTL MSR
+const IA32_MC0_CTL: u32 = 0x400;
+/// Names of the MSRs of a machine check bank, in address order
+const MCA_BANK_REGISTERS: [&str; 4] = ["ctl", "status", "addr", "misc"];
+/// Number of machine check banks mapped from IA32_MC0_CTL
+const MCA_BANK_COUNT: usize = 32;
+
+/// UEFI 2.10 N.2.4.2.1 IA32/X64 Processor Error Information Structure
+#[derive(Debug, Clone, Default)]
+pub struct ErrorInfo {
+    pub error_type: Guid,
+    pub check_info: Option<u64>,
+    pub target_id: Option<u64>,
+    pub requestor_id: Option<u64>,
+    pub responder_id: Option<u64>,
+    pub instruction_pointer: Option<u64>,
+}
+
+/// UEFI 2.10 N.2.4.2.2 IA32/X64 Processor Context Information
+#[derive(Debug, Clone, Default)]
+pub struct ContextInfo {
+    /// Type of the registers stored in the context (see [register_context_types])
+    pub register_context_type: u16,
+    /// Address of the first MSR stored in the register array, if the context stores MSRs
+    pub msr_address: u32,
+    /// Address of the first memory mapped register stored in the register array, if the context
+    /// stores memory mapped registers
+    pub mm_register_address: u64,
+    pub register_array: Vec<u8>,
+}
+
+/// UEFI 2.10 N.2.4.2 IA32/X64 Processor Error Section
+#[derive(Debug, Clone, Default)]
+pub struct Ia32X64ProcessorErrorSection {
+    pub local_apic_id: Option<u64>,
+    pub cpuid_info: Option<[u8; 48]>,
+    pub error_info: Vec<ErrorInfo>,
+    pub context_info: Vec<ContextInfo>,
+}
+
+fn read_u16(s: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([s[offset], s[offset + 1]])
+}
+
+fn read_u32(s: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(&s[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+fn read_u64(s: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&s[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+impl ErrorInfo {
+    fn from_slice(s: &[u8]) -> Option<Self> {
+        let s = s.get(..ERROR_INFO_SIZE)?;
+        let validation_bits = read_u64(s, 16);
+        let valid = |bit: u64| validation_bits & bit != 0;
+        let mut error_type = [0; 16];
+        error_type.copy_from_slice(&s[0..16]);
+
+        Some(Self {
+            error_type: Guid::from_bytes(error_type),
+            check_info: valid(validation::CHECK_INFO).then(|| read_u64(s, 24)),
+            target_id: valid(validation::TARGET_ID).then(|| read_u64(s, 32)),
+            requestor_id: valid(validation::REQUESTOR_ID).then(|| read_u64(s, 40)),
+            responder_id: valid(validation::RESPONDER_ID).then(|| read_u64(s, 48)),
+            instruction_pointer: valid(validation::INSTRUCTION_POINTER).then(|| read_u64(s, 56)),
+        })
+    }
+
+    /// Returns the validation bits matching the fields that are set.
+    pub fn validation_bits(&self) -> u64 {
+        [
+            (self.check_info.is_some(), validation::CHECK_INFO),
+            (self.target_id.is_some(), validation::TARGET_ID),
+            (self.requestor_id.is_some(), validation::REQUESTOR_ID),
+            (self.responder_id.is_some(), validation::RESPONDER_ID),
+            (
+                self.instruction_pointer.is_some(),
+                validation::INSTRUCTION_POINTER,
+            ),
+        ]
+        .into_iter()
+        .filter(|(valid, _)| *valid)
+        .fold(0, |bits, (_, bit)| bits | bit)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.error_type.to_bytes());
+        bytes.extend_from_slice(&self.validation_bits().to_le_bytes());
+        for field in [
+            self.check_info,
+            self.target_id,
+            self.requestor_id,
+            self.responder_id,
+            self.instruction_pointer,
+        ] {
+            bytes.extend_from_slice(&field.unwrap_or_default().to_le_bytes());
+        }
+
+        debug_assert_eq!(bytes.len(), ERROR_INFO_SIZE);
+        bytes
+    }
+
+    /// Returns the name of the type of the error information structure.
+    pub fn error_type_name(&self) -> Option<&'static str> {
+        match self.error_type {
+            guids::CACHE_CHECK => Some("cache_check"),
+            guids::TLB_CHECK => Some("tlb_check"),
+            guids::BUS_CHECK => Some("bus_check"),
+            guids::MS_CHECK => Some("ms_check"),
+            _ => None,
+        }
+    }
+}
+
+impl ContextInfo {
+    fn from_slice(s: &[u8]) -> Option<Self> {
+        let header = s.get(..CONTEXT_INFO_HEADER_SIZE)?;
+        let size = read_u16(header, 2) as usize;
+        Some(Self {
+            register_context_type: read_u16(header, 0),
+            msr_address: read_u32(header, 4),
+            mm_register_address: read_u64(header, 8),
+            register_array: Vec::from(
+                s.get(CONTEXT_INFO_HEADER_SIZE..CONTEXT_INFO_HEADER_SIZE + size)?,
+            ),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.register_context_type.to_le_bytes());
+        bytes.extend_from_slice(&(self.register_array.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&self.msr_address.to_le_bytes());
+        bytes.extend_from_slice(&self.mm_register_address.to_le_bytes());
+        bytes.extend_from_slice(&self.register_array);
+        bytes
+    }
+
+    /// Returns the size of the structure in bytes
+    pub fn len(&self) -> usize {
+        CONTEXT_INFO_HEADER_SIZE + self.register_array.len()
+    }
+
+    /// Returns the address and the value of the MSRs stored in the context, if the context
+    /// stores MSRs.
+    pub fn msrs(&self) -> impl Iterator<Item = (u32, u64)> + '_ {
+        let msrs = if self.register_context_type == register_context_types::MSR {
+            self.register_array.as_slice()
+        } else {
+            &[]
+        };
+
+        msrs.chunks_exact(8).enumerate().map(|(i, msr)| {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(msr);
+            (
+                self.msr_address.wrapping_add(i as u32),
+                u64::from_le_bytes(bytes),
+            )
+        })
+    }
+}
+
+impl Ia32X64ProcessorErrorSection {
+    /// Parses the section from a slice.
+    pub fn from_slice(s: &[u8]) -> Option<Self> {
+        let header = s.get(..HEADER_SIZE)?;
+        let validation_bits = read_u64(header, 0);
+        let valid = |bit: u64| validation_bits & bit != 0;
+        let error_info_count =
+            (validation_bits >> validation::ERROR_INFO_COUNT_SHIFT) & validation::COUNT_MASK;
+        let context_info_count =
+            (validation_bits >> validation::CONTEXT_INFO_COUNT_SHIFT) & validation::COUNT_MASK;
+
+        let mut section = Self {
+            local_apic_id: valid(validation::LOCAL_APIC_ID).then(|| read_u64(header, 8)),
+            cpuid_info: if valid(validation::CPUID_INFO) {
+                let mut cpuid_info = [0; 48];
+                cpuid_info.copy_from_slice(&header[16..64]);
+                Some(cpuid_info)
+            } else {
+                None
+            },
+            ..Self::default()
+        };
+
+        let mut cursor = HEADER_SIZE;
+        for _ in 0..error_info_count {
+            section
+                .error_info
+                .push(ErrorInfo::from_slice(s.get(cursor..)?)?);
+            cursor += ERROR_INFO_SIZE;
+        }
+        for _ in 0..context_info_count {
+            let context_info = ContextInfo::from_slice(s.get(cursor..)?)?;
+            cursor += context_info.len();
+            section.context_info.push(context_info);
+        }
+
+        Some(section)
+    }
+
+    /// Returns the validation bits matching the fields that are set.
+    pub fn validation_bits(&self) -> u64 {
+        let mut validation_bits = ((self.error_info.len() as u64 & validation::COUNT_MASK)
+            << validation::ERROR_INFO_COUNT_SHIFT)
+            | ((self.context_info.len() as u64 & validation::COUNT_MASK)
+                << validation::CONTEXT_INFO_COUNT_SHIFT);
+        if self.local_apic_id.is_some() {
+            validation_bits |= validation::LOCAL_APIC_ID;
+        }
+        if self.cpuid_info.is_some() {
+            validation_bits |= validation::CPUID_INFO;
+        }
+        validation_bits
+    }
+
+    /// Converts the section into a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.validation_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.local_apic_id.unwrap_or_default().to_le_bytes());
+        bytes.extend_from_slice(&self.cpuid_info.unwrap_or([0; 48]));
+        for error_info in self.error_info.iter() {
+            bytes.append(&mut error_info.to_bytes());
+        }
+        for context_info in self.context_info.iter() {
+            bytes.append(&mut context_info.to_bytes());
+        }
+
+        debug_assert_eq!(bytes.len(), self.len());
+        bytes
+    }
+
+    /// Returns the size of the section in bytes
+    pub fn len(&self) -> usize {
+        HEADER_SIZE
+            + self.error_info.len() * ERROR_INFO_SIZE
+            + self
+                .context_info
+                .iter()
+                .map(ContextInfo::len)
+                .sum::<usize>()
+    }
+}
+
+impl From<&ErrorInfo> for Node {
+    fn from(error_info: &ErrorInfo) -> Self {
+        let mut node = Node::section("error_info");
+        match error_info.error_type_name() {
+            Some(name) => node.add(Node::string("type", name)),
+            None => node.add(Node::string("type", &format!("{}", error_info.error_type))),
+        }
+        for (name, value) in [
+            ("check_info", error_info.check_info),
+            ("target_id", error_info.target_id),
+            ("requestor_id", error_info.requestor_id),
+            ("responder_id", error_info.responder_id),
+            ("instruction_pointer", error_info.instruction_pointer),
+        ] {
+            if let Some(value) = value {
+                node.add(Node::field(name, value));
+            }
+        }
+        node
+    }
+}
+
+impl From<&ContextInfo> for Node {
+    fn from(context_info: &ContextInfo) -> Self {
+        let mut node = Node::section("context_info");
+        node.add(Node::field(
+            "register_context_type",
+            context_info.register_context_type as u64,
+        ));
+
+        if context_info.register_context_type == register_context_types::MSR {
+            node.add(Node::field("msr_address", context_info.msr_address as u64));
+
+            // The machine check banks are decoded using the architectural MSR layout.
+            let mut mca = Node::section("mca");
+            for (address, value) in context_info.msrs() {
+                let Some(offset) = address.checked_sub(IA32_MC0_CTL) else {
+                    continue;
+                };
+                let bank = offset as usize / MCA_BANK_REGISTERS.len();
+                let register = MCA_BANK_REGISTERS[offset as usize % MCA_BANK_REGISTERS.len()];
+                if bank < MCA_BANK_COUNT {
+                    mca.create_hierarchy(&format!("bank{bank}"))
+                        .add(Node::field(register, value));
+                }
+            }
+            if mca.children().next().is_some() {
+                node.add(mca);
+            }
+        } else if context_info.register_context_type == register_context_types::MEMORY_MAPPED {
+            node.add(Node::field(
+                "mm_register_address",
+                context_info.mm_register_address,
+            ));
+        }
+
+        node.add(Node::bytes("register_array", &context_info.register_array));
+        node
+    }
+}
+
+impl From<&Ia32X64ProcessorErrorSection> for Node {
+    fn from(section: &Ia32X64ProcessorErrorSection) -> Self {
+        let mut node = Node::section("ia32x64_processor_error");
+        if let Some(local_apic_id) = section.local_apic_id {
+            node.add(Node::field("local_apic_id", local_apic_id));
+        }
+        if let Some(cpuid_info) = &section.cpuid_info {
+            node.add(Node::bytes("cpuid_info", cpuid_info));
+        }
+        for (i, error_info) in section.error_info.iter().enumerate() {
+            let mut child = Node::from(error_info);
+            child.name = format!("error_info{i}");
+            node.add(child);
+        }
+        for (i, context_info) in section.context_info.iter().enumerate() {
+            let mut child = Node::from(context_info);
+            child.name = format!("context_info{i}");
+            node.add(child);
+        }
+        node
+    }
+}
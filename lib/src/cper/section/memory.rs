@@ -0,0 +1,251 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::node::Node;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// cbindgen:ignore
+pub const MEMORY_ERROR_SIZE: usize = 80;
+
+/// cbindgen:ignore
+mod validation {
+    pub const ERROR_STATUS: u64 = 1 << 0;
+    pub const PHYSICAL_ADDRESS: u64 = 1 << 1;
+    pub const PHYSICAL_ADDRESS_MASK: u64 = 1 << 2;
+    pub const NODE: u64 = 1 << 3;
+    pub const CARD: u64 = 1 << 4;
+    pub const MODULE: u64 = 1 << 5;
+    pub const BANK: u64 = 1 << 6;
+    pub const DEVICE: u64 = 1 << 7;
+    pub const ROW: u64 = 1 << 8;
+    pub const COLUMN: u64 = 1 << 9;
+    pub const BIT_POSITION: u64 = 1 << 10;
+    pub const REQUESTOR_ID: u64 = 1 << 11;
+    pub const RESPONDER_ID: u64 = 1 << 12;
+    pub const TARGET_ID: u64 = 1 << 13;
+    pub const MEMORY_ERROR_TYPE: u64 = 1 << 14;
+    pub const RANK_NUMBER: u64 = 1 << 15;
+    pub const CARD_HANDLE: u64 = 1 << 16;
+    pub const MODULE_HANDLE: u64 = 1 << 17;
+    pub const EXTENDED_ROW: u64 = 1 << 18;
+    pub const BANK_GROUP: u64 = 1 << 19;
+    pub const BANK_ADDRESS: u64 = 1 << 20;
+    pub const CHIP_ID: u64 = 1 << 21;
+}
+
+/// UEFI 2.10 N.2.5 Memory Error Section
+///
+/// The fields are set to [`None`] when the corresponding validation bit is cleared.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryErrorSection {
+    pub error_status: Option<u64>,
+    pub physical_address: Option<u64>,
+    pub physical_address_mask: Option<u64>,
+    pub node: Option<u16>,
+    pub card: Option<u16>,
+    pub module: Option<u16>,
+    pub bank: Option<u16>,
+    /// Bank group, stored in the upper byte of the bank field
+    pub bank_group: Option<u8>,
+    /// Bank address, stored in the lower byte of the bank field
+    pub bank_address: Option<u8>,
+    pub device: Option<u16>,
+    /// Row number, including the bits 16 and 17 stored in the extended field
+    pub row: Option<u32>,
+    pub column: Option<u16>,
+    pub bit_position: Option<u16>,
+    pub requestor_id: Option<u64>,
+    pub responder_id: Option<u64>,
+    pub target_id: Option<u64>,
+    pub memory_error_type: Option<u8>,
+    pub rank_number: Option<u16>,
+    pub card_handle: Option<u16>,
+    pub module_handle: Option<u16>,
+    /// Chip identification, stored in the bits 5 to 7 of the extended field
+    pub chip_id: Option<u8>,
+}
+
+fn read_u16(s: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([s[offset], s[offset + 1]])
+}
+
+fn read_u64(s: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&s[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+impl MemoryErrorSection {
+    /// Parses the section from a slice.
+    pub fn from_slice(s: &[u8]) -> Option<Self> {
+        let s = s.get(..MEMORY_ERROR_SIZE)?;
+        let validation_bits = read_u64(s, 0);
+        let valid = |bit: u64| validation_bits & bit != 0;
+        let extended = s[73];
+
+        Some(Self {
+            error_status: valid(validation::ERROR_STATUS).then(|| read_u64(s, 8)),
+            physical_address: valid(validation::PHYSICAL_ADDRESS).then(|| read_u64(s, 16)),
+            physical_address_mask: valid(validation::PHYSICAL_ADDRESS_MASK)
+                .then(|| read_u64(s, 24)),
+            node: valid(validation::NODE).then(|| read_u16(s, 32)),
+            card: valid(validation::CARD).then(|| read_u16(s, 34)),
+            module: valid(validation::MODULE).then(|| read_u16(s, 36)),
+            bank: valid(validation::BANK).then(|| read_u16(s, 38)),
+            bank_group: valid(validation::BANK_GROUP).then_some(s[39]),
+            bank_address: valid(validation::BANK_ADDRESS).then_some(s[38]),
+            device: valid(validation::DEVICE).then(|| read_u16(s, 40)),
+            row: valid(validation::ROW).then(|| {
+                let row = read_u16(s, 42) as u32;
+                if valid(validation::EXTENDED_ROW) {
+                    row | ((extended as u32 & 0x3) << 16)
+                } else {
+                    row
+                }
+            }),
+            column: valid(validation::COLUMN).then(|| read_u16(s, 44)),
+            bit_position: valid(validation::BIT_POSITION).then(|| read_u16(s, 46)),
+            requestor_id: valid(validation::REQUESTOR_ID).then(|| read_u64(s, 48)),
+            responder_id: valid(validation::RESPONDER_ID).then(|| read_u64(s, 56)),
+            target_id: valid(validation::TARGET_ID).then(|| read_u64(s, 64)),
+            memory_error_type: valid(validation::MEMORY_ERROR_TYPE).then_some(s[72]),
+            rank_number: valid(validation::RANK_NUMBER).then(|| read_u16(s, 74)),
+            card_handle: valid(validation::CARD_HANDLE).then(|| read_u16(s, 76)),
+            module_handle: valid(validation::MODULE_HANDLE).then(|| read_u16(s, 78)),
+            chip_id: valid(validation::CHIP_ID).then_some(extended >> 5),
+        })
+    }
+
+    /// Returns the validation bits matching the fields that are set.
+    pub fn validation_bits(&self) -> u64 {
+        [
+            (self.error_status.is_some(), validation::ERROR_STATUS),
+            (
+                self.physical_address.is_some(),
+                validation::PHYSICAL_ADDRESS,
+            ),
+            (
+                self.physical_address_mask.is_some(),
+                validation::PHYSICAL_ADDRESS_MASK,
+            ),
+            (self.node.is_some(), validation::NODE),
+            (self.card.is_some(), validation::CARD),
+            (self.module.is_some(), validation::MODULE),
+            (self.bank.is_some(), validation::BANK),
+            (self.bank_group.is_some(), validation::BANK_GROUP),
+            (self.bank_address.is_some(), validation::BANK_ADDRESS),
+            (self.device.is_some(), validation::DEVICE),
+            (self.row.is_some(), validation::ROW),
+            (
+                self.row.is_some_and(|row| row > 0xffff),
+                validation::EXTENDED_ROW,
+            ),
+            (self.column.is_some(), validation::COLUMN),
+            (self.bit_position.is_some(), validation::BIT_POSITION),
+            (self.requestor_id.is_some(), validation::REQUESTOR_ID),
+            (self.responder_id.is_some(), validation::RESPONDER_ID),
+            (self.target_id.is_some(), validation::TARGET_ID),
+            (
+                self.memory_error_type.is_some(),
+                validation::MEMORY_ERROR_TYPE,
+            ),
+            (self.rank_number.is_some(), validation::RANK_NUMBER),
+            (self.card_handle.is_some(), validation::CARD_HANDLE),
+            (self.module_handle.is_some(), validation::MODULE_HANDLE),
+            (self.chip_id.is_some(), validation::CHIP_ID),
+        ]
+        .into_iter()
+        .filter(|(valid, _)| *valid)
+        .fold(0, |bits, (_, bit)| bits | bit)
+    }
+
+    /// Converts the section into a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bank = self.bank.unwrap_or_default().to_le_bytes();
+        if let Some(bank_address) = self.bank_address {
+            bank[0] = bank_address;
+        }
+        if let Some(bank_group) = self.bank_group {
+            bank[1] = bank_group;
+        }
+
+        let row = self.row.unwrap_or_default();
+        let extended = ((row >> 16) as u8 & 0x3) | (self.chip_id.unwrap_or_default() << 5);
+
+        let mut bytes = Vec::new();
+        for field in [
+            self.validation_bits(),
+            self.error_status.unwrap_or_default(),
+            self.physical_address.unwrap_or_default(),
+            self.physical_address_mask.unwrap_or_default(),
+        ] {
+            bytes.extend_from_slice(&field.to_le_bytes());
+        }
+        for field in [self.node, self.card, self.module] {
+            bytes.extend_from_slice(&field.unwrap_or_default().to_le_bytes());
+        }
+        bytes.extend_from_slice(&bank);
+        for field in [
+            self.device,
+            Some(row as u16),
+            self.column,
+            self.bit_position,
+        ] {
+            bytes.extend_from_slice(&field.unwrap_or_default().to_le_bytes());
+        }
+        for field in [self.requestor_id, self.responder_id, self.target_id] {
+            bytes.extend_from_slice(&field.unwrap_or_default().to_le_bytes());
+        }
+        bytes.push(self.memory_error_type.unwrap_or_default());
+        bytes.push(extended);
+        for field in [self.rank_number, self.card_handle, self.module_handle] {
+            bytes.extend_from_slice(&field.unwrap_or_default().to_le_bytes());
+        }
+
+        debug_assert_eq!(bytes.len(), MEMORY_ERROR_SIZE);
+        bytes
+    }
+
+    /// Returns the size of the section in bytes
+    pub fn len(&self) -> usize {
+        MEMORY_ERROR_SIZE
+    }
+}
+
+impl From<&MemoryErrorSection> for Node {
+    fn from(section: &MemoryErrorSection) -> Self {
+        let mut node = Node::section("memory_error");
+        for (name, value) in [
+            ("error_status", section.error_status),
+            ("physical_address", section.physical_address),
+            ("physical_address_mask", section.physical_address_mask),
+            ("node", section.node.map(u64::from)),
+            ("card", section.card.map(u64::from)),
+            ("module", section.module.map(u64::from)),
+            ("bank", section.bank.map(u64::from)),
+            ("bank_group", section.bank_group.map(u64::from)),
+            ("bank_address", section.bank_address.map(u64::from)),
+            ("device", section.device.map(u64::from)),
+            ("row", section.row.map(u64::from)),
+            ("column", section.column.map(u64::from)),
+            ("bit_position", section.bit_position.map(u64::from)),
+            ("requestor_id", section.requestor_id),
+            ("responder_id", section.responder_id),
+            ("target_id", section.target_id),
+            (
+                "memory_error_type",
+                section.memory_error_type.map(u64::from),
+            ),
+            ("rank_number", section.rank_number.map(u64::from)),
+            ("card_handle", section.card_handle.map(u64::from)),
+            ("module_handle", section.module_handle.map(u64::from)),
+            ("chip_id", section.chip_id.map(u64::from)),
+        ] {
+            if let Some(value) = value {
+                node.add(Node::field(name, value));
+            }
+        }
+        node
+    }
+}
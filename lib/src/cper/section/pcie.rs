@@ -0,0 +1,404 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::node::Node;
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+/// cbindgen:ignore
+pub const PCIE_ERROR_SIZE: usize = 208;
+/// cbindgen:ignore
+pub const DEVICE_ID_SIZE: usize = 16;
+/// cbindgen:ignore
+pub const CAPABILITY_SIZE: usize = 60;
+/// cbindgen:ignore
+pub const AER_INFO_SIZE: usize = 96;
+
+/// cbindgen:ignore
+mod validation {
+    pub const PORT_TYPE: u64 = 1 << 0;
+    pub const VERSION: u64 = 1 << 1;
+    pub const COMMAND_STATUS: u64 = 1 << 2;
+    pub const DEVICE_ID: u64 = 1 << 3;
+    pub const SERIAL_NUMBER: u64 = 1 << 4;
+    pub const BRIDGE_CONTROL_STATUS: u64 = 1 << 5;
+    pub const CAPABILITY: u64 = 1 << 6;
+    pub const AER_INFO: u64 = 1 << 7;
+}
+
+/// Names of the bits of the AER Uncorrectable Error Status register
+const UNCORRECTABLE_ERRORS: &[(usize, &str)] = &[
+    (4, "data_link_protocol_error"),
+    (5, "surprise_down_error"),
+    (12, "poisoned_tlp"),
+    (13, "flow_control_protocol_error"),
+    (14, "completion_timeout"),
+    (15, "completer_abort"),
+    (16, "unexpected_completion"),
+    (17, "receiver_overflow"),
+    (18, "malformed_tlp"),
+    (19, "ecrc_error"),
+    (20, "unsupported_request"),
+    (21, "acs_violation"),
+    (22, "uncorrectable_internal_error"),
+    (23, "mc_blocked_tlp"),
+    (24, "atomicop_egress_blocked"),
+    (25, "tlp_prefix_blocked"),
+    (26, "poisoned_tlp_egress_blocked"),
+];
+
+/// Names of the bits of the AER Correctable Error Status register
+const CORRECTABLE_ERRORS: &[(usize, &str)] = &[
+    (0, "receiver_error"),
+    (6, "bad_tlp"),
+    (7, "bad_dllp"),
+    (8, "replay_num_rollover"),
+    (12, "replay_timer_timeout"),
+    (13, "advisory_non_fatal_error"),
+    (14, "corrected_internal_error"),
+    (15, "header_log_overflow"),
+];
+
+/// Identification of the PCIe device that reported the error
+#[derive(Debug, Clone, Default)]
+pub struct PcieDeviceId {
+    pub vendor_id: u16,
+    pub device_id: u16,
+    pub class_code: u32,
+    pub function: u8,
+    pub device: u8,
+    pub segment: u16,
+    pub primary_bus: u8,
+    pub secondary_bus: u8,
+    pub slot: u16,
+}
+
+/// PCIe Advanced Error Reporting extended capability structure
+#[derive(Debug, Clone)]
+pub struct AerInfo(pub [u8; AER_INFO_SIZE]);
+
+/// UEFI 2.10 N.2.7 PCI Express Error Section
+///
+/// The fields are set to [`None`] when the corresponding validation bit is cleared.
+#[derive(Debug, Clone, Default)]
+pub struct PcieErrorSection {
+    pub port_type: Option<u32>,
+    pub version: Option<u32>,
+    /// Command (bits 0 to 15) and Status (bits 16 to 31) registers
+    pub command_status: Option<u32>,
+    pub device_id: Option<PcieDeviceId>,
+    pub device_serial_number: Option<u64>,
+    /// Secondary Status (bits 0 to 15) and Bridge Control (bits 16 to 31) registers
+    pub bridge_control_status: Option<u32>,
+    pub capability: Option<[u8; CAPABILITY_SIZE]>,
+    pub aer_info: Option<AerInfo>,
+}
+
+fn read_u16(s: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([s[offset], s[offset + 1]])
+}
+
+fn read_u32(s: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([s[offset], s[offset + 1], s[offset + 2], s[offset + 3]])
+}
+
+fn read_u64(s: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&s[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+impl PcieDeviceId {
+    fn from_slice(s: &[u8]) -> Self {
+        Self {
+            vendor_id: read_u16(s, 0),
+            device_id: read_u16(s, 2),
+            class_code: read_u32(s, 4) & 0xffffff,
+            function: s[7],
+            device: s[8],
+            segment: read_u16(s, 9),
+            primary_bus: s[11],
+            secondary_bus: s[12],
+            slot: read_u16(s, 13) >> 3,
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.vendor_id.to_le_bytes());
+        bytes.extend_from_slice(&self.device_id.to_le_bytes());
+        bytes.extend_from_slice(&self.class_code.to_le_bytes()[..3]);
+        bytes.push(self.function);
+        bytes.push(self.device);
+        bytes.extend_from_slice(&self.segment.to_le_bytes());
+        bytes.push(self.primary_bus);
+        bytes.push(self.secondary_bus);
+        bytes.extend_from_slice(&(self.slot << 3).to_le_bytes());
+        bytes.push(0);
+
+        debug_assert_eq!(bytes.len(), DEVICE_ID_SIZE);
+        bytes
+    }
+}
+
+impl AerInfo {
+    fn register(&self, offset: usize) -> u32 {
+        read_u32(&self.0, offset)
+    }
+
+    pub fn uncorrectable_error_status(&self) -> u32 {
+        self.register(4)
+    }
+
+    pub fn uncorrectable_error_mask(&self) -> u32 {
+        self.register(8)
+    }
+
+    pub fn uncorrectable_error_severity(&self) -> u32 {
+        self.register(12)
+    }
+
+    pub fn correctable_error_status(&self) -> u32 {
+        self.register(16)
+    }
+
+    pub fn correctable_error_mask(&self) -> u32 {
+        self.register(20)
+    }
+
+    pub fn capabilities_and_control(&self) -> u32 {
+        self.register(24)
+    }
+
+    /// Returns the header of the TLP associated with the first reported error.
+    pub fn header_log(&self) -> [u32; 4] {
+        [
+            self.register(28),
+            self.register(32),
+            self.register(36),
+            self.register(40),
+        ]
+    }
+
+    pub fn root_error_command(&self) -> u32 {
+        self.register(44)
+    }
+
+    pub fn root_error_status(&self) -> u32 {
+        self.register(48)
+    }
+
+    pub fn error_source_id(&self) -> u32 {
+        self.register(52)
+    }
+}
+
+impl PcieErrorSection {
+    /// Parses the section from a slice.
+    pub fn from_slice(s: &[u8]) -> Option<Self> {
+        let s = s.get(..PCIE_ERROR_SIZE)?;
+        let validation_bits = read_u64(s, 0);
+        let valid = |bit: u64| validation_bits & bit != 0;
+
+        Some(Self {
+            port_type: valid(validation::PORT_TYPE).then(|| read_u32(s, 8)),
+            version: valid(validation::VERSION).then(|| read_u32(s, 12)),
+            command_status: valid(validation::COMMAND_STATUS).then(|| read_u32(s, 16)),
+            device_id: valid(validation::DEVICE_ID).then(|| PcieDeviceId::from_slice(&s[24..40])),
+            device_serial_number: valid(validation::SERIAL_NUMBER).then(|| read_u64(s, 40)),
+            bridge_control_status: valid(validation::BRIDGE_CONTROL_STATUS)
+                .then(|| read_u32(s, 48)),
+            capability: if valid(validation::CAPABILITY) {
+                let mut capability = [0; CAPABILITY_SIZE];
+                capability.copy_from_slice(&s[52..112]);
+                Some(capability)
+            } else {
+                None
+            },
+            aer_info: if valid(validation::AER_INFO) {
+                let mut aer_info = [0; AER_INFO_SIZE];
+                aer_info.copy_from_slice(&s[112..208]);
+                Some(AerInfo(aer_info))
+            } else {
+                None
+            },
+        })
+    }
+
+    /// Returns the validation bits matching the fields that are set.
+    pub fn validation_bits(&self) -> u64 {
+        [
+            (self.port_type.is_some(), validation::PORT_TYPE),
+            (self.version.is_some(), validation::VERSION),
+            (self.command_status.is_some(), validation::COMMAND_STATUS),
+            (self.device_id.is_some(), validation::DEVICE_ID),
+            (
+                self.device_serial_number.is_some(),
+                validation::SERIAL_NUMBER,
+            ),
+            (
+                self.bridge_control_status.is_some(),
+                validation::BRIDGE_CONTROL_STATUS,
+            ),
+            (self.capability.is_some(), validation::CAPABILITY),
+            (self.aer_info.is_some(), validation::AER_INFO),
+        ]
+        .into_iter()
+        .filter(|(valid, _)| *valid)
+        .fold(0, |bits, (_, bit)| bits | bit)
+    }
+
+    /// Converts the section into a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&self.validation_bits().to_le_bytes());
+        for field in [self.port_type, self.version, self.command_status, Some(0)] {
+            bytes.extend_from_slice(&field.unwrap_or_default().to_le_bytes());
+        }
+        match &self.device_id {
+            Some(device_id) => bytes.append(&mut device_id.to_bytes()),
+            None => bytes.extend_from_slice(&[0; DEVICE_ID_SIZE]),
+        }
+        bytes.extend_from_slice(&self.device_serial_number.unwrap_or_default().to_le_bytes());
+        bytes.extend_from_slice(&self.bridge_control_status.unwrap_or_default().to_le_bytes());
+        bytes.extend_from_slice(&self.capability.unwrap_or([0; CAPABILITY_SIZE]));
+        match &self.aer_info {
+            Some(aer_info) => bytes.extend_from_slice(&aer_info.0),
+            None => bytes.extend_from_slice(&[0; AER_INFO_SIZE]),
+        }
+
+        debug_assert_eq!(bytes.len(), PCIE_ERROR_SIZE);
+        bytes
+    }
+
+    /// Returns the size of the section in bytes
+    pub fn len(&self) -> usize {
+        PCIE_ERROR_SIZE
+    }
+}
+
+/// Returns a field node whose children store the value of the named bits of the register.
+fn register_node(name: &str, value: u32, bits: &[(usize, &str)]) -> Node {
+    let mut node = Node::field(name, value as u64);
+    for (bit, bit_name) in bits {
+        node.add(Node::field(bit_name, (value as u64 >> bit) & 1));
+    }
+    node
+}
+
+impl From<&PcieDeviceId> for Node {
+    fn from(device_id: &PcieDeviceId) -> Self {
+        let mut node = Node::section("device_id");
+        node.add(Node::field("vendor_id", device_id.vendor_id as u64));
+        node.add(Node::field("device_id", device_id.device_id as u64));
+        node.add(Node::field("class_code", device_id.class_code as u64));
+        node.add(Node::field("function", device_id.function as u64));
+        node.add(Node::field("device", device_id.device as u64));
+        node.add(Node::field("segment", device_id.segment as u64));
+        node.add(Node::field("primary_bus", device_id.primary_bus as u64));
+        node.add(Node::field("secondary_bus", device_id.secondary_bus as u64));
+        node.add(Node::field("slot", device_id.slot as u64));
+        node
+    }
+}
+
+impl From<&AerInfo> for Node {
+    fn from(aer_info: &AerInfo) -> Self {
+        let mut node = Node::section("aer");
+        node.add(register_node(
+            "uncorrectable_error_status",
+            aer_info.uncorrectable_error_status(),
+            UNCORRECTABLE_ERRORS,
+        ));
+        node.add(register_node(
+            "uncorrectable_error_mask",
+            aer_info.uncorrectable_error_mask(),
+            UNCORRECTABLE_ERRORS,
+        ));
+        node.add(register_node(
+            "uncorrectable_error_severity",
+            aer_info.uncorrectable_error_severity(),
+            UNCORRECTABLE_ERRORS,
+        ));
+        node.add(register_node(
+            "correctable_error_status",
+            aer_info.correctable_error_status(),
+            CORRECTABLE_ERRORS,
+        ));
+        node.add(register_node(
+            "correctable_error_mask",
+            aer_info.correctable_error_mask(),
+            CORRECTABLE_ERRORS,
+        ));
+
+        let control = aer_info.capabilities_and_control();
+        let mut control_node = Node::field("capabilities_and_control", control as u64);
+        control_node.add(Node::field("first_error_pointer", control as u64 & 0x1f));
+        node.add(control_node);
+
+        let mut header_log = Node::section("header_log");
+        for (i, dword) in aer_info.header_log().into_iter().enumerate() {
+            header_log.add(Node::field(&format!("dw{i}"), dword as u64));
+        }
+        node.add(header_log);
+
+        node.add(Node::field(
+            "root_error_command",
+            aer_info.root_error_command() as u64,
+        ));
+        node.add(Node::field(
+            "root_error_status",
+            aer_info.root_error_status() as u64,
+        ));
+        node.add(Node::field(
+            "error_source_id",
+            aer_info.error_source_id() as u64,
+        ));
+        node
+    }
+}
+
+impl From<&PcieErrorSection> for Node {
+    fn from(section: &PcieErrorSection) -> Self {
+        let mut node = Node::section("pcie_error");
+        if let Some(port_type) = section.port_type {
+            node.add(Node::field("port_type", port_type as u64));
+        }
+        if let Some(version) = section.version {
+            let mut version_node = Node::field("version", version as u64);
+            version_node.add(Node::field("minor", version as u64 & 0xff));
+            version_node.add(Node::field("major", (version as u64 >> 8) & 0xff));
+            node.add(version_node);
+        }
+        if let Some(command_status) = section.command_status {
+            let mut command_status_node = Node::field("command_status", command_status as u64);
+            command_status_node.add(Node::field("command", command_status as u64 & 0xffff));
+            command_status_node.add(Node::field("status", command_status as u64 >> 16));
+            node.add(command_status_node);
+        }
+        if let Some(device_id) = &section.device_id {
+            node.add(Node::from(device_id));
+        }
+        if let Some(serial_number) = section.device_serial_number {
+            node.add(Node::field("device_serial_number", serial_number));
+        }
+        if let Some(bridge_control_status) = section.bridge_control_status {
+            let mut bridge_node =
+                Node::field("bridge_control_status", bridge_control_status as u64);
+            bridge_node.add(Node::field(
+                "secondary_status",
+                bridge_control_status as u64 & 0xffff,
+            ));
+            bridge_node.add(Node::field("control", bridge_control_status as u64 >> 16));
+            node.add(bridge_node);
+        }
+        if let Some(capability) = section.capability {
+            node.add(Node::bytes("capability", &capability));
+        }
+        if let Some(aer_info) = &section.aer_info {
+            node.add(Node::from(aer_info));
+        }
+        node
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::node::Node;
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+/// cbindgen:ignore
+pub const PROCESSOR_GENERIC_ERROR_SIZE: usize = 192;
+
+/// cbindgen:ignore
+mod validation {
+    pub const PROCESSOR_TYPE: u64 = 1 << 0;
+    pub const PROCESSOR_ISA: u64 = 1 << 1;
+    pub const ERROR_TYPE: u64 = 1 << 2;
+    pub const OPERATION: u64 = 1 << 3;
+    pub const FLAGS: u64 = 1 << 4;
+    pub const LEVEL: u64 = 1 << 5;
+    pub const CPU_VERSION: u64 = 1 << 6;
+    pub const CPU_BRAND_STRING: u64 = 1 << 7;
+    pub const PROCESSOR_ID: u64 = 1 << 8;
+    pub const TARGET_ADDRESS: u64 = 1 << 9;
+    pub const REQUESTOR_ID: u64 = 1 << 10;
+    pub const RESPONDER_ID: u64 = 1 << 11;
+    pub const INSTRUCTION_IP: u64 = 1 << 12;
+    /// Validation bits defined by the specification
+    pub const ALL: u64 = (1 << 13) - 1;
+}
+
+/// UEFI 2.10 N.2.4.1 Processor Generic Error Section
+///
+/// The fields are set to [`None`] when the corresponding validation bit is cleared.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessorGenericErrorSection {
+    /// Raw content of the parsed section. The bytes of the fields set to [`None`], the reserved
+    /// bytes and the reserved validation bits are serialized from it, so a parsed section is
+    /// serialized back to the same bytes. Empty for the sections built from scratch.
+    pub raw: Vec<u8>,
+    pub processor_type: Option<u8>,
+    pub processor_isa: Option<u8>,
+    pub error_type: Option<u8>,
+    pub operation: Option<u8>,
+    pub flags: Option<u8>,
+    pub level: Option<u8>,
+    pub cpu_version: Option<u64>,
+    pub cpu_brand_string: Option<[u8; 128]>,
+    pub processor_id: Option<u64>,
+    pub target_address: Option<u64>,
+    pub requestor_id: Option<u64>,
+    pub responder_id: Option<u64>,
+    pub instruction_ip: Option<u64>,
+}
+
+fn read_u64(s: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&s[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+impl ProcessorGenericErrorSection {
+    /// Parses the section from a slice.
+    pub fn from_slice(s: &[u8]) -> Option<Self> {
+        let s = s.get(..PROCESSOR_GENERIC_ERROR_SIZE)?;
+        let validation_bits = read_u64(s, 0);
+        let valid = |bit: u64| validation_bits & bit != 0;
+
+        Some(Self {
+            raw: s.to_vec(),
+            processor_type: valid(validation::PROCESSOR_TYPE).then_some(s[8]),
+            processor_isa: valid(validation::PROCESSOR_ISA).then_some(s[9]),
+            error_type: valid(validation::ERROR_TYPE).then_some(s[10]),
+            operation: valid(validation::OPERATION).then_some(s[11]),
+            flags: valid(validation::FLAGS).then_some(s[12]),
+            level: valid(validation::LEVEL).then_some(s[13]),
+            cpu_version: valid(validation::CPU_VERSION).then(|| read_u64(s, 16)),
+            cpu_brand_string: if valid(validation::CPU_BRAND_STRING) {
+                let mut brand_string = [0; 128];
+                brand_string.copy_from_slice(&s[24..152]);
+                Some(brand_string)
+            } else {
+                None
+            },
+            processor_id: valid(validation::PROCESSOR_ID).then(|| read_u64(s, 152)),
+            target_address: valid(validation::TARGET_ADDRESS).then(|| read_u64(s, 160)),
+            requestor_id: valid(validation::REQUESTOR_ID).then(|| read_u64(s, 168)),
+            responder_id: valid(validation::RESPONDER_ID).then(|| read_u64(s, 176)),
+            instruction_ip: valid(validation::INSTRUCTION_IP).then(|| read_u64(s, 184)),
+        })
+    }
+
+    /// Returns the validation bits matching the fields that are set.
+    pub fn validation_bits(&self) -> u64 {
+        [
+            (self.processor_type.is_some(), validation::PROCESSOR_TYPE),
+            (self.processor_isa.is_some(), validation::PROCESSOR_ISA),
+            (self.error_type.is_some(), validation::ERROR_TYPE),
+            (self.operation.is_some(), validation::OPERATION),
+            (self.flags.is_some(), validation::FLAGS),
+            (self.level.is_some(), validation::LEVEL),
+            (self.cpu_version.is_some(), validation::CPU_VERSION),
+            (
+                self.cpu_brand_string.is_some(),
+                validation::CPU_BRAND_STRING,
+            ),
+            (self.processor_id.is_some(), validation::PROCESSOR_ID),
+            (self.target_address.is_some(), validation::TARGET_ADDRESS),
+            (self.requestor_id.is_some(), validation::REQUESTOR_ID),
+            (self.responder_id.is_some(), validation::RESPONDER_ID),
+            (self.instruction_ip.is_some(), validation::INSTRUCTION_IP),
+        ]
+        .into_iter()
+        .filter(|(valid, _)| *valid)
+        .fold(0, |bits, (_, bit)| bits | bit)
+    }
+
+    /// Converts the section into a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = match self.raw.len() {
+            PROCESSOR_GENERIC_ERROR_SIZE => self.raw.clone(),
+            _ => vec![0; PROCESSOR_GENERIC_ERROR_SIZE],
+        };
+        let reserved_bits = read_u64(&bytes, 0) & !validation::ALL;
+        let mut write = |offset: usize, value: &[u8]| {
+            bytes[offset..offset + value.len()].copy_from_slice(value);
+        };
+
+        write(0, &(self.validation_bits() | reserved_bits).to_le_bytes());
+        for (offset, field) in [
+            (8, self.processor_type),
+            (9, self.processor_isa),
+            (10, self.error_type),
+            (11, self.operation),
+            (12, self.flags),
+            (13, self.level),
+        ] {
+            if let Some(value) = field {
+                write(offset, &[value]);
+            }
+        }
+        if let Some(brand_string) = &self.cpu_brand_string {
+            write(24, brand_string);
+        }
+        for (offset, field) in [
+            (16, self.cpu_version),
+            (152, self.processor_id),
+            (160, self.target_address),
+            (168, self.requestor_id),
+            (176, self.responder_id),
+            (184, self.instruction_ip),
+        ] {
+            if let Some(value) = field {
+                write(offset, &value.to_le_bytes());
+            }
+        }
+
+        bytes
+    }
+
+    /// Returns the size of the section in bytes
+    pub fn len(&self) -> usize {
+        PROCESSOR_GENERIC_ERROR_SIZE
+    }
+}
+
+impl From<&ProcessorGenericErrorSection> for Node {
+    fn from(section: &ProcessorGenericErrorSection) -> Self {
+        let mut node = Node::section("processor_generic_error");
+        for (name, value) in [
+            ("processor_type", section.processor_type),
+            ("processor_isa", section.processor_isa),
+            ("error_type", section.error_type),
+            ("operation", section.operation),
+            ("flags", section.flags),
+            ("level", section.level),
+        ] {
+            if let Some(value) = value {
+                node.add(Node::field(name, value as u64));
+            }
+        }
+        if let Some(cpu_version) = section.cpu_version {
+            node.add(Node::field("cpu_version", cpu_version));
+        }
+        if let Some(brand_string) = &section.cpu_brand_string {
+            let end = brand_string
+                .iter()
+                .position(|&b| b == 0)
+                .unwrap_or(brand_string.len());
+            node.add(Node::string(
+                "cpu_brand_string",
+                &String::from_utf8_lossy(&brand_string[..end]),
+            ));
+        }
+        for (name, value) in [
+            ("processor_id", section.processor_id),
+            ("target_address", section.target_address),
+            ("requestor_id", section.requestor_id),
+            ("responder_id", section.responder_id),
+            ("instruction_ip", section.instruction_ip),
+        ] {
+            if let Some(value) = value {
+                node.add(Node::field(name, value));
+            }
+        }
+        node
+    }
+}
@@ -1,9 +1,17 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
-use super::Cper;
-use super::section::CperSectionBody;
+use super::header::ErrorSeverity;
+use super::section::ia32x64::{
+    self, ContextInfo, ErrorInfo, Ia32X64ProcessorErrorSection, register_context_types,
+};
+use super::section::memory::MemoryErrorSection;
+use super::section::pcie::{AerInfo, PcieDeviceId, PcieErrorSection};
+use super::section::processor::ProcessorGenericErrorSection;
+use super::section::{CperSection, CperSectionBody, guids};
+use super::{Cper, CperBuilder};
 use crate::CrashLog;
+use crate::node::Node;
 
 pub const FW_ERROR_RECORD_GUID: uguid::Guid = uguid::guid!("81212a96-09ed-4996-9471-8d729c8e69ed");
 
@@ -23,7 +31,7 @@ fn from_slice() {
 #[test]
 fn cl_from_cper() {
     let cper = Cper::from_slice(&std::fs::read("tests/samples/cper.whea").unwrap()).unwrap();
-    let crashlog = CrashLog::from_cper(cper);
+    let crashlog = CrashLog::from_cper(cper, false);
     assert!(crashlog.is_ok());
     let crashlog = crashlog.unwrap();
 
@@ -61,3 +69,308 @@ fn cl_to_cper() {
     };
     assert_eq!(fer.payload, data);
 }
+
+#[test]
+fn find_all() {
+    let cper = std::fs::read("tests/samples/cper.whea").unwrap();
+    let report = [b"PAGEDUMPCPER".as_slice(), &cper, &[0; 7], &cper, b"CPER"].concat();
+
+    let cpers = Cper::find_all(&report);
+    assert_eq!(cpers.len(), 2);
+    assert_eq!(cpers[1].sections.len(), 5);
+}
+
+#[test]
+fn truncated_sections() {
+    let mut cper = Cper::default();
+    for guid in [
+        guids::FW_ERROR_RECORD,
+        guids::PROCESSOR_GENERIC,
+        guids::IA32X64_PROCESSOR,
+        guids::MEMORY_ERROR,
+        guids::PCIE_ERROR,
+    ] {
+        cper.append_section(CperSection::from_body(CperSectionBody::Unknown(
+            guid,
+            vec![0x5a; 12],
+        )));
+    }
+    let bytes = cper.to_bytes();
+
+    // The sections that cannot be parsed are kept as is.
+    let cper = Cper::from_slice(&bytes).unwrap();
+    assert_eq!(cper.sections.len(), 5);
+    for section in cper.sections.iter() {
+        let CperSectionBody::Unknown(guid, ref data) = section.body else {
+            panic!("Truncated section has been parsed");
+        };
+        assert_eq!(guid, section.descriptor.section_type);
+        assert_eq!(data, &[0x5a; 12]);
+    }
+    assert_eq!(cper.to_bytes(), bytes);
+}
+
+#[test]
+fn processor_generic_error() {
+    let mut brand_string = [0; 128];
+    brand_string[..5].copy_from_slice(b"Intel");
+    let section = ProcessorGenericErrorSection {
+        processor_type: Some(0),
+        error_type: Some(0x2),
+        cpu_brand_string: Some(brand_string),
+        instruction_ip: Some(0xfffff80012345678),
+        ..ProcessorGenericErrorSection::default()
+    };
+
+    let mut cper = Cper::default();
+    cper.append_section(CperSection::from_body(
+        CperSectionBody::ProcessorGenericError(section),
+    ));
+    let cper = Cper::from_slice(&cper.to_bytes()).unwrap();
+
+    let CperSectionBody::ProcessorGenericError(ref section) = cper.sections[0].body else {
+        panic!("Section is not a Processor Generic Error Section");
+    };
+    assert_eq!(section.validation_bits(), 0x1085);
+    assert_eq!(section.processor_type, Some(0));
+    assert_eq!(section.processor_isa, None);
+    assert_eq!(section.error_type, Some(0x2));
+    assert_eq!(section.cpu_brand_string, Some(brand_string));
+    assert_eq!(section.instruction_ip, Some(0xfffff80012345678));
+}
+
+#[test]
+fn processor_generic_error_round_trip() {
+    let mut bytes: Vec<u8> = (0..192).map(|i| i as u8).collect();
+    // Only the processor type, the error type and the instruction IP are valid.
+    bytes[..8].copy_from_slice(&(0x1005u64 | 1 << 40).to_le_bytes());
+
+    let section = ProcessorGenericErrorSection::from_slice(&bytes).unwrap();
+    assert_eq!(section.processor_isa, None);
+    assert_eq!(section.cpu_version, None);
+    assert_eq!(section.to_bytes(), bytes);
+
+    let mut cper = Cper::default();
+    cper.append_section(CperSection::from_body(
+        CperSectionBody::ProcessorGenericError(section),
+    ));
+    let cper = Cper::from_slice(&cper.to_bytes()).unwrap();
+    assert_eq!(cper.sections[0].body.to_bytes(), bytes);
+}
+
+#[test]
+fn memory_error() {
+    let section = MemoryErrorSection {
+        physical_address: Some(0x1234_5000),
+        bank_group: Some(0x2),
+        bank_address: Some(0x1),
+        row: Some(0x2abcd),
+        chip_id: Some(0x5),
+        memory_error_type: Some(0xe),
+        ..MemoryErrorSection::default()
+    };
+
+    let bytes = section.to_bytes();
+    assert_eq!(bytes.len(), 80);
+    assert_eq!(u16::from_le_bytes([bytes[38], bytes[39]]), 0x0201);
+    assert_eq!(bytes[73], 0xa2);
+
+    let mut cper = Cper::default();
+    cper.append_section(CperSection::from_body(CperSectionBody::MemoryError(
+        section,
+    )));
+    let cper = Cper::from_slice(&cper.to_bytes()).unwrap();
+
+    let CperSectionBody::MemoryError(ref section) = cper.sections[0].body else {
+        panic!("Section is not a Memory Error Section");
+    };
+    assert_eq!(section.validation_bits(), 0x3c4102);
+    assert_eq!(section.physical_address, Some(0x1234_5000));
+    assert_eq!(section.bank, None);
+    assert_eq!(section.bank_group, Some(0x2));
+    assert_eq!(section.bank_address, Some(0x1));
+    assert_eq!(section.row, Some(0x2abcd));
+    assert_eq!(section.chip_id, Some(0x5));
+    assert_eq!(section.memory_error_type, Some(0xe));
+}
+
+#[test]
+fn pcie_error() {
+    let mut aer_info = [0; 96];
+    aer_info[4..8].copy_from_slice(&(1u32 << 14).to_le_bytes());
+    aer_info[16..20].copy_from_slice(&1u32.to_le_bytes());
+    let section = PcieErrorSection {
+        port_type: Some(4),
+        device_id: Some(PcieDeviceId {
+            vendor_id: 0x8086,
+            device_id: 0x1234,
+            class_code: 0x060400,
+            device: 0x1c,
+            slot: 0x12,
+            ..PcieDeviceId::default()
+        }),
+        aer_info: Some(AerInfo(aer_info)),
+        ..PcieErrorSection::default()
+    };
+
+    let mut cper = Cper::default();
+    cper.append_section(CperSection::from_body(CperSectionBody::PcieError(section)));
+    let cper = Cper::from_slice(&cper.to_bytes()).unwrap();
+
+    let CperSectionBody::PcieError(ref section) = cper.sections[0].body else {
+        panic!("Section is not a PCIe Error Section");
+    };
+    assert_eq!(section.validation_bits(), 0x89);
+    assert_eq!(section.port_type, Some(4));
+    assert_eq!(section.version, None);
+
+    let node = Node::from(section);
+    assert_eq!(node.get_value_by_path("device_id.vendor_id"), Some(0x8086));
+    assert_eq!(
+        node.get_value_by_path("device_id.class_code"),
+        Some(0x060400)
+    );
+    assert_eq!(node.get_value_by_path("device_id.slot"), Some(0x12));
+    assert_eq!(
+        node.get_value_by_path("aer.uncorrectable_error_status.completion_timeout"),
+        Some(1)
+    );
+    assert_eq!(
+        node.get_value_by_path("aer.uncorrectable_error_status.malformed_tlp"),
+        Some(0)
+    );
+    assert_eq!(
+        node.get_value_by_path("aer.correctable_error_status.receiver_error"),
+        Some(1)
+    );
+}
+
+#[test]
+fn ia32x64_processor_error() {
+    // IA32_MC1_CTL to IA32_MC2_STATUS
+    let msrs: [u64; 6] = [0xff, 0xbe00_0000_0001_0005, 0x1234_5000, 0x86, 0x0, 0x0];
+    let section = Ia32X64ProcessorErrorSection {
+        local_apic_id: Some(0x10),
+        error_info: vec![ErrorInfo {
+            error_type: ia32x64::guids::CACHE_CHECK,
+            check_info: Some(0x20_0003),
+            instruction_pointer: Some(0xfffff80012345678),
+            ..ErrorInfo::default()
+        }],
+        context_info: vec![ContextInfo {
+            register_context_type: register_context_types::MSR,
+            msr_address: 0x404,
+            register_array: msrs.iter().flat_map(|msr| msr.to_le_bytes()).collect(),
+            ..ContextInfo::default()
+        }],
+        ..Ia32X64ProcessorErrorSection::default()
+    };
+    assert_eq!(section.len(), 64 + 64 + 16 + 48);
+
+    let mut cper = Cper::default();
+    cper.append_section(CperSection::from_body(
+        CperSectionBody::Ia32X64ProcessorError(section),
+    ));
+    let cper = Cper::from_slice(&cper.to_bytes()).unwrap();
+
+    let CperSectionBody::Ia32X64ProcessorError(ref section) = cper.sections[0].body else {
+        panic!("Section is not an IA32/X64 Processor Error Section");
+    };
+    assert_eq!(section.validation_bits(), 0x105);
+    assert_eq!(section.local_apic_id, Some(0x10));
+    assert_eq!(section.cpuid_info, None);
+    assert_eq!(section.error_info.len(), 1);
+    assert_eq!(section.error_info[0].validation_bits(), 0x11);
+    assert_eq!(section.error_info[0].target_id, None);
+    assert_eq!(section.context_info.len(), 1);
+    assert_eq!(section.context_info[0].msrs().count(), 6);
+
+    let node = Node::from(section);
+    assert_eq!(node.get_value_by_path("local_apic_id"), Some(0x10));
+    assert_eq!(
+        node.get_by_path("error_info0.type").map(|node| &node.kind),
+        Some(&crate::node::NodeType::String {
+            value: "cache_check".into()
+        })
+    );
+    assert_eq!(
+        node.get_value_by_path("error_info0.check_info"),
+        Some(0x20_0003)
+    );
+    assert_eq!(
+        node.get_value_by_path("context_info0.mca.bank1.status"),
+        Some(0xbe00_0000_0001_0005)
+    );
+    assert_eq!(
+        node.get_value_by_path("context_info0.mca.bank1.addr"),
+        Some(0x1234_5000)
+    );
+    assert_eq!(
+        node.get_value_by_path("context_info0.mca.bank2.ctl"),
+        Some(0x0)
+    );
+}
+
+#[test]
+fn builder() {
+    let data = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let platform_id = uguid::guid!("01234567-89ab-cdef-0123-456789abcdef");
+
+    let cper = CperBuilder::new()
+        .severity(ErrorSeverity::Corrected)
+        .platform_id(platform_id)
+        .record_id(0x1234)
+        .flags(super::header::flags::RECOVERED)
+        .crashlog(&crashlog)
+        .build();
+    let cper = Cper::from_slice(&cper.to_bytes()).unwrap();
+
+    assert!(matches!(
+        cper.record_header.error_severity,
+        ErrorSeverity::Corrected
+    ));
+    assert_eq!(cper.record_header.platform_id, Some(platform_id));
+    assert_eq!(cper.record_header.partition_id, None);
+    assert_eq!(cper.record_header.record_id, 0x1234);
+    assert_eq!(cper.record_header.flags, 1);
+    assert_eq!(
+        cper.record_header.notification_type,
+        super::header::notification_types::BOOT
+    );
+    assert_eq!(cper.sections.len(), 1);
+    assert_eq!(cper.sections[0].descriptor.section_severity as u32, 2);
+}
+
+#[test]
+fn select_sections() {
+    let data = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let mut cper = CperBuilder::new()
+        .crashlog(&crashlog)
+        .section(CperSection::from_body(CperSectionBody::MemoryError(
+            MemoryErrorSection::default(),
+        )))
+        .section(CperSection::from_body(CperSectionBody::PcieError(
+            PcieErrorSection::default(),
+        )))
+        .build();
+    assert_eq!(cper.sections.len(), 3);
+    assert_eq!(cper.sections_by_guid(guids::PCIE_ERROR).count(), 1);
+
+    let selected = cper.select_sections(&[FW_ERROR_RECORD_GUID, guids::PCIE_ERROR]);
+    let selected = Cper::from_slice(&selected.to_bytes()).unwrap();
+    assert_eq!(selected.record_header.section_count, 2);
+    assert_eq!(selected.sections_by_guid(guids::MEMORY_ERROR).count(), 0);
+    assert_eq!(selected.sections_by_guid(guids::PCIE_ERROR).count(), 1);
+
+    let removed = cper.remove_sections(guids::MEMORY_ERROR);
+    assert_eq!(removed.len(), 1);
+    cper.retain_sections(|section| section.descriptor.section_type == FW_ERROR_RECORD_GUID);
+    let cper = Cper::from_slice(&cper.to_bytes()).unwrap();
+    assert_eq!(cper.record_header.section_count, 1);
+    let CperSectionBody::FirmwareErrorRecord(ref fer) = cper.sections[0].body else {
+        panic!("Section is not a FirmwareErrorRecord");
+    };
+    assert_eq!(fer.payload, data);
+}
@@ -1,18 +1,34 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+mod builder;
+
 use crate::Error;
 use crate::bert::{Berr, Bert};
 #[cfg(feature = "collateral_manager")]
 use crate::collateral::{CollateralManager, CollateralTree};
+#[cfg(feature = "compression")]
+use crate::compression;
 use crate::cper::{Cper, CperSectionBody};
 use crate::metadata::Metadata;
 use crate::node::Node;
+use crate::pmt::PmtDump;
+#[cfg(feature = "collateral_manager")]
+use crate::record::{DecodeReport, RecordReport, RegionGap};
+use crate::record::{Record, SortKey, SplitKey};
 use crate::region::Region;
 #[cfg(not(feature = "std"))]
-use alloc::{collections::VecDeque, vec, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    format, vec,
+    vec::Vec,
+};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 #[cfg(feature = "std")]
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+pub use builder::CrashLogBuilder;
 
 use crate::header::record_types;
 
@@ -26,7 +42,10 @@ pub struct CrashLog {
 }
 
 impl CrashLog {
-    pub(crate) fn from_regions(regions: Vec<Region>) -> Result<Self, Error> {
+    /// Builds a [CrashLog] from its regions and extracts the regions stored in the Box records.
+    /// The Box payloads are parsed in recovery mode if `recover` is set (see
+    /// [Region::from_slice_with_recovery]).
+    pub(crate) fn from_regions(regions: Vec<Region>, recover: bool) -> Result<Self, Error> {
         let mut queue = VecDeque::from(regions);
         let mut regions = Vec::new();
 
@@ -45,7 +64,7 @@ impl CrashLog {
                     continue;
                 };
 
-                match Region::from_slice(payload) {
+                match Region::parse(payload, recover) {
                     Ok(mut region) => {
                         region.set_child_context(&record.header);
                         queue.push_front(region)
@@ -68,33 +87,160 @@ impl CrashLog {
     }
 
     /// Extracts the Crash Log records from [Berr].
-    pub(crate) fn from_berr(berr: Berr) -> Result<Self, Error> {
+    pub(crate) fn from_berr(berr: Berr, recover: bool) -> Result<Self, Error> {
         let regions = berr
             .entries
             .iter()
-            .filter_map(|entry| Region::from_cper_section(&entry.cper_section))
+            .filter_map(|entry| Region::from_cper_section(&entry.cper_section, recover))
             .collect();
-        CrashLog::from_regions(regions)
+        CrashLog::from_regions(regions, recover)
     }
 
     #[cfg(any(all(target_os = "windows", feature = "extraction"), doc))]
     /// Searches for any Intel Crash Log logged in the Windows event logs.
     ///
     /// The Windows event logs typically captures the records reported through ACPI.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub fn from_windows_event_logs(path: Option<&std::path::Path>) -> Result<Vec<Self>, Error> {
         Self::from_event_logs(path).map_err(|err| {
             log::error!("Error while accessing windows event logs: {err}");
-            Error::InternalError
+            if err.code() == windows::Win32::Foundation::E_ACCESSDENIED {
+                Error::InsufficientPrivileges(
+                    "the Windows event logs".into(),
+                    "Administrator",
+                    "Run the command from an elevated prompt.",
+                )
+            } else {
+                Error::InternalError
+            }
         })
     }
 
+    /// Searches for the Crash Logs embedded in a Windows report file.
+    ///
+    /// The report files written by Windows under `LiveKernelReports/WHEA`, as well as the files
+    /// attached to the `.wer` reports, embed the CPER records reported by the platform. The Crash
+    /// Log records are extracted from each of these CPER records.
+    pub fn from_windows_report(s: &[u8]) -> Result<Vec<Self>, Error> {
+        let crashlogs = Self::from_embedded_cpers(s);
+
+        if crashlogs.is_empty() {
+            return Err(Error::NoCrashLogFound);
+        }
+
+        Ok(crashlogs)
+    }
+
+    /// Searches for the Crash Logs embedded in a Windows memory dump.
+    ///
+    /// The kernel memory dumps (`MEMORY.DMP`) and the minidumps keep a copy of the CPER records
+    /// reported through WHEA. The Crash Log records are extracted from each of these CPER records.
+    /// As the same CPER record can be stored several times in the memory, the duplicated Crash
+    /// Logs are only reported once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let cper = std::fs::read("tests/samples/cper.whea").unwrap();
+    /// let dump = [b"PAGEDU64".as_slice(), &[0; 0x1000], &cper, &[0; 0x100], &cper].concat();
+    ///
+    /// let crashlogs = CrashLog::from_windows_dump(&dump).unwrap();
+    /// assert_eq!(crashlogs.len(), 1);
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(size = s.len())))]
+    pub fn from_windows_dump(s: &[u8]) -> Result<Vec<Self>, Error> {
+        match s.get(0..8) {
+            Some(b"PAGEDU64") => log::debug!("Found 64-bit kernel memory dump"),
+            Some(b"PAGEDUMP") => log::debug!("Found 32-bit kernel memory dump"),
+            _ if s.starts_with(b"MDMP") => log::debug!("Found minidump"),
+            _ => log::warn!("Unknown memory dump signature"),
+        }
+
+        let mut regions: Vec<Vec<u8>> = Vec::new();
+        let crashlogs: Vec<Self> = Self::from_embedded_cpers(s)
+            .into_iter()
+            .filter(|crashlog| {
+                let bytes: Vec<u8> = crashlog
+                    .regions
+                    .iter()
+                    .flat_map(|region| region.to_bytes())
+                    .collect();
+                if regions.contains(&bytes) {
+                    log::debug!("Ignoring duplicated Crash Log");
+                    return false;
+                }
+                regions.push(bytes);
+                true
+            })
+            .collect();
+
+        if crashlogs.is_empty() {
+            return Err(Error::NoCrashLogFound);
+        }
+
+        Ok(crashlogs)
+    }
+
+    /// Searches for the Crash Log records stored at arbitrary offsets in a binary blob, such as a
+    /// memory dump or a flash image, and reconstructs the regions they belong to.
+    ///
+    /// The blob is scanned for valid record headers aligned on 32 bits. Only the records of a
+    /// known type that fit entirely in the blob are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let region = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let blob = [[0xff; 0x40].as_slice(), &region, &[0x0; 0x20]].concat();
+    ///
+    /// let crashlog = CrashLog::carve_from_slice(&blob).unwrap();
+    /// assert_eq!(crashlog.regions.len(), 1);
+    /// assert_eq!(crashlog.regions[0].to_bytes(), region);
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(size = s.len())))]
+    pub fn carve_from_slice(s: &[u8]) -> Result<Self, Error> {
+        let regions: Vec<Region> = Region::carve_from_slice(s)
+            .into_iter()
+            .map(|(offset, region)| {
+                log::info!(
+                    "Carved {} records at offset {offset:#x}",
+                    region.records.len()
+                );
+                region
+            })
+            .collect();
+
+        if regions.is_empty() {
+            return Err(Error::NoCrashLogFound);
+        }
+
+        CrashLog::from_regions(regions, false)
+    }
+
+    /// Extracts the Crash Log records from each of the CPER records embedded at arbitrary offsets
+    /// in a byte slice.
+    pub(crate) fn from_embedded_cpers(s: &[u8]) -> Vec<Self> {
+        Cper::find_all(s)
+            .into_iter()
+            .filter_map(|cper| {
+                CrashLog::from_cper(cper, false)
+                    .inspect_err(|err| log::debug!("Ignoring CPER record: {err}"))
+                    .ok()
+            })
+            .collect()
+    }
+
     /// Extracts the Crash Log records from [Cper] record.
-    pub(crate) fn from_cper(cper: Cper) -> Result<Self, Error> {
+    pub(crate) fn from_cper(cper: Cper, recover: bool) -> Result<Self, Error> {
         let mut regions: Vec<Region> = Vec::new();
         let mut extra_cper_sections: Vec<CperSectionBody> = Vec::new();
 
         for section in cper.sections {
-            if let Some(region) = Region::from_cper_section(&section.body) {
+            if let Some(region) = Region::from_cper_section(&section.body, recover) {
                 regions.push(region);
             } else {
                 extra_cper_sections.push(section.body);
@@ -105,21 +251,93 @@ impl CrashLog {
             return Err(Error::NoCrashLogFound);
         }
 
-        let mut crashlog = CrashLog::from_regions(regions)?;
+        let mut crashlog = CrashLog::from_regions(regions, recover)?;
         crashlog.metadata.extra_cper_sections = extra_cper_sections;
         Ok(crashlog)
     }
 
+    /// Extracts the Crash Log records from an offline dump of Intel PMT Crash Log regions.
+    pub(crate) fn from_pmt_dump(dump: PmtDump, recover: bool) -> Result<Self, Error> {
+        let (regions, endpoints): (Vec<Region>, Vec<_>) = dump
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                let region = Region::parse(&entry.region, recover)
+                    .inspect_err(|err| {
+                        log::error!("Invalid PMT region (guid={:#x}): {err}", entry.header.guid)
+                    })
+                    .ok()?;
+                Some((region, entry.header.endpoint(format!("crashlog{i}"))))
+            })
+            .unzip();
+
+        let mut crashlog = CrashLog::from_regions(regions, recover)?;
+        crashlog.metadata.pmt_endpoints = endpoints;
+        Ok(crashlog)
+    }
+
     /// Decodes a raw Crash Log binary.
+    ///
+    /// The binary can be a BERT file, a CPER record, an offline dump of Intel PMT Crash Log
+    /// regions prefixed by their discovery headers, or a single Crash Log region.
+    ///
+    /// When the `compression` feature is enabled, the binary can also be wrapped into a gzip or
+    /// zip archive. The first file of the archive that contains a Crash Log is decoded. Up to
+    /// [compression::MAX_ARCHIVE_NESTING] archives can be nested (e.g. a gzip file stored in a zip archive),
+    /// and the binaries that cannot be decompressed are decoded as is.
     pub fn from_slice(s: &[u8]) -> Result<Self, Error> {
+        CrashLog::parse(s, false, 0)
+    }
+
+    /// Decodes a raw Crash Log binary like [CrashLog::from_slice], but keeps parsing the regions
+    /// past the corrupted records. See [Region::from_slice_with_recovery].
+    ///
+    /// The bytes skipped in each region are listed in [crate::region::ParseMetrics::gaps] and in
+    /// the report returned by [CrashLog::decode_with_report].
+    pub fn from_slice_with_recovery(s: &[u8]) -> Result<Self, Error> {
+        CrashLog::parse(s, true, 0)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(size = s.len())))]
+    fn parse(s: &[u8], recover: bool, depth: usize) -> Result<Self, Error> {
+        #[cfg(feature = "compression")]
+        if compression::Format::detect(s).is_some() {
+            match CrashLog::from_compressed_slice(s, recover, depth) {
+                Ok(crashlog) => return Ok(crashlog),
+                Err(err) => log::debug!("Decoding the binary as uncompressed data: {err}"),
+            }
+        }
+        #[cfg(not(feature = "compression"))]
+        let _ = depth;
+
         if let Some(berr) = Berr::from_bert_file(s) {
-            CrashLog::from_berr(berr)
+            CrashLog::from_berr(berr, recover)
         } else if let Some(cper) = Cper::from_slice(s) {
-            CrashLog::from_cper(cper)
+            CrashLog::from_cper(cper, recover)
+        } else if let Some(dump) = PmtDump::from_slice(s) {
+            CrashLog::from_pmt_dump(dump, recover)
         } else {
             // Input file is a single Crash Log region
-            CrashLog::from_regions(vec![Region::from_slice(s)?])
+            CrashLog::from_regions(vec![Region::parse(s, recover)?], recover)
+        }
+    }
+
+    #[cfg(feature = "compression")]
+    fn from_compressed_slice(s: &[u8], recover: bool, depth: usize) -> Result<Self, Error> {
+        if depth >= compression::MAX_ARCHIVE_NESTING {
+            return Err(Error::InvalidArchive("too many nested archives".into()));
+        }
+
+        let mut result = Err(Error::NoCrashLogFound);
+        for file in compression::decompress(s)? {
+            result = CrashLog::parse(&file, recover, depth + 1);
+            match &result {
+                Ok(_) => break,
+                Err(err) => log::debug!("Skipping compressed file: {err}"),
+            }
         }
+        result
     }
 
     /// Exports the [CrashLog] as a BERT file.
@@ -142,6 +360,314 @@ impl CrashLog {
         Cper::from_raw_crashlog(self).to_bytes()
     }
 
+    /// Regenerates the record headers after the Crash Log records have been filtered or edited.
+    ///
+    /// The size fields of the record headers are updated to match the actual length of the
+    /// records, the invalid records are removed, and the empty regions are dropped. Each rebuilt
+    /// region is terminated by a termination marker. The checksums of the records are left
+    /// untouched, so the corrupted records remain detectable, unless
+    /// [CrashLog::update_checksums] is called. The resulting [CrashLog] can then be serialized
+    /// using [CrashLog::to_bytes] or [CrashLog::to_bert].
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidCrashLog] if no record remains after the rebuild.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let mut crashlog = CrashLog::from_slice(&data).unwrap();
+    ///
+    /// // Truncate the MCA record
+    /// crashlog.regions[0].records[0].data.truncate(0x40);
+    /// crashlog.rebuild().unwrap();
+    ///
+    /// let crashlog = CrashLog::from_slice(&crashlog.to_bytes()).unwrap();
+    /// assert_eq!(crashlog.regions[0].records[0].header.record_size(), 0x40);
+    /// ```
+    pub fn rebuild(&mut self) -> Result<(), Error> {
+        for region in self.regions.iter_mut() {
+            region.rebuild();
+        }
+
+        self.regions.retain(|region| !region.records.is_empty());
+
+        if self.regions.is_empty() {
+            return Err(Error::InvalidCrashLog);
+        }
+
+        Ok(())
+    }
+
+    /// Updates the checksums (CLDIC) of all the records that have one to match their content,
+    /// typically after the records have been edited and rebuilt (see [CrashLog::rebuild]).
+    ///
+    /// Returns the number of checksums updated.
+    pub fn update_checksums(&mut self) -> usize {
+        self.regions
+            .iter_mut()
+            .flat_map(|region| region.records.iter_mut())
+            .filter_map(|record| record.update_checksum().then_some(()))
+            .count()
+    }
+
+    /// Removes the duplicated records, such as the records of the same crash extracted from
+    /// several sources (e.g. BERT and Intel PMT) and merged into a single [CrashLog].
+    ///
+    /// Two records are duplicates if their raw content, header included, is identical. The first
+    /// occurrence of each record is kept, and the regions left empty are dropped. Returns the
+    /// number of records removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+    /// let mut crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let regions = crashlog.regions.len();
+    ///
+    /// // Merge the same Crash Log extracted twice
+    /// crashlog
+    ///     .regions
+    ///     .append(&mut CrashLog::from_slice(&data).unwrap().regions);
+    ///
+    /// assert!(crashlog.dedup() > 0);
+    /// assert_eq!(crashlog.regions.len(), regions);
+    /// ```
+    pub fn dedup(&mut self) -> usize {
+        let mut seen: BTreeSet<&[u8]> = BTreeSet::new();
+        let duplicates: Vec<Vec<bool>> = self
+            .regions
+            .iter()
+            .map(|region| {
+                region
+                    .records
+                    .iter()
+                    .map(|record| !seen.insert(record.data.as_slice()))
+                    .collect()
+            })
+            .collect();
+
+        let mut removed = 0;
+        for (region, duplicates) in self.regions.iter_mut().zip(duplicates) {
+            let mut duplicates = duplicates.into_iter();
+            region.records.retain(|record| {
+                let duplicate = duplicates.next().unwrap_or(false);
+                if duplicate {
+                    log::debug!("Removing duplicated record: {}", record.header);
+                    removed += 1;
+                }
+                !duplicate
+            });
+        }
+
+        self.regions.retain(|region| !region.records.is_empty());
+        removed
+    }
+
+    /// Builds a Crash Log made of a subset of the records. Each entry of `selection` identifies a
+    /// record (region index, record index) and the number of bytes of the record to keep.
+    fn subset(&self, selection: &[(usize, usize, usize)]) -> Option<CrashLog> {
+        let mut regions: Vec<(usize, Region)> = Vec::new();
+
+        for &(region_index, record_index, len) in selection {
+            let record = &self.regions[region_index].records[record_index];
+            let record = Record {
+                header: record.header.clone(),
+                data: record.data[..len.min(record.data.len())].to_vec(),
+                context: record.context.clone(),
+            };
+
+            match regions.last_mut() {
+                Some((index, region)) if *index == region_index => region.records.push(record),
+                _ => regions.push((
+                    region_index,
+                    Region {
+                        records: vec![record],
+                        ..Region::default()
+                    },
+                )),
+            }
+        }
+
+        let mut crashlog = CrashLog {
+            regions: regions.into_iter().map(|(_, region)| region).collect(),
+            metadata: self.metadata.clone(),
+        };
+        crashlog.rebuild().ok()?;
+        Some(crashlog)
+    }
+
+    /// Searches for the smallest subset of the Crash Log that still reproduces a given behavior.
+    ///
+    /// The `reproduces` predicate returns true if the behavior (e.g. a decode failure or a
+    /// triage finding) can be observed on the Crash Log passed as argument. The records that are
+    /// not required to reproduce the behavior are removed first, then the payload of each
+    /// remaining record is truncated to the shortest length that still reproduces the behavior.
+    /// The record headers are regenerated to match the truncated records.
+    ///
+    /// Returns `None` if the behavior cannot be reproduced on the original Crash Log.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    ///
+    /// let has_mca = |crashlog: &CrashLog| {
+    ///     crashlog
+    ///         .regions
+    ///         .iter()
+    ///         .flat_map(|region| region.records.iter())
+    ///         .any(|record| record.header.record_type().is_ok_and(|rt| rt == "MCA"))
+    /// };
+    /// let minimized = crashlog.minimize(has_mca).unwrap();
+    /// assert_eq!(minimized.regions.len(), 1);
+    /// assert_eq!(minimized.regions[0].records.len(), 1);
+    /// ```
+    pub fn minimize(&self, mut reproduces: impl FnMut(&CrashLog) -> bool) -> Option<CrashLog> {
+        if !reproduces(self) {
+            return None;
+        }
+
+        let mut selection: Vec<(usize, usize, usize)> = self
+            .regions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, region)| {
+                region
+                    .records
+                    .iter()
+                    .enumerate()
+                    .map(move |(j, record)| (i, j, record.data.len()))
+            })
+            .collect();
+
+        let mut reproduces_with = |selection: &[(usize, usize, usize)]| {
+            self.subset(selection)
+                .is_some_and(|crashlog| reproduces(&crashlog))
+        };
+
+        // Removes the records that are not needed to reproduce the behavior.
+        let mut i = 0;
+        while i < selection.len() && selection.len() > 1 {
+            let mut candidate = selection.clone();
+            candidate.remove(i);
+            if reproduces_with(&candidate) {
+                selection = candidate;
+            } else {
+                i += 1;
+            }
+        }
+
+        // Truncates the payload of the remaining records.
+        for i in 0..selection.len() {
+            let (region_index, record_index, len) = selection[i];
+            let header = &self.regions[region_index].records[record_index].header;
+            let granularity = header.record_size_granularity();
+
+            let mut low = header.header_size().div_ceil(granularity);
+            let mut high = len.div_ceil(granularity);
+            while low < high {
+                let middle = low + (high - low) / 2;
+                let mut candidate = selection.clone();
+                candidate[i].2 = middle * granularity;
+                if reproduces_with(&candidate) {
+                    high = middle;
+                } else {
+                    low = middle + 1;
+                }
+            }
+            selection[i].2 = (high * granularity).min(len);
+        }
+
+        self.subset(&selection)
+    }
+
+    /// Sorts the Crash Log records using one of the built-in [SortKey].
+    ///
+    /// The records are sorted within each region, then the regions are sorted according to their
+    /// first record. The records are never moved from one region to another, so the resulting
+    /// order is only global when each region is homogeneous for the given key. The sort is
+    /// stable: records sharing the same key keep their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::SortKey;
+    ///
+    /// let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+    /// let mut crashlog = CrashLog::from_slice(&data).unwrap();
+    ///
+    /// crashlog.sort_records_by(SortKey::RecordType);
+    /// let record_type = crashlog.regions[0].records[0].header.record_type().unwrap();
+    /// assert_eq!(record_type, "CRASHLOG_AGENT");
+    /// ```
+    pub fn sort_records_by(&mut self, key: SortKey) {
+        for region in self.regions.iter_mut() {
+            region.records.sort_by_key(|record| record.sort_key(key));
+        }
+
+        self.regions
+            .sort_by_key(|region| region.records.first().map(|record| record.sort_key(key)));
+    }
+
+    /// Splits the Crash Log into one [CrashLog] per socket or per die.
+    ///
+    /// The returned map is indexed by socket ID and die ID. The die ID is always `None` when the
+    /// records are grouped by socket, and for the records that don't have any die ID. The records
+    /// keep their original region layout and the metadata is copied into each [CrashLog]. The
+    /// regions stored in a Box record are grouped with the Box record, regardless of the location
+    /// of their own records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::SplitKey;
+    ///
+    /// let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    ///
+    /// let crashlogs = crashlog.split_by(SplitKey::Socket);
+    /// assert_eq!(crashlogs.len(), 1);
+    /// assert_eq!(crashlogs[&(0, None)].regions.len(), 2);
+    /// ```
+    pub fn split_by(self, key: SplitKey) -> BTreeMap<(u8, Option<u8>), CrashLog> {
+        let mut crashlogs: BTreeMap<(u8, Option<u8>), CrashLog> = BTreeMap::new();
+
+        for region in self.regions {
+            let mut regions: BTreeMap<(u8, Option<u8>), Region> = BTreeMap::new();
+            for record in region.records {
+                regions
+                    .entry(record.split_key(key))
+                    .or_default()
+                    .records
+                    .push(record);
+            }
+
+            for (id, region) in regions {
+                crashlogs
+                    .entry(id)
+                    .or_insert_with(|| CrashLog {
+                        metadata: self.metadata.clone(),
+                        ..CrashLog::default()
+                    })
+                    .regions
+                    .push(region);
+            }
+        }
+
+        crashlogs
+    }
+
     /// Returns the register tree representation of the Crash Log record headers.
     pub fn decode_without_cm(&self) -> Node {
         let mut root = Node::root();
@@ -154,14 +680,163 @@ impl CrashLog {
     }
 
     /// Returns the register tree representation of the Crash Log record content.
+    ///
+    /// Only the records selected by the [`crate::record::DecodeOptions`] of the collateral manager
+    /// are decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeOptions;
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// cm.set_decode_options(DecodeOptions {
+    ///     record_types: vec!["PCORE".into()],
+    ///     ..DecodeOptions::default()
+    /// });
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// assert!(crashlog.decode(&mut cm).get("mca").is_none());
+    /// ```
     #[cfg(feature = "collateral_manager")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn decode<T: CollateralTree>(&self, cm: &mut CollateralManager<T>) -> Node {
         let mut root = Node::root();
-        for region in self.regions.iter() {
-            for record in region.records.iter() {
-                root.merge(record.decode(cm))
+        for record in self.selected_records(cm) {
+            root.merge(record.decode(cm))
+        }
+        root
+    }
+
+    /// Returns the records selected by the decode options of the collateral manager, along with
+    /// the indices of their region and of the record in the region.
+    #[cfg(feature = "collateral_manager")]
+    fn selected_records_indexed<T: CollateralTree>(
+        &self,
+        cm: &CollateralManager<T>,
+    ) -> Vec<(usize, usize, &Record)> {
+        let options = cm.decode_options();
+        self.regions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, region)| {
+                region
+                    .records
+                    .iter()
+                    .enumerate()
+                    .map(move |(j, record)| (i, j, record))
+            })
+            .filter(|(_, _, record)| options.selects(record))
+            .collect()
+    }
+
+    #[cfg(feature = "collateral_manager")]
+    fn selected_records<T: CollateralTree>(&self, cm: &CollateralManager<T>) -> Vec<&Record> {
+        self.selected_records_indexed(cm)
+            .into_iter()
+            .map(|(_, _, record)| record)
+            .collect()
+    }
+
+    /// Returns the register tree representation of the Crash Log record content, along with the
+    /// issues found while decoding the records and the corrupted bytes skipped while parsing the
+    /// regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    ///
+    /// let (root, report) = crashlog.decode_with_report(&mut cm);
+    /// for record in report.records.iter() {
+    ///     for warning in record.warnings.iter() {
+    ///         println!("{}-{}: {warning}", record.region, record.record);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "collateral_manager")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn decode_with_report<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+    ) -> (Node, DecodeReport) {
+        let mut root = Node::root();
+        let mut report = DecodeReport::default();
+        for (i, region) in self.regions.iter().enumerate() {
+            report
+                .gaps
+                .extend(region.metrics.gaps.iter().map(|gap| RegionGap {
+                    region: i,
+                    offset: gap.start,
+                    size: gap.len(),
+                }));
+        }
+        for (i, j, record) in self.selected_records_indexed(cm) {
+            let (node, warnings) = record.decode_with_report(cm);
+            root.merge(node);
+            if !warnings.is_empty() {
+                report.records.push(RecordReport {
+                    region: i,
+                    record: j,
+                    header: record.header.clone(),
+                    warnings,
+                });
             }
         }
+        (root, report)
+    }
+
+    /// Returns the register tree representation of the Crash Log record content. The records are
+    /// decoded concurrently on the global [rayon] thread pool.
+    ///
+    /// The decode definitions are loaded from the collateral manager beforehand, and the records
+    /// are then decoded in parallel. The records handled by the decoders registered with
+    /// [`CollateralManager::register_decoder`], the records with a compressed payload, and the
+    /// post-decode hooks are processed serially.
+    /// The resulting tree is the same as the one returned by [`CrashLog::decode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    ///
+    /// assert_eq!(crashlog.decode_parallel(&mut cm), crashlog.decode(&mut cm));
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn decode_parallel<T: CollateralTree>(&self, cm: &mut CollateralManager<T>) -> Node {
+        let records = self.selected_records(cm);
+
+        // The collateral manager is not thread-safe.
+        let plans: Vec<_> = records
+            .iter()
+            .map(|record| record.plan_decode(cm))
+            .collect();
+
+        let options = cm.decode_options().clone();
+        let partials: Vec<_> = records
+            .par_iter()
+            .zip(plans)
+            .map(|(record, plan)| record.decode_planned(plan, &options))
+            .collect();
+
+        let mut root = Node::root();
+        for (record, partial) in records.into_iter().zip(partials) {
+            root.merge(match partial {
+                Some(partial) => record.complete_decode(cm, partial, &mut Vec::new()),
+                None => record.decode(cm),
+            })
+        }
         root
     }
 }
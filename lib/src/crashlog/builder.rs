@@ -0,0 +1,77 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::CrashLog;
+use crate::error::Error;
+use crate::metadata::Metadata;
+use crate::region::RegionBuilder;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Builds a [CrashLog] from a sequence of regions, to synthesize captures with specific sets of
+/// records.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::CrashLogBuilder;
+/// use intel_crashlog::prelude::*;
+/// use intel_crashlog::region::RegionBuilder;
+///
+/// let mca = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+/// let agent = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+///
+/// let crashlog = CrashLogBuilder::new()
+///     .add_region(RegionBuilder::new().add_record(mca))
+///     .add_region(RegionBuilder::new().add_record(agent))
+///     .build()
+///     .unwrap();
+/// assert_eq!(crashlog.regions.len(), 2);
+///
+/// // The Crash Log can be exported and parsed back.
+/// let crashlog = CrashLog::from_slice(&crashlog.to_bytes()).unwrap();
+/// assert_eq!(crashlog.regions.len(), 2);
+/// ```
+#[derive(Clone, Default)]
+pub struct CrashLogBuilder {
+    regions: Vec<RegionBuilder>,
+    metadata: Metadata,
+}
+
+impl CrashLogBuilder {
+    /// Returns a new builder of a Crash Log without region.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a region to the Crash Log.
+    pub fn add_region(mut self, region: RegionBuilder) -> Self {
+        self.regions.push(region);
+        self
+    }
+
+    /// Sets the extra information stored alongside the Crash Log records.
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Builds the Crash Log. The regions stored in the Box records are extracted like in the
+    /// Crash Logs read from a file.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [RegionBuilder::build], and [Error::InvalidCrashLog] if the Crash
+    /// Log has no region.
+    pub fn build(self) -> Result<CrashLog, Error> {
+        let regions = self
+            .regions
+            .iter()
+            .map(RegionBuilder::build)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut crashlog = CrashLog::from_regions(regions, false)?;
+        crashlog.metadata = self.metadata;
+        Ok(crashlog)
+    }
+}
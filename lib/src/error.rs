@@ -6,8 +6,10 @@ use crate::{
     collateral::{ItemPath, PVSS},
     header::Version,
 };
+#[cfg(all(not(feature = "std"), feature = "collateral_verification"))]
+use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
-use alloc::{fmt, str};
+use alloc::{fmt, str, string::String};
 #[cfg(not(feature = "std"))]
 use core::num;
 #[cfg(feature = "std")]
@@ -26,17 +28,40 @@ pub enum Error {
     InvalidBootErrorRecordRegion,
     InvalidHeader,
     EmptyRegion,
+    RegionOverflow(usize, usize),
+    /// The size of the record (in bytes) cannot be encoded in its header.
+    InvalidRecordSize(usize),
+    /// The payload of the record is compressed but the `compression` feature is disabled.
+    CompressedPayload,
     InvalidHeaderType(u16),
     InvalidRecordType(u8),
+    /// The name does not designate any known record type.
+    InvalidRecordTypeName(String),
     InvalidProductID(u32),
+    MissingNode(String, String),
+    InvalidNodeType(String),
+    InvalidNodeValue(String, u64),
+    InvalidSession(String),
+    InvalidDecodeDefinition(String),
+    /// The resource cannot be accessed without the given privilege. The last element provides a
+    /// hint on how to get the required privilege.
+    InsufficientPrivileges(String, &'static str, &'static str),
     #[cfg(feature = "serialize")]
     JsonError(serde_json::Error),
+    #[cfg(feature = "yaml")]
+    YamlError(serde_yaml_ng::Error),
     Utf8Error(str::Utf8Error),
     ParseIntError(num::ParseIntError),
     #[cfg(feature = "std")]
     IOError(io::Error),
     #[cfg(feature = "std")]
     OsStringError(std::ffi::OsString),
+    #[cfg(feature = "compression")]
+    InvalidArchive(String),
+    #[cfg(feature = "collateral_verification")]
+    UnverifiedCollateral(Box<PVSS>, ItemPath),
+    #[cfg(feature = "collateral_verification")]
+    InvalidVerifyingKey,
 }
 
 #[cfg(feature = "std")]
@@ -59,17 +84,69 @@ impl fmt::Display for Error {
             Error::InvalidBootErrorRecordRegion => write!(f, "Invalid Boot Error Record region"),
             Error::InvalidHeader => write!(f, "Invalid Crash Log Header"),
             Error::EmptyRegion => write!(f, "The Crash Log Region is not populated"),
+            Error::RegionOverflow(size, region_size) => write!(
+                f,
+                "The records ({size}B) do not fit in the Crash Log Region ({region_size}B)"
+            ),
+            Error::InvalidRecordSize(size) => {
+                write!(
+                    f,
+                    "The record size ({size}B) cannot be encoded in its header"
+                )
+            }
+            Error::CompressedPayload => write!(
+                f,
+                "The record payload is compressed and the compression support is disabled"
+            ),
             Error::InvalidHeaderType(ht) => write!(f, "Invalid Crash Log Header Type: {ht}"),
             Error::InvalidRecordType(rt) => write!(f, "Unknown Crash Log Record Type: {rt:#x}"),
+            Error::InvalidRecordTypeName(name) => {
+                write!(f, "Unknown Crash Log Record Type: {name}")
+            }
             Error::InvalidProductID(pid) => write!(f, "Unknown Crash Log Product ID: {pid:#x}"),
+            Error::MissingNode(path, ancestor) if ancestor.is_empty() => {
+                write!(f, "No node found at {path}")
+            }
+            Error::MissingNode(path, ancestor) => {
+                write!(
+                    f,
+                    "No node found at {path} (deepest existing node: {ancestor})"
+                )
+            }
+            Error::InvalidNodeType(path) => write!(f, "The node at {path} is not a field"),
+            Error::InvalidNodeValue(path, value) => {
+                write!(f, "Unexpected value for the node at {path}: {value:#x}")
+            }
+            Error::InvalidSession(reason) => write!(f, "Invalid session bundle: {reason}"),
+            Error::InvalidDecodeDefinition(reason) => {
+                write!(f, "Invalid decode definition: {reason}")
+            }
+            Error::InsufficientPrivileges(resource, privilege, hint) => write!(
+                f,
+                "Insufficient privileges to access {resource}: {privilege} privileges are \
+                 required. {hint}"
+            ),
             #[cfg(feature = "serialize")]
             Error::JsonError(err) => write!(f, "Invalid JSON file: {err}"),
+            #[cfg(feature = "yaml")]
+            Error::YamlError(err) => write!(f, "Invalid YAML document: {err}"),
             Error::Utf8Error(err) => write!(f, "UTF8 Error: {err}"),
             Error::ParseIntError(err) => write!(f, "Error while parsing integer: {err}"),
             #[cfg(feature = "std")]
             Error::IOError(err) => write!(f, "Encountered IO error: {err}"),
             #[cfg(feature = "std")]
             Error::OsStringError(s) => write!(f, "Cannot convert OS string: {s:?}"),
+            #[cfg(feature = "compression")]
+            Error::InvalidArchive(reason) => write!(f, "Invalid compressed file: {reason}"),
+            #[cfg(feature = "collateral_verification")]
+            Error::UnverifiedCollateral(pvss, item) => {
+                write!(
+                    f,
+                    "The {item} collateral file for {pvss} cannot be verified"
+                )
+            }
+            #[cfg(feature = "collateral_verification")]
+            Error::InvalidVerifyingKey => write!(f, "Invalid collateral verifying key"),
         }
     }
 }
@@ -95,6 +172,13 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+#[cfg(feature = "yaml")]
+impl From<serde_yaml_ng::Error> for Error {
+    fn from(err: serde_yaml_ng::Error) -> Self {
+        Error::YamlError(err)
+    }
+}
+
 impl From<str::Utf8Error> for Error {
     fn from(err: str::Utf8Error) -> Self {
         Error::Utf8Error(err)
@@ -1,9 +1,32 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+//! Extraction of the Crash Log records from the platform.
+
 #[cfg(target_os = "uefi")]
-pub mod efi;
+mod efi;
+#[cfg(all(target_family = "windows", feature = "std"))]
+mod event_log;
 #[cfg(all(target_family = "windows", feature = "std"))]
-pub mod event_log;
+mod firmware_variable;
+#[cfg(feature = "physical_memory")]
+mod physical_memory;
+#[cfg(feature = "std")]
+mod source;
 #[cfg(all(target_os = "linux", feature = "std"))]
-pub mod sysfs;
+mod sysfs;
+#[cfg(feature = "tokio")]
+mod tokio;
+#[cfg(all(feature = "std", feature = "serialize"))]
+mod watch;
+
+#[cfg(all(target_family = "unix", feature = "physical_memory", feature = "std"))]
+pub use physical_memory::DevMem;
+#[cfg(feature = "physical_memory")]
+pub use physical_memory::PhysicalMemory;
+#[cfg(feature = "std")]
+pub use source::{
+    Availability, BuiltinSource, Extracted, ExtractionSource, SourceRegistry, UefiVariable,
+};
+#[cfg(all(feature = "std", feature = "serialize"))]
+pub use watch::Watcher;
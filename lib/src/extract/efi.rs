@@ -75,6 +75,7 @@ fn find_bert() -> Result<Bert, Error> {
 
 impl CrashLog {
     /// Reads the Crash Log records from the EFI System Table.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn from_system_table(system_table: Option<NonNull<SystemTable>>) -> Result<Self, Error> {
         if let Some(system_table) = system_table {
             unsafe { uefi::table::set_system_table(system_table.as_ptr()) }
@@ -84,7 +85,7 @@ impl CrashLog {
             .and_then(|bert| {
                 unsafe { bert.berr_from_phys_mem() }.ok_or(Error::InvalidBootErrorRecordRegion)
             })
-            .and_then(CrashLog::from_berr)?;
+            .and_then(|berr| CrashLog::from_berr(berr, false))?;
 
         crashlog.metadata = metadata::Metadata {
             computer: Some("efi".to_string()),
@@ -1,6 +1,7 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use super::source::Availability;
 use crate::CrashLog;
 use crate::metadata;
 use std::alloc::{Layout, alloc, dealloc};
@@ -210,6 +211,19 @@ fn query_crashlogs(path: PCWSTR, query: PCWSTR, query_flags: u32) -> Result<Vec<
     Ok(crashlogs)
 }
 
+pub(super) fn probe() -> Availability {
+    for channel in ["Microsoft-Windows-Kernel-WHEA/Errors", "System"] {
+        let path = HSTRING::from(channel);
+        if let Err(err) = evt_query(PCWSTR(path.as_ptr()), w!("*"), EvtQueryChannelPath.0) {
+            return Availability::Unavailable(format!(
+                "cannot access the {channel} event channel: {}",
+                err.message()
+            ));
+        }
+    }
+    Availability::Available
+}
+
 impl CrashLog {
     pub(crate) fn from_event_logs(path: Option<&Path>) -> Result<Vec<Self>> {
         let evtx_path_hstring = path.map(HSTRING::from);
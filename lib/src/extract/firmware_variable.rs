@@ -0,0 +1,122 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::CrashLog;
+use crate::error::Error;
+use uguid::Guid;
+use windows::Win32::Foundation::{
+    CloseHandle, ERROR_ENVVAR_NOT_FOUND, ERROR_INSUFFICIENT_BUFFER, ERROR_NOT_ALL_ASSIGNED,
+    ERROR_PRIVILEGE_NOT_HELD, GetLastError, HANDLE, LUID, WIN32_ERROR,
+};
+use windows::Win32::Security::{
+    AdjustTokenPrivileges, LUID_AND_ATTRIBUTES, LookupPrivilegeValueW, SE_PRIVILEGE_ENABLED,
+    SE_SYSTEM_ENVIRONMENT_NAME, TOKEN_ADJUST_PRIVILEGES, TOKEN_PRIVILEGES, TOKEN_QUERY,
+};
+use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+use windows::Win32::System::WindowsProgramming::GetFirmwareEnvironmentVariableW;
+use windows::core::{HSTRING, PCWSTR};
+
+/// Initial size of the buffer used to read the UEFI variables
+const INITIAL_BUFFER_SIZE: usize = 0x10000;
+/// Maximum size of the UEFI variables
+const MAX_BUFFER_SIZE: usize = 0x100000;
+
+/// Enables the `SeSystemEnvironmentPrivilege` of the current process, which is required to read
+/// the UEFI variables. The privilege can only be enabled if it is held by the process (e.g. when
+/// running from an elevated prompt).
+fn enable_system_environment_privilege() -> windows::core::Result<()> {
+    let mut token = HANDLE::default();
+    unsafe {
+        OpenProcessToken(
+            GetCurrentProcess(),
+            TOKEN_ADJUST_PRIVILEGES | TOKEN_QUERY,
+            &mut token,
+        )?
+    };
+
+    let mut luid = LUID::default();
+    let res =
+        unsafe { LookupPrivilegeValueW(PCWSTR::null(), SE_SYSTEM_ENVIRONMENT_NAME, &mut luid) }
+            .and_then(|()| {
+                let privileges = TOKEN_PRIVILEGES {
+                    PrivilegeCount: 1,
+                    Privileges: [LUID_AND_ATTRIBUTES {
+                        Luid: luid,
+                        Attributes: SE_PRIVILEGE_ENABLED,
+                    }],
+                };
+                unsafe { AdjustTokenPrivileges(token, false, Some(&privileges), 0, None, None) }
+            })
+            .and_then(|()| {
+                // AdjustTokenPrivileges succeeds even if the process doesn't hold the privilege.
+                match unsafe { GetLastError() } {
+                    ERROR_NOT_ALL_ASSIGNED => Err(ERROR_NOT_ALL_ASSIGNED.into()),
+                    _ => Ok(()),
+                }
+            });
+
+    let _ = unsafe { CloseHandle(token) };
+    res
+}
+
+fn read_firmware_variable(name: &str, vendor: Guid) -> Result<Vec<u8>, WIN32_ERROR> {
+    let name = HSTRING::from(name);
+    let vendor = HSTRING::from(format!("{{{vendor}}}"));
+    let mut buffer = vec![0u8; INITIAL_BUFFER_SIZE];
+
+    if let Err(err) = enable_system_environment_privilege() {
+        // Reading the variable fails with ERROR_PRIVILEGE_NOT_HELD.
+        log::debug!("Cannot enable SeSystemEnvironmentPrivilege: {err}");
+    }
+
+    loop {
+        let size = unsafe {
+            GetFirmwareEnvironmentVariableW(
+                PCWSTR(name.as_ptr()),
+                PCWSTR(vendor.as_ptr()),
+                Some(buffer.as_mut_ptr() as *mut _),
+                buffer.len() as u32,
+            )
+        };
+
+        if size != 0 {
+            buffer.truncate(size as usize);
+            return Ok(buffer);
+        }
+
+        let error = unsafe { GetLastError() };
+        if error != ERROR_INSUFFICIENT_BUFFER || buffer.len() >= MAX_BUFFER_SIZE {
+            return Err(error);
+        }
+        buffer.resize(buffer.len() * 2, 0);
+    }
+}
+
+impl CrashLog {
+    /// Searches for the Crash Logs embedded in the CPER records stored in a UEFI variable.
+    ///
+    /// The variable is read using the `GetFirmwareEnvironmentVariable` Windows API. The
+    /// `SeSystemEnvironmentPrivilege` it requires is enabled for the current process, which
+    /// must therefore run from an elevated prompt.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn from_uefi_variable(name: &str, vendor: Guid) -> Result<Vec<Self>, Error> {
+        let value = read_firmware_variable(name, vendor).map_err(|err| {
+            log::warn!("Cannot read UEFI variable {name}-{vendor}: {err:?}");
+            match err {
+                ERROR_ENVVAR_NOT_FOUND => Error::NoCrashLogFound,
+                ERROR_PRIVILEGE_NOT_HELD => Error::InsufficientPrivileges(
+                    "the UEFI variables".into(),
+                    "SeSystemEnvironmentPrivilege",
+                    "Run the command from an elevated prompt.",
+                ),
+                _ => Error::InternalError,
+            }
+        })?;
+
+        let crashlogs = Self::from_embedded_cpers(&value);
+        if crashlogs.is_empty() {
+            return Err(Error::NoCrashLogFound);
+        }
+        Ok(crashlogs)
+    }
+}
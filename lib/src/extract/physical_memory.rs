@@ -0,0 +1,282 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::CrashLog;
+use crate::bert::{Berr, Bert};
+use crate::error::Error;
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+use uguid::Guid;
+
+/// Signature of the EFI System Table
+const EFI_SYSTEM_TABLE_SIGNATURE: &[u8; 8] = b"IBI SYST";
+/// Offset of the number of entries in the EFI configuration table
+const EFI_SYSTEM_TABLE_ENTRY_COUNT_OFFSET: usize = 104;
+/// Offset of the pointer to the EFI configuration table
+const EFI_SYSTEM_TABLE_ENTRIES_OFFSET: usize = 112;
+/// Size of the EFI System Table fields read by this module
+const EFI_SYSTEM_TABLE_SIZE: usize = 120;
+/// Size of an EFI configuration table entry (vendor GUID and table pointer)
+const EFI_CONFIGURATION_TABLE_ENTRY_SIZE: usize = 24;
+/// Upper bound of the number of EFI configuration table entries
+const MAX_EFI_CONFIGURATION_TABLE_ENTRIES: usize = 0x100;
+/// GUID of the EFI configuration table entry pointing to the ACPI 2.0 RSDP
+const ACPI2_TABLE_GUID: Guid = uguid::guid!("8868e871-e4f1-11d3-bc22-0080c73c8881");
+/// GUID of the EFI configuration table entry pointing to the ACPI 1.0 RSDP
+const ACPI_TABLE_GUID: Guid = uguid::guid!("eb9d2d30-2d88-11d3-9a16-0090273fc14d");
+
+/// Signature of the Root System Description Pointer
+const RSDP_SIGNATURE: &[u8; 8] = b"RSD PTR ";
+/// Size of the ACPI 1.0 RSDP
+const RSDP_SIZE: usize = 20;
+/// Size of the ACPI 2.0 RSDP
+const XSDP_SIZE: usize = 36;
+/// Physical memory range where the RSDP is located on the legacy BIOS systems
+const BIOS_AREA: Range<u64> = 0xe0000..0x100000;
+/// Alignment of the RSDP in the legacy BIOS area
+const RSDP_ALIGNMENT: usize = 16;
+
+/// Size of the header of the ACPI System Description Tables
+const SDT_HEADER_SIZE: usize = 36;
+/// Upper bound of the size of the ACPI System Description Tables
+const MAX_SDT_SIZE: usize = 0x100000;
+
+/// Read access to the physical memory of the platform.
+///
+/// This trait allows extracting the Crash Logs reported through ACPI on the operating systems
+/// that are not supported by the other extraction functions (e.g. FreeBSD or embedded operating
+/// systems), as long as they provide a way to read the physical memory.
+///
+/// # Examples
+///
+/// ```no_run
+/// use intel_crashlog::extract::PhysicalMemory;
+/// use intel_crashlog::prelude::*;
+///
+/// struct IdentityMapped;
+///
+/// impl PhysicalMemory for IdentityMapped {
+///     fn read(&mut self, address: u64, buf: &mut [u8]) -> Result<(), Error> {
+///         let src = unsafe { core::slice::from_raw_parts(address as *const u8, buf.len()) };
+///         buf.copy_from_slice(src);
+///         Ok(())
+///     }
+/// }
+///
+/// let crashlog = CrashLog::from_physical_memory(&mut IdentityMapped);
+/// ```
+pub trait PhysicalMemory {
+    /// Fills the buffer with the content of the physical memory starting at the given address.
+    fn read(&mut self, address: u64, buf: &mut [u8]) -> Result<(), Error>;
+}
+
+/// Physical memory exposed by a character device of the operating system (e.g. `/dev/mem`).
+#[cfg(all(target_family = "unix", feature = "std"))]
+pub struct DevMem {
+    file: std::fs::File,
+}
+
+#[cfg(all(target_family = "unix", feature = "std"))]
+impl DevMem {
+    /// Opens the character device exposing the physical memory.
+    pub fn open<P: AsRef<std::path::Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path).map_err(|err| {
+            log::warn!("Cannot open {}: {err}", path.display());
+            match err.kind() {
+                std::io::ErrorKind::PermissionDenied => Error::InsufficientPrivileges(
+                    path.display().to_string(),
+                    "root",
+                    "Run the command as root (e.g. using sudo).",
+                ),
+                _ => Error::from(err),
+            }
+        })?;
+        Ok(Self { file })
+    }
+}
+
+#[cfg(all(target_family = "unix", feature = "std"))]
+impl PhysicalMemory for DevMem {
+    fn read(&mut self, address: u64, buf: &mut [u8]) -> Result<(), Error> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        self.file.seek(SeekFrom::Start(address))?;
+        Ok(self.file.read_exact(buf)?)
+    }
+}
+
+fn read_bytes<M: PhysicalMemory>(
+    memory: &mut M,
+    address: u64,
+    len: usize,
+) -> Result<Vec<u8>, Error> {
+    let mut bytes = vec![0; len];
+    memory.read(address, &mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_u32(s: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(s[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(s: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(s[offset..offset + 8].try_into().unwrap())
+}
+
+fn checksum_is_valid(s: &[u8]) -> bool {
+    s.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) == 0
+}
+
+/// Returns the address of the ACPI RSDP listed in the EFI configuration table.
+fn find_rsdp_in_efi_system_table<M: PhysicalMemory>(
+    memory: &mut M,
+    address: u64,
+) -> Result<u64, Error> {
+    let system_table = read_bytes(memory, address, EFI_SYSTEM_TABLE_SIZE)?;
+    if !system_table.starts_with(EFI_SYSTEM_TABLE_SIGNATURE) {
+        log::error!("Invalid EFI System Table signature at {address:#x}");
+        return Err(Error::NoCrashLogFound);
+    }
+
+    let count = read_u64(&system_table, EFI_SYSTEM_TABLE_ENTRY_COUNT_OFFSET) as usize;
+    let entries = read_u64(&system_table, EFI_SYSTEM_TABLE_ENTRIES_OFFSET);
+    log::debug!("Found {count} EFI configuration table entries at {entries:#x}");
+
+    let table = read_bytes(
+        memory,
+        entries,
+        count.min(MAX_EFI_CONFIGURATION_TABLE_ENTRIES) * EFI_CONFIGURATION_TABLE_ENTRY_SIZE,
+    )?;
+
+    let mut rsdp = None;
+    for entry in table.chunks_exact(EFI_CONFIGURATION_TABLE_ENTRY_SIZE) {
+        let guid = Guid::from_bytes(entry[..16].try_into().unwrap());
+        let pointer = read_u64(entry, 16);
+        if guid == ACPI2_TABLE_GUID {
+            return Ok(pointer);
+        } else if guid == ACPI_TABLE_GUID {
+            rsdp = Some(pointer);
+        }
+    }
+
+    rsdp.ok_or_else(|| {
+        log::error!("No ACPI table found in the EFI configuration table");
+        Error::NoCrashLogFound
+    })
+}
+
+/// Searches for the ACPI RSDP in the legacy BIOS area.
+fn find_rsdp_in_bios_area<M: PhysicalMemory>(memory: &mut M) -> Result<u64, Error> {
+    let area = read_bytes(
+        memory,
+        BIOS_AREA.start,
+        (BIOS_AREA.end - BIOS_AREA.start) as usize,
+    )?;
+
+    (0..area.len())
+        .step_by(RSDP_ALIGNMENT)
+        .find(|&offset| {
+            area[offset..].starts_with(RSDP_SIGNATURE)
+                && area
+                    .get(offset..offset + RSDP_SIZE)
+                    .is_some_and(checksum_is_valid)
+        })
+        .map(|offset| BIOS_AREA.start + offset as u64)
+        .ok_or_else(|| {
+            log::error!("No ACPI RSDP found in the BIOS area");
+            Error::NoCrashLogFound
+        })
+}
+
+/// Reads the ACPI System Description Table located at the given address.
+fn read_sdt<M: PhysicalMemory>(memory: &mut M, address: u64) -> Result<Vec<u8>, Error> {
+    let header = read_bytes(memory, address, SDT_HEADER_SIZE)?;
+    let length = read_u32(&header, 4) as usize;
+    if !(SDT_HEADER_SIZE..=MAX_SDT_SIZE).contains(&length) {
+        log::error!("Invalid ACPI table length at {address:#x}: {length:#x}");
+        return Err(Error::InvalidBootErrorRecordRegion);
+    }
+    read_bytes(memory, address, length)
+}
+
+/// Returns the BERT listed in the ACPI tables pointed by the given RSDP.
+fn find_bert<M: PhysicalMemory>(memory: &mut M, rsdp: u64) -> Result<Bert, Error> {
+    let header = read_bytes(memory, rsdp, XSDP_SIZE)?;
+    if !header.starts_with(RSDP_SIGNATURE) {
+        log::error!("Invalid ACPI RSDP signature at {rsdp:#x}");
+        return Err(Error::NoCrashLogFound);
+    }
+
+    // The XSDT is only available since ACPI 2.0.
+    let revision = header[15];
+    let (sdt, entry_size) = if revision >= 2 && read_u64(&header, 24) != 0 {
+        (read_sdt(memory, read_u64(&header, 24))?, 8)
+    } else {
+        (read_sdt(memory, read_u32(&header, 16) as u64)?, 4)
+    };
+
+    for entry in sdt[SDT_HEADER_SIZE..].chunks_exact(entry_size) {
+        let address = if entry_size == 8 {
+            read_u64(entry, 0)
+        } else {
+            read_u32(entry, 0) as u64
+        };
+
+        let mut signature = [0; 4];
+        memory.read(address, &mut signature)?;
+        if &signature != b"BERT" {
+            continue;
+        }
+
+        log::info!("Found ACPI boot error record table at {address:#x}");
+        return Bert::from_slice(&read_sdt(memory, address)?)
+            .ok_or(Error::InvalidBootErrorRecordRegion);
+    }
+
+    log::info!("Could not find the ACPI BERT table");
+    Err(Error::NoCrashLogFound)
+}
+
+impl CrashLog {
+    /// Reads the Crash Log reported through the ACPI tables pointed by the given Root System
+    /// Description Pointer (RSDP).
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(memory)))]
+    pub fn from_rsdp<M: PhysicalMemory>(memory: &mut M, rsdp: u64) -> Result<Self, Error> {
+        let bert = find_bert(memory, rsdp)?;
+        let region = bert.region;
+        let region_length = bert.region_length as usize;
+        log::debug!("Reading Boot Error Region at {region:#x} ({region_length} bytes)");
+
+        let berr = read_bytes(memory, region, region_length)?;
+        let berr = Berr::from_slice(&berr).ok_or(Error::InvalidBootErrorRecordRegion)?;
+        Self::from_berr(berr, false)
+    }
+
+    /// Reads the Crash Log reported through the ACPI tables listed in the EFI configuration
+    /// table.
+    ///
+    /// The address of the EFI System Table is typically exposed by the operating system (e.g.
+    /// the `machdep.efi_systbl` sysctl on FreeBSD).
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(memory)))]
+    pub fn from_efi_system_table<M: PhysicalMemory>(
+        memory: &mut M,
+        address: u64,
+    ) -> Result<Self, Error> {
+        let rsdp = find_rsdp_in_efi_system_table(memory, address)?;
+        Self::from_rsdp(memory, rsdp)
+    }
+
+    /// Reads the Crash Log reported through the ACPI tables, which are searched in the legacy
+    /// BIOS area of the physical memory.
+    ///
+    /// On the UEFI systems, the ACPI tables are not guaranteed to be referenced from the BIOS
+    /// area. [CrashLog::from_efi_system_table] should be preferred when the address of the EFI
+    /// System Table is known.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn from_physical_memory<M: PhysicalMemory>(memory: &mut M) -> Result<Self, Error> {
+        let rsdp = find_rsdp_in_bios_area(memory)?;
+        Self::from_rsdp(memory, rsdp)
+    }
+}
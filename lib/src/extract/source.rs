@@ -0,0 +1,283 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::CrashLog;
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+use uguid::Guid;
+
+/// Platform interface the Crash Log records can be extracted from.
+///
+/// The interfaces supported by this crate are provided by [BuiltinSource]. Other interfaces
+/// (e.g. proprietary transports) can implement this trait and be added to a [SourceRegistry].
+pub trait ExtractionSource {
+    /// Returns the name of the source.
+    fn name(&self) -> &str;
+
+    /// Checks if the source exists on the current system and can be accessed.
+    fn probe(&self) -> Availability;
+
+    /// Extracts the Crash Logs currently exposed by the source.
+    fn extract(&self) -> Result<Vec<CrashLog>, Error>;
+}
+
+/// Crash Logs extracted from an extraction source (see [`SourceRegistry::extract_all`])
+pub type Extracted<'a> = (&'a dyn ExtractionSource, Result<Vec<CrashLog>, Error>);
+
+/// Platform interfaces supported by this crate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuiltinSource {
+    /// ACPI Boot Error Record Table exposed in the linux sysfs
+    AcpiSysfs,
+    /// Intel PMT Crash Log endpoints exposed in the linux sysfs
+    PmtSysfs,
+    /// Error records persisted in the linux pstore filesystem
+    Pstore,
+    /// Error records stored in the UEFI variables exposed in the linux efivarfs
+    Efivars,
+    /// Windows event logs
+    WindowsEventLog,
+}
+
+/// Availability of an extraction source on the current system
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Availability {
+    Available,
+    /// The source doesn't exist on this platform.
+    Unsupported,
+    /// The source cannot be accessed for the given reason.
+    Unavailable(String),
+}
+
+impl BuiltinSource {
+    /// All the builtin extraction sources
+    pub const ALL: [BuiltinSource; 5] = [
+        BuiltinSource::AcpiSysfs,
+        BuiltinSource::PmtSysfs,
+        BuiltinSource::Pstore,
+        BuiltinSource::Efivars,
+        BuiltinSource::WindowsEventLog,
+    ];
+}
+
+impl ExtractionSource for BuiltinSource {
+    fn name(&self) -> &str {
+        match self {
+            BuiltinSource::AcpiSysfs => "ACPI (sysfs)",
+            BuiltinSource::PmtSysfs => "Intel PMT (sysfs)",
+            BuiltinSource::Pstore => "pstore",
+            BuiltinSource::Efivars => "UEFI variables (efivarfs)",
+            BuiltinSource::WindowsEventLog => "Windows event logs",
+        }
+    }
+
+    fn probe(&self) -> Availability {
+        match self {
+            #[cfg(target_os = "linux")]
+            BuiltinSource::AcpiSysfs => super::sysfs::probe_acpi(),
+            #[cfg(target_os = "linux")]
+            BuiltinSource::PmtSysfs => super::sysfs::probe_pmt(),
+            #[cfg(target_os = "linux")]
+            BuiltinSource::Pstore => super::sysfs::probe_pstore(),
+            #[cfg(target_os = "linux")]
+            BuiltinSource::Efivars => super::sysfs::probe_efivars(),
+            #[cfg(target_family = "windows")]
+            BuiltinSource::WindowsEventLog => super::event_log::probe(),
+            _ => Availability::Unsupported,
+        }
+    }
+
+    /// Extracts the Crash Logs currently exposed by the source.
+    ///
+    /// When the Boot Error Region is not exposed in the linux sysfs, the ACPI source reads it
+    /// from the physical memory (see [`CrashLog::from_acpi_bert`]).
+    fn extract(&self) -> Result<Vec<CrashLog>, Error> {
+        match self {
+            #[cfg(target_os = "linux")]
+            BuiltinSource::AcpiSysfs => CrashLog::from_acpi_sysfs()
+                .or_else(|err| match err {
+                    Error::NoCrashLogFound => CrashLog::from_acpi_bert(),
+                    err => Err(err),
+                })
+                .map(|crashlog| vec![crashlog]),
+            #[cfg(target_os = "linux")]
+            BuiltinSource::PmtSysfs => CrashLog::from_pmt_sysfs().map(|crashlog| vec![crashlog]),
+            #[cfg(target_os = "linux")]
+            BuiltinSource::Pstore => CrashLog::from_pstore(),
+            #[cfg(target_os = "linux")]
+            BuiltinSource::Efivars => CrashLog::from_efivars(),
+            #[cfg(target_family = "windows")]
+            BuiltinSource::WindowsEventLog => CrashLog::from_windows_event_logs(None),
+            _ => Err(Error::NoCrashLogFound),
+        }
+    }
+}
+
+/// UEFI variable storing error records, read with [`CrashLog::from_uefi_variable`]
+///
+/// Unlike [`BuiltinSource::Efivars`], this source reads a single variable, which can be done on
+/// both linux and Windows. The variable is identified by its name and vendor GUID, formatted as
+/// in the linux efivarfs:
+///
+/// ```
+/// use intel_crashlog::extract::UefiVariable;
+///
+/// let variable: UefiVariable = "CrashLog-8be4df61-93ca-11d2-aa0d-00e098032b8c".parse().unwrap();
+/// assert_eq!(variable.name, "CrashLog");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UefiVariable {
+    /// Name of the variable
+    pub name: String,
+    /// Vendor GUID of the variable
+    pub vendor: Guid,
+}
+
+impl FromStr for UefiVariable {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, vendor) = s
+            .split_at_checked(s.len().checked_sub(36).ok_or(())?)
+            .ok_or(())?;
+        Ok(Self {
+            name: name.strip_suffix('-').ok_or(())?.to_string(),
+            vendor: vendor.parse().map_err(|_| ())?,
+        })
+    }
+}
+
+impl ExtractionSource for UefiVariable {
+    fn name(&self) -> &str {
+        "UEFI variable"
+    }
+
+    fn probe(&self) -> Availability {
+        #[cfg(target_os = "linux")]
+        {
+            super::sysfs::probe_efivars()
+        }
+        #[cfg(target_family = "windows")]
+        {
+            Availability::Available
+        }
+        #[cfg(not(any(target_os = "linux", target_family = "windows")))]
+        {
+            Availability::Unsupported
+        }
+    }
+
+    fn extract(&self) -> Result<Vec<CrashLog>, Error> {
+        #[cfg(any(target_os = "linux", target_family = "windows"))]
+        {
+            CrashLog::from_uefi_variable(&self.name, self.vendor)
+        }
+        #[cfg(not(any(target_os = "linux", target_family = "windows")))]
+        {
+            Err(Error::NoCrashLogFound)
+        }
+    }
+}
+
+impl fmt::Display for BuiltinSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Set of extraction sources
+///
+/// The default registry contains all the [BuiltinSource]s.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::extract::{Availability, ExtractionSource, SourceRegistry};
+/// use intel_crashlog::prelude::*;
+///
+/// struct Bmc;
+///
+/// impl ExtractionSource for Bmc {
+///     fn name(&self) -> &str {
+///         "BMC"
+///     }
+///
+///     fn probe(&self) -> Availability {
+///         Availability::Unavailable("no BMC connection".into())
+///     }
+///
+///     fn extract(&self) -> Result<Vec<CrashLog>, Error> {
+///         Err(Error::NoCrashLogFound)
+///     }
+/// }
+///
+/// let mut registry = SourceRegistry::default();
+/// registry.register(Bmc);
+/// assert!(registry.sources().any(|source| source.name() == "BMC"));
+/// ```
+pub struct SourceRegistry {
+    sources: Vec<Box<dyn ExtractionSource>>,
+}
+
+impl Default for SourceRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        for source in BuiltinSource::ALL {
+            registry.register(source);
+        }
+        registry
+    }
+}
+
+impl SourceRegistry {
+    /// Returns a registry without any extraction source.
+    pub fn empty() -> Self {
+        Self {
+            sources: Vec::new(),
+        }
+    }
+
+    /// Adds an extraction source to the registry.
+    pub fn register<S: ExtractionSource + 'static>(&mut self, source: S) {
+        self.sources.push(Box::new(source));
+    }
+
+    /// Returns an iterator over the extraction sources of the registry.
+    pub fn sources(&self) -> impl Iterator<Item = &dyn ExtractionSource> {
+        self.sources.iter().map(|source| source.as_ref())
+    }
+
+    /// Reports the availability of each extraction source on the current system.
+    pub fn probe_all(&self) -> Vec<(&dyn ExtractionSource, Availability)> {
+        self.sources()
+            .map(|source| (source, source.probe()))
+            .collect()
+    }
+
+    /// Extracts the Crash Logs from each extraction source supported on the current system.
+    pub fn extract_all(&self) -> Vec<Extracted<'_>> {
+        self.sources()
+            .filter(|source| source.probe() != Availability::Unsupported)
+            .map(|source| (source, source.extract()))
+            .collect()
+    }
+}
+
+impl CrashLog {
+    /// Reports the availability of each builtin extraction source on the current system.
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::extract::BuiltinSource;
+    ///
+    /// let sources = CrashLog::available_sources();
+    /// assert_eq!(sources.len(), BuiltinSource::ALL.len());
+    /// ```
+    pub fn available_sources() -> Vec<(BuiltinSource, Availability)> {
+        BuiltinSource::ALL
+            .into_iter()
+            .map(|source| (source, source.probe()))
+            .collect()
+    }
+}
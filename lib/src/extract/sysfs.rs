@@ -1,42 +1,373 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+#[cfg(test)]
+mod tests;
+
+use super::source::Availability;
 use crate::CrashLog;
-use crate::bert::Berr;
+use crate::bert::{Berr, Bert};
 use crate::error::Error;
+use crate::metadata::PmtEndpoint;
 use crate::region::Region;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::path::Path;
+use uguid::Guid;
 
 const BERR_PATH: &str = "/sys/firmware/acpi/tables/data/BERT";
+const BERT_PATH: &str = "/sys/firmware/acpi/tables/BERT";
+const MEM_PATH: &str = "/dev/mem";
+const PSTORE_PATH: &str = "/sys/fs/pstore";
+const EFIVARS_PATH: &str = "/sys/firmware/efi/efivars";
+/// Size of the attributes stored before the value of each variable in the efivarfs
+const EFIVAR_ATTRIBUTES_SIZE: usize = 4;
 const PMT_PATH: &str = "/sys/class/intel_pmt";
+const ROOT_HINT: &str = "Run the command as root (e.g. using sudo).";
+
+fn read_pmt_attribute(path: &Path, name: &str) -> Option<String> {
+    std::fs::read_to_string(path.join(name))
+        .inspect_err(|err| log::debug!("Cannot read {name} in {}: {err}", path.display()))
+        .ok()
+        .map(|value| value.trim().to_string())
+}
+
+fn read_pmt_flag(path: &Path, name: &str) -> Option<bool> {
+    read_pmt_attribute(path, name)?
+        .parse::<u8>()
+        .inspect_err(|err| log::warn!("Invalid {name} value in {}: {err}", path.display()))
+        .ok()
+        .map(|value| value != 0)
+}
+
+/// Converts the errors returned while accessing the sysfs into the errors reported to the user.
+fn sysfs_error(path: &Path, err: std::io::Error) -> Error {
+    match err.kind() {
+        ErrorKind::NotFound => Error::NoCrashLogFound,
+        ErrorKind::PermissionDenied => {
+            Error::InsufficientPrivileges(path.display().to_string(), "root", ROOT_HINT)
+        }
+        _ => Error::from(err),
+    }
+}
+
+fn unavailable(path: &Path, err: std::io::Error) -> Availability {
+    Availability::Unavailable(sysfs_error(path, err).to_string())
+}
+
+pub(super) fn probe_acpi() -> Availability {
+    match std::fs::File::open(BERR_PATH) {
+        Ok(_) => Availability::Available,
+        // The Boot Error Region can still be read from the memory.
+        Err(err) if err.kind() == ErrorKind::NotFound && Path::new(BERT_PATH).exists() => {
+            Availability::Available
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound => Availability::Unavailable(format!(
+            "{BERR_PATH} not found: the firmware doesn't report any boot error record"
+        )),
+        Err(err) => unavailable(Path::new(BERR_PATH), err),
+    }
+}
+
+pub(super) fn probe_pmt() -> Availability {
+    let entries = match std::fs::read_dir(PMT_PATH) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            return Availability::Unavailable(format!(
+                "{PMT_PATH} not found: the intel_pmt driver is not loaded"
+            ));
+        }
+        Err(err) => return unavailable(Path::new(PMT_PATH), err),
+    };
+
+    let mut availability =
+        Availability::Unavailable(format!("no Crash Log endpoint enumerated in {PMT_PATH}"));
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let is_crashlog_dir = entry
+            .file_name()
+            .to_str()
+            .map(|name| name.starts_with("crashlog"))
+            .unwrap_or(false);
+        if !is_crashlog_dir {
+            continue;
+        }
+
+        let path = entry.path().join("crashlog");
+        match std::fs::File::open(&path) {
+            Ok(_) => return Availability::Available,
+            Err(err) => availability = unavailable(&path, err),
+        }
+    }
+    availability
+}
+
+/// Reads the Boot Error Region pointed by the BERT ACPI table from the physical memory.
+fn read_boot_error_region(bert: &Bert) -> Result<Vec<u8>, Error> {
+    let region = bert.region;
+    let region_length = bert.region_length as usize;
+    log::debug!("Reading Boot Error Region at {region:#x} ({region_length} bytes)");
+
+    let mut mem = std::fs::File::open(MEM_PATH).map_err(|err| {
+        log::warn!("Cannot open {MEM_PATH}: {err}");
+        sysfs_error(Path::new(MEM_PATH), err)
+    })?;
+    let mut berr = vec![0; region_length];
+    mem.seek(SeekFrom::Start(region))?;
+    mem.read_exact(&mut berr)?;
+    Ok(berr)
+}
+
+pub(super) fn probe_pstore() -> Availability {
+    match std::fs::read_dir(PSTORE_PATH) {
+        Ok(mut entries) => {
+            if entries.next().is_some() {
+                Availability::Available
+            } else {
+                Availability::Unavailable(format!(
+                    "{PSTORE_PATH} is empty: no error record has been persisted"
+                ))
+            }
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound => Availability::Unavailable(format!(
+            "{PSTORE_PATH} not found: the pstore filesystem is not mounted"
+        )),
+        Err(err) => unavailable(Path::new(PSTORE_PATH), err),
+    }
+}
+
+pub(super) fn probe_efivars() -> Availability {
+    match std::fs::read_dir(EFIVARS_PATH) {
+        Ok(_) => Availability::Available,
+        Err(err) if err.kind() == ErrorKind::NotFound => Availability::Unavailable(format!(
+            "{EFIVARS_PATH} not found: the system is not booted in UEFI mode or efivarfs is not \
+            mounted"
+        )),
+        Err(err) => unavailable(Path::new(EFIVARS_PATH), err),
+    }
+}
+
+/// Returns the value of a UEFI variable read from the efivarfs.
+fn read_efivar(path: &Path) -> Result<Vec<u8>, std::io::Error> {
+    let mut value = std::fs::read(path)?;
+    value.drain(..EFIVAR_ATTRIBUTES_SIZE.min(value.len()));
+    Ok(value)
+}
+
+impl PmtEndpoint {
+    /// Reads the control and status registers of a Crash Log endpoint from the linux sysfs
+    ///
+    /// Each attribute is read independently: the attributes that are missing or unreadable are
+    /// reported as `None`. The version of the endpoint is only known when the driver exposes it
+    /// through the `version` attribute.
+    fn from_sysfs(path: &Path) -> Self {
+        let guid = read_pmt_attribute(path, "guid").and_then(|guid| {
+            u32::from_str_radix(guid.trim_start_matches("0x"), 16)
+                .inspect_err(|err| log::warn!("Invalid GUID in {}: {err}", path.display()))
+                .ok()
+        });
+
+        let version = read_pmt_attribute(path, "version").and_then(|version| {
+            version
+                .parse()
+                .inspect_err(|err| log::warn!("Invalid version in {}: {err}", path.display()))
+                .ok()
+        });
+
+        PmtEndpoint {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            guid,
+            version,
+            enabled: read_pmt_flag(path, "enable"),
+            triggered: read_pmt_flag(path, "trigger"),
+            rearmed: read_pmt_flag(path, "rearm"),
+            error: read_pmt_flag(path, "error"),
+            consumed: read_pmt_flag(path, "consumed"),
+            dump_complete: read_pmt_flag(path, "dump_complete"),
+        }
+    }
+}
 
 impl CrashLog {
     /// Reads the Crash Log reported through ACPI from the linux sysfs
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub fn from_acpi_sysfs() -> Result<Self, Error> {
         let berr = std::fs::read(BERR_PATH)
             .map_err(|err| {
                 log::warn!("Cannot read {BERR_PATH}: {err}");
-                match err.kind() {
-                    std::io::ErrorKind::NotFound => Error::NoCrashLogFound,
-                    _ => Error::from(err),
-                }
+                sysfs_error(Path::new(BERR_PATH), err)
             })
             .and_then(|berr| {
                 log::info!("Found ACPI boot error record in sysfs");
                 Berr::from_slice(&berr).ok_or(Error::InvalidBootErrorRecordRegion)
             })?;
 
-        Self::from_berr(berr)
+        Self::from_berr(berr, false)
+    }
+
+    /// Reads the Crash Log reported through the ACPI Boot Error Record Table (BERT).
+    ///
+    /// The BERT is read from the linux sysfs, and the Boot Error Region it points to is read from
+    /// the physical memory. This allows extracting the Crash Log records on the platforms where
+    /// the kernel doesn't expose the Boot Error Region in the sysfs (see
+    /// [`CrashLog::from_acpi_sysfs`]).
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn from_acpi_bert() -> Result<Self, Error> {
+        let bert = std::fs::read(BERT_PATH)
+            .map_err(|err| {
+                log::warn!("Cannot read {BERT_PATH}: {err}");
+                sysfs_error(Path::new(BERT_PATH), err)
+            })
+            .and_then(|bert| {
+                log::info!("Found ACPI boot error record table in sysfs");
+                Bert::from_slice(&bert).ok_or(Error::InvalidBootErrorRecordRegion)
+            })?;
+
+        let berr = read_boot_error_region(&bert)?;
+        let berr = Berr::from_slice(&berr).ok_or(Error::InvalidBootErrorRecordRegion)?;
+        log::debug!(
+            "Found {} entries in the Boot Error Region",
+            berr.entries.len()
+        );
+
+        Self::from_berr(berr, false)
+    }
+
+    /// Searches for the Crash Logs embedded in the error records persisted in the linux pstore
+    /// filesystem.
+    ///
+    /// The pstore backends (e.g. `efi-pstore` or ERST) keep the CPER records reported by the
+    /// platform across resets. The Crash Log records are extracted from each of these CPER
+    /// records. The compressed entries are ignored.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn from_pstore() -> Result<Vec<Self>, Error> {
+        let mut denied = None;
+        let crashlogs: Vec<Self> = std::fs::read_dir(PSTORE_PATH)
+            .map_err(|err| {
+                log::warn!("Cannot read {PSTORE_PATH}: {err}");
+                sysfs_error(Path::new(PSTORE_PATH), err)
+            })?
+            .filter_map(|entry| {
+                entry
+                    .inspect_err(|err| log::error!("Cannot access directory entry: {err}"))
+                    .ok()
+            })
+            .map(|entry| entry.path())
+            .filter(|path| {
+                let compressed = path.extension().is_some_and(|extension| extension == "z");
+                if compressed {
+                    log::debug!("Ignoring compressed pstore entry: {}", path.display());
+                }
+                !compressed
+            })
+            .filter_map(|path| {
+                std::fs::read(&path)
+                    .inspect_err(|err| {
+                        if err.kind() == ErrorKind::PermissionDenied {
+                            denied = Some(path.display().to_string())
+                        }
+                        log::error!("{}: {err}", path.display())
+                    })
+                    .ok()
+                    .map(|data| (path, data))
+            })
+            .flat_map(|(path, data)| {
+                let crashlogs = Self::from_embedded_cpers(&data);
+                if !crashlogs.is_empty() {
+                    log::info!(
+                        "Found {} Crash Logs in pstore entry: {}",
+                        crashlogs.len(),
+                        path.display()
+                    );
+                }
+                crashlogs
+            })
+            .collect();
+
+        if crashlogs.is_empty() {
+            return Err(match denied {
+                Some(path) => Error::InsufficientPrivileges(path, "root", ROOT_HINT),
+                None => Error::NoCrashLogFound,
+            });
+        }
+
+        Ok(crashlogs)
+    }
+
+    /// Searches for the Crash Logs embedded in the CPER records stored in a UEFI variable, read
+    /// from the linux efivarfs.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn from_uefi_variable(name: &str, vendor: Guid) -> Result<Vec<Self>, Error> {
+        let path = Path::new(EFIVARS_PATH).join(format!("{name}-{vendor}"));
+        let value = read_efivar(&path).map_err(|err| {
+            log::warn!("Cannot read {}: {err}", path.display());
+            sysfs_error(&path, err)
+        })?;
+
+        let crashlogs = Self::from_embedded_cpers(&value);
+        if crashlogs.is_empty() {
+            return Err(Error::NoCrashLogFound);
+        }
+        Ok(crashlogs)
+    }
+
+    /// Searches for the Crash Logs embedded in the CPER records stored in any of the UEFI
+    /// variables exposed in the linux efivarfs.
+    ///
+    /// Some firmwares keep the error records reported by the platform in UEFI variables.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn from_efivars() -> Result<Vec<Self>, Error> {
+        let crashlogs: Vec<Self> = std::fs::read_dir(EFIVARS_PATH)
+            .map_err(|err| {
+                log::warn!("Cannot read {EFIVARS_PATH}: {err}");
+                sysfs_error(Path::new(EFIVARS_PATH), err)
+            })?
+            .filter_map(|entry| {
+                entry
+                    .inspect_err(|err| log::error!("Cannot access directory entry: {err}"))
+                    .ok()
+            })
+            .map(|entry| entry.path())
+            .filter_map(|path| {
+                read_efivar(&path)
+                    .inspect_err(|err| log::debug!("{}: {err}", path.display()))
+                    .ok()
+                    .map(|value| (path, value))
+            })
+            .flat_map(|(path, value)| {
+                let crashlogs = Self::from_embedded_cpers(&value);
+                if !crashlogs.is_empty() {
+                    log::info!(
+                        "Found {} Crash Logs in UEFI variable: {}",
+                        crashlogs.len(),
+                        path.display()
+                    );
+                }
+                crashlogs
+            })
+            .collect();
+
+        if crashlogs.is_empty() {
+            return Err(Error::NoCrashLogFound);
+        }
+        Ok(crashlogs)
     }
 
     /// Reads the Crash Log reported through Intel PMT from the linux sysfs
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub fn from_pmt_sysfs() -> Result<Self, Error> {
-        let regions: Vec<Region> = std::fs::read_dir(PMT_PATH)
+        Self::from_pmt_class(Path::new(PMT_PATH))
+    }
+
+    /// Reads the Crash Log endpoints enumerated in the given `intel_pmt` class directory.
+    fn from_pmt_class(class: &Path) -> Result<Self, Error> {
+        let mut endpoints = Vec::new();
+        let mut denied = None;
+        let regions: Vec<Region> = std::fs::read_dir(class)
             .map_err(|err| {
-                log::warn!("Cannot read {PMT_PATH}: {err}");
-                match err.kind() {
-                    std::io::ErrorKind::NotFound => Error::NoCrashLogFound,
-                    _ => Error::from(err),
-                }
+                log::warn!("Cannot read {}: {err}", class.display());
+                sysfs_error(class, err)
             })?
             .filter_map(|entry| {
                 entry
@@ -56,6 +387,11 @@ impl CrashLog {
 
                 is_dir && is_crashlog_dir
             })
+            .inspect(|entry| {
+                let endpoint = PmtEndpoint::from_sysfs(&entry.path());
+                log::debug!("Crash Log endpoint state: {endpoint:?}");
+                endpoints.push(endpoint);
+            })
             .filter_map(|entry| {
                 let mut path = entry.path();
 
@@ -63,6 +399,11 @@ impl CrashLog {
                 path.push("crashlog");
 
                 std::fs::read(&path)
+                    .inspect_err(|err| {
+                        if err.kind() == ErrorKind::PermissionDenied {
+                            denied = Some(path.display().to_string())
+                        }
+                    })
                     .map_err(Error::IOError)
                     .and_then(|region| Region::from_slice(&region))
                     .inspect(|_| log::info!("Extracted valid record from {}", path.display()))
@@ -71,6 +412,14 @@ impl CrashLog {
             })
             .collect();
 
-        Self::from_regions(regions)
+        if regions.is_empty()
+            && let Some(path) = denied
+        {
+            return Err(Error::InsufficientPrivileges(path, "root", ROOT_HINT));
+        }
+
+        let mut crashlog = Self::from_regions(regions, false)?;
+        crashlog.metadata.pmt_endpoints = endpoints;
+        Ok(crashlog)
     }
 }
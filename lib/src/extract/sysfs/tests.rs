@@ -0,0 +1,66 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::CrashLog;
+use crate::metadata::PmtTriggerCause;
+use std::path::Path;
+
+fn add_endpoint(class: &Path, name: &str, attributes: &[(&str, &str)], region: &[u8]) {
+    let path = class.join(name);
+    std::fs::create_dir_all(&path).unwrap();
+    for (attribute, value) in attributes {
+        std::fs::write(path.join(attribute), format!("{value}\n")).unwrap();
+    }
+    std::fs::write(path.join("crashlog"), region).unwrap();
+}
+
+#[test]
+fn pmt_class() {
+    let class = std::env::temp_dir().join(format!("crashlog-pmt-{}", std::process::id()));
+    let mca = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let agent = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+
+    let v2 = [
+        ("guid", "0x1234"),
+        ("version", "2"),
+        ("enable", "1"),
+        ("trigger", "0"),
+        ("rearm", "0"),
+        ("error", "0"),
+        ("consumed", "1"),
+        ("dump_complete", "1"),
+    ];
+    add_endpoint(&class, "crashlog0", &v2, &mca);
+    let v0 = [("guid", "0x5678"), ("enable", "0"), ("trigger", "1")];
+    add_endpoint(&class, "crashlog1", &v0, &agent);
+    // The version is not inferred from the attributes exposed by the endpoint.
+    let unversioned = [("guid", "0x9abc"), ("rearm", "1")];
+    add_endpoint(&class, "crashlog2", &unversioned, &agent);
+    std::fs::create_dir_all(class.join("telem0")).unwrap();
+
+    let crashlog = CrashLog::from_pmt_class(&class);
+    std::fs::remove_dir_all(&class).unwrap();
+    let crashlog = crashlog.unwrap();
+    assert_eq!(crashlog.regions.len(), 3);
+
+    let mut endpoints = crashlog.metadata.pmt_endpoints;
+    endpoints.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(endpoints.len(), 3);
+
+    assert_eq!(endpoints[0].name, "crashlog0");
+    assert_eq!(endpoints[0].guid, Some(0x1234));
+    assert_eq!(endpoints[0].version, Some(2));
+    assert_eq!(endpoints[0].rearmed, Some(false));
+    assert_eq!(endpoints[0].consumed, Some(true));
+    assert_eq!(endpoints[0].trigger_cause(), Some(PmtTriggerCause::Crash));
+
+    assert_eq!(endpoints[1].guid, Some(0x5678));
+    assert_eq!(endpoints[1].version, None);
+    assert_eq!(endpoints[1].enabled, Some(false));
+    assert_eq!(endpoints[1].triggered, Some(true));
+    assert_eq!(endpoints[1].rearmed, None);
+    assert_eq!(endpoints[1].trigger_cause(), None);
+
+    assert_eq!(endpoints[2].version, None);
+    assert_eq!(endpoints[2].rearmed, Some(true));
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::source::{BuiltinSource, ExtractionSource};
+use crate::CrashLog;
+use crate::Error;
+
+/// Runs a blocking extraction function on the thread pool dedicated to the blocking tasks of the
+/// tokio runtime.
+async fn spawn_extraction<T, F>(f: F) -> Result<T, Error>
+where
+    F: FnOnce() -> Result<T, Error> + Send + 'static,
+    T: Send + 'static,
+{
+    ::tokio::task::spawn_blocking(f).await.map_err(|err| {
+        log::error!("Extraction task failed: {err}");
+        Error::InternalError
+    })?
+}
+
+impl BuiltinSource {
+    /// Extracts the Crash Logs currently exposed by the source without blocking the executor.
+    pub async fn extract_async(self) -> Result<Vec<CrashLog>, Error> {
+        spawn_extraction(move || self.extract()).await
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl CrashLog {
+    /// Asynchronous variant of [CrashLog::from_acpi_sysfs].
+    pub async fn from_acpi_sysfs_async() -> Result<Self, Error> {
+        spawn_extraction(Self::from_acpi_sysfs).await
+    }
+
+    /// Asynchronous variant of [CrashLog::from_acpi_bert].
+    pub async fn from_acpi_bert_async() -> Result<Self, Error> {
+        spawn_extraction(Self::from_acpi_bert).await
+    }
+
+    /// Asynchronous variant of [CrashLog::from_pmt_sysfs].
+    pub async fn from_pmt_sysfs_async() -> Result<Self, Error> {
+        spawn_extraction(Self::from_pmt_sysfs).await
+    }
+
+    /// Asynchronous variant of [CrashLog::from_pstore].
+    pub async fn from_pstore_async() -> Result<Vec<Self>, Error> {
+        spawn_extraction(Self::from_pstore).await
+    }
+
+    /// Asynchronous variant of [CrashLog::from_efivars].
+    pub async fn from_efivars_async() -> Result<Vec<Self>, Error> {
+        spawn_extraction(Self::from_efivars).await
+    }
+}
+
+#[cfg(target_family = "windows")]
+impl CrashLog {
+    /// Asynchronous variant of [CrashLog::from_windows_event_logs].
+    pub async fn from_windows_event_logs_async(
+        path: Option<std::path::PathBuf>,
+    ) -> Result<Vec<Self>, Error> {
+        spawn_extraction(move || Self::from_windows_event_logs(path.as_deref())).await
+    }
+}
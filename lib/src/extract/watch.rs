@@ -0,0 +1,116 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::source::{Availability, SourceRegistry};
+use crate::CrashLog;
+use crate::Error;
+use crate::state::ExtractionState;
+use std::time::Duration;
+
+/// Collects the Crash Logs exposed by the platform as they appear.
+///
+/// The extraction sources are polled periodically and only the Crash Logs that haven't been
+/// reported before are returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use intel_crashlog::extract::Watcher;
+/// use std::time::Duration;
+///
+/// let mut watcher = Watcher::new(Duration::from_secs(60));
+/// watcher.run(|source, crashlog| {
+///     println!("New Crash Log from {source}: {} regions", crashlog.regions.len());
+///     true
+/// });
+/// ```
+pub struct Watcher {
+    sources: SourceRegistry,
+    interval: Duration,
+    state: ExtractionState,
+}
+
+impl Watcher {
+    /// Creates a watcher polling all the builtin extraction sources at the given interval.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            sources: SourceRegistry::default(),
+            interval,
+            state: ExtractionState::default(),
+        }
+    }
+
+    /// Only polls the extraction sources of the given registry.
+    pub fn with_sources(mut self, sources: SourceRegistry) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Skips the Crash Logs recorded in the given state (e.g. loaded from a previous run).
+    pub fn with_state(mut self, state: ExtractionState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Returns the state tracking the Crash Logs reported so far.
+    pub fn state(&self) -> &ExtractionState {
+        &self.state
+    }
+
+    /// Polls each available extraction source once and returns the Crash Logs that haven't been
+    /// reported before, along with the name of their source. The returned Crash Logs are recorded
+    /// as reported.
+    pub fn poll(&mut self) -> Vec<(String, CrashLog)> {
+        self.extract_new()
+            .into_iter()
+            .filter(|(_, crashlog)| self.state.mark(crashlog))
+            .collect()
+    }
+
+    /// Polls the extraction sources and passes the new Crash Logs to the callback until it
+    /// returns `false`.
+    ///
+    /// Each Crash Log is recorded as reported when it is passed to the callback, so the Crash
+    /// Logs left undelivered when the callback stops the watcher are reported by the next run.
+    pub fn run<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(&str, CrashLog) -> bool,
+    {
+        loop {
+            for (source, crashlog) in self.extract_new() {
+                // The same Crash Log can be exposed by several sources.
+                if !self.state.mark(&crashlog) {
+                    continue;
+                }
+                log::info!("New Crash Log extracted from {source}");
+                if !callback(&source, crashlog) {
+                    return;
+                }
+            }
+            std::thread::sleep(self.interval);
+        }
+    }
+
+    /// Extracts the Crash Logs from each available extraction source and returns the ones that
+    /// haven't been reported before, without recording them.
+    fn extract_new(&self) -> Vec<(String, CrashLog)> {
+        let mut crashlogs = Vec::new();
+        for source in self.sources.sources() {
+            if source.probe() != Availability::Available {
+                continue;
+            }
+
+            match source.extract() {
+                Ok(extracted) => crashlogs.extend(
+                    extracted
+                        .into_iter()
+                        .filter(|crashlog| self.state.is_new(crashlog))
+                        .map(|crashlog| (source.name().to_string(), crashlog)),
+                ),
+                Err(Error::NoCrashLogFound) => (),
+                Err(err) => log::warn!("{}: {err}", source.name()),
+            }
+        }
+        crashlogs
+    }
+}
@@ -182,6 +182,20 @@ pub extern "C" fn crashlog_read_from_acpi_sysfs(context: *mut CrashLogContext) -
         .unwrap_or(ptr::null_mut())
 }
 
+/// Reads the Crash Log reported through the ACPI Boot Error Record Table from the linux sysfs
+/// and the physical memory
+///
+/// # Errors
+///
+/// Returns a `NULL` pointer if the Crash Log record cannot be found.
+#[cfg(any(all(target_os = "linux", feature = "extraction"), doc))]
+#[unsafe(no_mangle)]
+pub extern "C" fn crashlog_read_from_acpi_bert(context: *mut CrashLogContext) -> *mut CrashLog {
+    CrashLog::from_acpi_bert()
+        .map(alloc)
+        .unwrap_or(ptr::null_mut())
+}
+
 /// Reads the Crash Log reported through Intel PMT from the linux sysfs
 ///
 /// # Errors
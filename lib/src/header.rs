@@ -3,11 +3,15 @@
 
 //! Data structures used in the Crash Log record headers.
 
+mod builder;
+
 #[cfg(feature = "collateral_manager")]
 use crate::collateral::{CollateralManager, CollateralTree, ItemPath, PVSS};
 use crate::errata::{Errata, SERVER_LEGACY_PRODUCT_IDS};
 use crate::error::Error;
 use crate::node::Node;
+#[cfg(feature = "collateral_manager")]
+use crate::utils;
 #[cfg(not(feature = "std"))]
 use alloc::{
     fmt, format,
@@ -18,6 +22,8 @@ use alloc::{
 #[cfg(feature = "std")]
 use std::fmt;
 
+pub use builder::HeaderBuilder;
+
 /// Lists all the Crash Log record types.
 ///
 /// cbindgen:ignore
@@ -35,6 +41,48 @@ pub mod record_types {
     pub const CRASHLOG_AGENT: u8 = 0x1C;
     pub const BOX: u8 = 0x3D;
     pub const MCA: u8 = 0x3E;
+
+    /// Record types that have a name, see [`super::Header::record_type`].
+    pub(crate) const NAMED: [u8; 13] = [
+        PMC,
+        PMC_FW_TRACE,
+        PUNIT,
+        PCORE,
+        ECORE,
+        UNCORE,
+        PMC_TRACE,
+        TCSS,
+        PMC_RST,
+        PCODE,
+        CRASHLOG_AGENT,
+        BOX,
+        MCA,
+    ];
+
+    /// Returns the name of the record type (example: `MCA`), if known.
+    pub(crate) fn name_of(record_type: u8) -> Option<&'static str> {
+        super::Version {
+            record_type,
+            ..super::Version::default()
+        }
+        .record_type_as_str()
+        .ok()
+    }
+
+    /// Returns the record type designated by its `name` (example: `MCA`), as returned by
+    /// [`super::Header::record_type`]. The name is case-insensitive.
+    ///
+    /// ```
+    /// use intel_crashlog::header::record_types;
+    ///
+    /// assert_eq!(record_types::from_name("mca"), Some(record_types::MCA));
+    /// assert_eq!(record_types::from_name("FOO"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<u8> {
+        NAMED.into_iter().find(|&record_type| {
+            name_of(record_type).is_some_and(|known| known.eq_ignore_ascii_case(name))
+        })
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -89,8 +137,22 @@ pub enum HeaderType {
         completion_status: u32,
         collection_complete: bool,
     },
+
+    /// Header type not known by this library, such as the header types introduced by newer
+    /// products. Only the version and size fields are decoded, and the bytes that follow them
+    /// (up to the size of the largest fixed-size header) are preserved in `raw`. The headers of
+    /// unknown type are rejected if the record type is unknown or if the record does not fit in
+    /// the parsed bytes.
+    Unknown {
+        type_value: u16,
+        raw: Vec<u8>,
+    },
 }
 
+/// Size assumed for the headers of unknown type, which is the size of the largest fixed-size
+/// header types (Type4 and Type5)
+const UNKNOWN_HEADER_SIZE: usize = 32;
+
 impl HeaderType {
     fn type2_from_slice(slice: &[u8]) -> Option<Self> {
         let timestamp = u64::from_le_bytes(slice.get(8..16)?.try_into().ok()?);
@@ -218,6 +280,108 @@ impl HeaderType {
         })
     }
 
+    /// Writes the optional fields into a raw header whose length is the size of the header.
+    fn write(&self, bytes: &mut [u8]) {
+        let completion_status_data = |completion_status: u32, collection_complete: bool| {
+            (completion_status & 0x7FFFFFFF) | ((collection_complete as u32) << 31)
+        };
+
+        match self {
+            HeaderType::Type0 | HeaderType::Type1 => (),
+            HeaderType::Type2 {
+                timestamp,
+                agent_version,
+                reason,
+            } => {
+                bytes[8..16].copy_from_slice(&timestamp.to_le_bytes());
+                bytes[16..20].copy_from_slice(&agent_version.to_le_bytes());
+                bytes[20..24].copy_from_slice(&reason.to_le_bytes());
+            }
+            HeaderType::Type3 {
+                timestamp,
+                agent_version,
+                reason,
+                completion_status,
+                collection_complete,
+            } => {
+                bytes[8..16].copy_from_slice(&timestamp.to_le_bytes());
+                bytes[16..20].copy_from_slice(&agent_version.to_le_bytes());
+                bytes[20..24].copy_from_slice(&reason.to_le_bytes());
+                let cs_data = completion_status_data(*completion_status, *collection_complete);
+                bytes[24..28].copy_from_slice(&cs_data.to_le_bytes());
+            }
+            HeaderType::Type4 {
+                timestamp,
+                agent_version,
+                reason,
+                whoami,
+                misc,
+            } => {
+                bytes[8..16].copy_from_slice(&timestamp.to_le_bytes());
+                bytes[16..20].copy_from_slice(&agent_version.to_le_bytes());
+                bytes[20..24].copy_from_slice(&reason.to_le_bytes());
+                bytes[24..28].copy_from_slice(&whoami.to_le_bytes());
+                bytes[28..32].copy_from_slice(&misc.to_le_bytes());
+            }
+            HeaderType::Type5 {
+                timestamp,
+                agent_version,
+                reason,
+                completion_status,
+                collection_complete,
+                error_status,
+            } => {
+                bytes[8..16].copy_from_slice(&timestamp.to_le_bytes());
+                bytes[16..20].copy_from_slice(&agent_version.to_le_bytes());
+                bytes[20..24].copy_from_slice(&reason.to_le_bytes());
+                let cs_data = completion_status_data(*completion_status, *collection_complete);
+                bytes[24..28].copy_from_slice(&cs_data.to_le_bytes());
+                bytes[28..32].copy_from_slice(&error_status.to_le_bytes());
+            }
+            HeaderType::Type6 {
+                timestamp,
+                agent_version,
+                reason,
+                die_id,
+                socket_id,
+                completion_status_size,
+                completion_status,
+                collection_complete,
+            } => {
+                bytes[8..16].copy_from_slice(&timestamp.to_le_bytes());
+                bytes[16..20].copy_from_slice(&agent_version.to_le_bytes());
+                bytes[20..24].copy_from_slice(&reason.to_le_bytes());
+                bytes[24] = *die_id;
+                bytes[25] = *socket_id;
+                bytes[26] = (*completion_status_size & 0x7F) as u8;
+                if *collection_complete {
+                    bytes[27] |= 0x80;
+                }
+                for (dword, cs_data) in bytes[28..].chunks_exact_mut(4).zip(completion_status) {
+                    dword.copy_from_slice(&cs_data.to_le_bytes());
+                }
+            }
+            HeaderType::Unknown { raw, .. } => bytes[8..8 + raw.len()].copy_from_slice(raw),
+            HeaderType::Type0LegacyServer {
+                timestamp,
+                agent_version,
+                reason,
+                socket_id,
+                completion_status,
+                collection_complete,
+                ..
+            } => {
+                // The die ID is encoded in the revision field of the version.
+                bytes[4..8].copy_from_slice(&reason.to_le_bytes());
+                bytes[8..16].copy_from_slice(&timestamp.to_le_bytes());
+                bytes[20..24].copy_from_slice(&agent_version.to_le_bytes());
+                bytes[24] = *socket_id;
+                let cs_data = completion_status_data(*completion_status, *collection_complete);
+                bytes[28..32].copy_from_slice(&cs_data.to_le_bytes());
+            }
+        }
+    }
+
     pub fn from_slice(header_type_value: u16, slice: &[u8]) -> Result<Self, Error> {
         match header_type_value {
             0 => Ok(HeaderType::Type0),
@@ -227,7 +391,13 @@ impl HeaderType {
             4 => Self::type4_from_slice(slice).ok_or(Error::InvalidHeader),
             5 => Self::type5_from_slice(slice).ok_or(Error::InvalidHeader),
             6 => Self::type6_from_slice(slice).ok_or(Error::InvalidHeader),
-            type_value => Err(Error::InvalidHeaderType(type_value)),
+            type_value => {
+                let raw = slice.get(8..UNKNOWN_HEADER_SIZE.min(slice.len()));
+                Ok(HeaderType::Unknown {
+                    type_value,
+                    raw: raw.unwrap_or_default().to_vec(),
+                })
+            }
         }
     }
 
@@ -268,16 +438,62 @@ impl Header {
             HeaderType::from_slice(version.header_type, slice)?
         };
 
-        Ok(Some(Header {
+        let header = Header {
             version,
             size,
             header_type,
-        }))
+        };
+
+        if let HeaderType::Unknown { type_value, .. } = header.header_type {
+            // Random bytes would often be parsed as records of unknown header types.
+            if !header.is_plausible(slice.len()) {
+                return Err(Error::InvalidHeaderType(type_value));
+            }
+            log::warn!("Unknown header type: {type_value}");
+        }
+
+        Ok(Some(header))
+    }
+
+    /// Indicates if the header describes a plausible record: the record type is known, and the
+    /// record is at least as large as its header and fits in the given number of bytes.
+    fn is_plausible(&self, len: usize) -> bool {
+        let size = self.record_size();
+        self.record_type().is_ok() && size >= self.header_size() && size <= len
+    }
+
+    /// Encodes the header into the raw header of a Crash Log record. The returned bytes are as
+    /// long as the header (see [Header::header_size]).
+    ///
+    /// The reserved bits of the header are set to zero, and the size fields are truncated to the
+    /// width of their encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    /// let header = Header::from_slice(&data).unwrap().unwrap();
+    ///
+    /// let bytes = header.to_bytes();
+    /// assert_eq!(bytes, data[..header.header_size()]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0; self.header_size()];
+        bytes[0..4].copy_from_slice(&self.version.as_u32().to_le_bytes());
+
+        self.header_type.write(&mut bytes);
+        match self.header_type {
+            HeaderType::Type0LegacyServer { .. } => self.size.write_type0_legacy_server(&mut bytes),
+            _ => self.size.write(&mut bytes),
+        }
+        bytes
     }
 
     /// Returns the granularity of the record size fields in bytes
     #[inline]
-    fn record_size_granularity(&self) -> usize {
+    pub(crate) fn record_size_granularity(&self) -> usize {
         if self.version.into_errata().core_record_size_bytes {
             return 1;
         }
@@ -342,6 +558,184 @@ impl Header {
         }
     }
 
+    /// Returns the timestamp stored in the header if the header type defines one.
+    pub fn timestamp(&self) -> Option<u64> {
+        match self.header_type {
+            HeaderType::Type2 { timestamp, .. }
+            | HeaderType::Type3 { timestamp, .. }
+            | HeaderType::Type4 { timestamp, .. }
+            | HeaderType::Type5 { timestamp, .. }
+            | HeaderType::Type6 { timestamp, .. }
+            | HeaderType::Type0LegacyServer { timestamp, .. } => Some(timestamp),
+            _ => None,
+        }
+    }
+
+    /// Returns the date of the record collection as an ISO-8601 string (UTC).
+    ///
+    /// The timestamp is converted using the `timestamp_epoch` and `timestamp_frequency` fields
+    /// of the `target_info.json` collateral item of the product. The frequency defaults to 1 Hz.
+    /// Returns [None] if the header has no timestamp or if the epoch of the product is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::collateral::{MemoryCollateralTree, PVSS};
+    /// use intel_crashlog::header::{HeaderBuilder, record_types};
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let pvss = PVSS {
+    ///     product: "XYZ".into(),
+    ///     ..PVSS::default()
+    /// };
+    /// let target_info = r#"{"product": "XYZ", "product_id": "0x7A", "timestamp_epoch": 1735689600}"#;
+    ///
+    /// let mut tree = MemoryCollateralTree::new();
+    /// tree.add_item(pvss, "target_info.json", target_info);
+    /// let cm = CollateralManager::new(tree).unwrap();
+    ///
+    /// let header = HeaderBuilder::new(record_types::MCA)
+    ///     .product_id(0x7a)
+    ///     .type2()
+    ///     .timestamp(3723)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(header.time(&cm).unwrap(), "2025-01-01T01:02:03Z");
+    /// ```
+    #[cfg(feature = "collateral_manager")]
+    pub fn time<T: CollateralTree>(&self, cm: &CollateralManager<T>) -> Option<String> {
+        let timestamp = self.timestamp().filter(|timestamp| *timestamp != 0)?;
+        let target_info = cm.target_info.get(&self.product_id())?;
+        let epoch = target_info.timestamp_epoch?;
+        let frequency = target_info
+            .timestamp_frequency
+            .filter(|frequency| *frequency != 0)
+            .unwrap_or(1);
+
+        let seconds = epoch.checked_add(timestamp / frequency)?;
+        let nanoseconds = (timestamp % frequency) as u128 * 1_000_000_000 / frequency as u128;
+        Some(utils::iso8601(seconds, nanoseconds as u32))
+    }
+
+    /// Returns the names of the triggers of the record collection that are set in the `reason`
+    /// field of the header, in the order of their bit positions.
+    ///
+    /// The names are product-specific and are read from the `reason` field of the
+    /// `target_info.json` collateral item, which maps the bit positions to their names. The bits
+    /// that are not named in the collateral tree are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::collateral::{MemoryCollateralTree, PVSS};
+    /// use intel_crashlog::header::{HeaderBuilder, record_types};
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let pvss = PVSS {
+    ///     product: "XYZ".into(),
+    ///     ..PVSS::default()
+    /// };
+    /// let target_info = r#"{
+    ///     "product": "XYZ",
+    ///     "product_id": "0x7A",
+    ///     "reason": {"0": "three_strike", "1": "machine_check", "4": "warm_reset"}
+    /// }"#;
+    ///
+    /// let mut tree = MemoryCollateralTree::new();
+    /// tree.add_item(pvss, "target_info.json", target_info);
+    /// let cm = CollateralManager::new(tree).unwrap();
+    ///
+    /// let header = HeaderBuilder::new(record_types::MCA)
+    ///     .product_id(0x7a)
+    ///     .type2()
+    ///     .reason(0x12)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(header.reasons(&cm), ["machine_check", "warm_reset"]);
+    /// ```
+    #[cfg(feature = "collateral_manager")]
+    pub fn reasons<'a, T: CollateralTree>(&self, cm: &'a CollateralManager<T>) -> Vec<&'a str> {
+        let Some(reason) = self.reason() else {
+            return Vec::new();
+        };
+        let Some(target_info) = cm.target_info.get(&self.product_id()) else {
+            return Vec::new();
+        };
+
+        (0..u32::BITS as u8)
+            .filter(|bit| reason & (1 << bit) != 0)
+            .filter_map(|bit| target_info.reason.get(&bit))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns the version of the agent that collected the record if the header type defines one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::header::{AgentVersion, HeaderBuilder, record_types};
+    ///
+    /// let header = HeaderBuilder::new(record_types::MCA)
+    ///     .type2()
+    ///     .agent_version(0x0102_0304)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let agent_version = header.agent_version().unwrap();
+    /// assert_eq!(agent_version.to_string(), "1.2.772");
+    /// assert!(agent_version > AgentVersion::from(0x0101_ffff));
+    /// ```
+    pub fn agent_version(&self) -> Option<AgentVersion> {
+        match self.header_type {
+            HeaderType::Type2 { agent_version, .. }
+            | HeaderType::Type3 { agent_version, .. }
+            | HeaderType::Type4 { agent_version, .. }
+            | HeaderType::Type5 { agent_version, .. }
+            | HeaderType::Type6 { agent_version, .. }
+            | HeaderType::Type0LegacyServer { agent_version, .. } => {
+                Some(AgentVersion::from(agent_version))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the reason of the record collection if the header type defines one.
+    pub fn reason(&self) -> Option<u32> {
+        match self.header_type {
+            HeaderType::Type2 { reason, .. }
+            | HeaderType::Type3 { reason, .. }
+            | HeaderType::Type4 { reason, .. }
+            | HeaderType::Type5 { reason, .. }
+            | HeaderType::Type6 { reason, .. }
+            | HeaderType::Type0LegacyServer { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Indicates if the record collection has completed if the header type defines it.
+    pub fn collection_complete(&self) -> Option<bool> {
+        match self.header_type {
+            HeaderType::Type3 {
+                collection_complete,
+                ..
+            }
+            | HeaderType::Type5 {
+                collection_complete,
+                ..
+            }
+            | HeaderType::Type6 {
+                collection_complete,
+                ..
+            }
+            | HeaderType::Type0LegacyServer {
+                collection_complete,
+                ..
+            } => Some(collection_complete),
+            _ => None,
+        }
+    }
+
     /// Returns the ID of the die that generated the record.
     pub fn die_id(&self) -> Option<u8> {
         match self.header_type {
@@ -407,11 +801,15 @@ impl Header {
             res => res?,
         };
         let variant = self.variant(cm).unwrap_or("all");
+        let target_info = cm.target_info.get(&self.product_id());
+        let stepping = target_info.map_or("all", |target_info| target_info.stepping.as_str());
+        let security = target_info.map_or("all", |target_info| target_info.security.as_str());
 
         Ok(PVSS {
             product: product.into(),
             variant: variant.into(),
-            ..PVSS::default()
+            stepping: stepping.into(),
+            security: security.into(),
         })
     }
 
@@ -428,6 +826,7 @@ impl Header {
                 ..
             } => 28 + completion_status_size as usize * 4,
             HeaderType::Type0LegacyServer { .. } => 32,
+            HeaderType::Unknown { ref raw, .. } => 8 + raw.len(),
         }
     }
 
@@ -464,23 +863,31 @@ impl Header {
 impl fmt::Display for Header {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let record_type = self.record_type().unwrap_or("RECORD");
-        let version = format!(
+        let mut fields = vec![format!(
             "product_id=0x{0:x}, record_type=0x{1:x}, revision=0x{2:x}",
             self.version.product_id, self.version.record_type, self.version.revision
-        );
-        let header_type = match self.header_type {
-            HeaderType::Type6 {
-                socket_id, die_id, ..
-            }
-            | HeaderType::Type0LegacyServer {
-                socket_id, die_id, ..
-            } => {
-                format!("die_id={die_id}, socket_id={socket_id}")
-            }
-            _ => "..".to_string(),
-        };
+        )];
+
+        if let Some(die_id) = self.die_id() {
+            fields.push(format!("die_id={die_id}, socket_id={}", self.socket_id()));
+        }
+        if let Some(timestamp) = self.timestamp() {
+            fields.push(format!("timestamp=0x{timestamp:x}"));
+        }
+        if let Some(agent_version) = self.agent_version() {
+            fields.push(format!("agent_version=0x{:x}", agent_version.as_u32()));
+        }
+        if let Some(reason) = self.reason() {
+            fields.push(format!("reason=0x{reason:x}"));
+        }
+        if let Some(collection_complete) = self.collection_complete() {
+            fields.push(format!("collection_complete={collection_complete}"));
+        }
+        if let HeaderType::Unknown { type_value, .. } = self.header_type {
+            fields.push(format!("header_type={type_value}"));
+        }
 
-        write!(f, "{record_type} - ({version}, {header_type})")
+        write!(f, "{record_type} - ({})", fields.join(", "))
     }
 }
 
@@ -573,31 +980,78 @@ impl fmt::Display for Version {
     }
 }
 
+/// Version of the agent that collected the Crash Log record
+///
+/// The versions are ordered by their major, minor and build numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AgentVersion {
+    /// Major version (bits 31:24)
+    pub major: u8,
+    /// Minor version (bits 23:16)
+    pub minor: u8,
+    /// Build number (bits 15:0)
+    pub build: u16,
+}
+
+impl AgentVersion {
+    pub fn as_u32(&self) -> u32 {
+        ((self.major as u32) << 24) | ((self.minor as u32) << 16) | self.build as u32
+    }
+}
+
+impl From<u32> for AgentVersion {
+    fn from(agent_version: u32) -> Self {
+        AgentVersion {
+            major: (agent_version >> 24) as u8,
+            minor: (agent_version >> 16) as u8,
+            build: agent_version as u16,
+        }
+    }
+}
+
+impl fmt::Display for AgentVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.build)
+    }
+}
+
 /// Size of the Crash Log record
 #[derive(Debug, Default, Clone)]
 pub struct RecordSize {
     /// Size of the main section of the record in dwords
-    pub record_size: u16,
+    pub record_size: u32,
     /// Size of the extended section of the record in dwords
-    pub extended_record_size: u16,
+    pub extended_record_size: u32,
 }
 
 impl RecordSize {
     /// Creates a [RecordSize] from the raw record
     pub fn from_slice(slice: &[u8]) -> Option<Self> {
         Some(RecordSize {
-            record_size: u16::from_le_bytes(slice.get(4..6)?.try_into().ok()?),
-            extended_record_size: u16::from_le_bytes(slice.get(6..8)?.try_into().ok()?),
+            record_size: u16::from_le_bytes(slice.get(4..6)?.try_into().ok()?) as u32,
+            extended_record_size: u16::from_le_bytes(slice.get(6..8)?.try_into().ok()?) as u32,
         })
     }
 
     /// Creates a [RecordSize] from the raw record of a server product with legacy header type0
     pub fn from_slice_type0_legacy_server(slice: &[u8]) -> Option<Self> {
         Some(RecordSize {
-            record_size: u16::from_le_bytes(slice.get(16..18)?.try_into().ok()?),
+            record_size: u16::from_le_bytes(slice.get(16..18)?.try_into().ok()?) as u32,
             extended_record_size: 0,
         })
     }
+
+    /// Writes the record size fields into a raw header.
+    pub(crate) fn write(&self, bytes: &mut [u8]) {
+        bytes[4..6].copy_from_slice(&(self.record_size as u16).to_le_bytes());
+        bytes[6..8].copy_from_slice(&(self.extended_record_size as u16).to_le_bytes());
+    }
+
+    /// Writes the record size fields into a raw header of a server product with legacy header
+    /// type0.
+    fn write_type0_legacy_server(&self, bytes: &mut [u8]) {
+        bytes[16..18].copy_from_slice(&(self.record_size as u16).to_le_bytes());
+    }
 }
 
 impl From<&RecordSize> for Node {
@@ -623,6 +1077,16 @@ impl From<&Version> for Node {
     }
 }
 
+impl From<&AgentVersion> for Node {
+    fn from(agent_version: &AgentVersion) -> Self {
+        let mut node = Node::field("agent_version", agent_version.as_u32() as u64);
+        node.add(Node::field("major", agent_version.major as u64));
+        node.add(Node::field("minor", agent_version.minor as u64));
+        node.add(Node::field("build", agent_version.build as u64));
+        node
+    }
+}
+
 impl From<&Header> for Node {
     fn from(header: &Header) -> Self {
         let mut node = Node::section("hdr");
@@ -636,7 +1100,7 @@ impl From<&Header> for Node {
                 reason,
             } => {
                 node.add(Node::field("timestamp", timestamp));
-                node.add(Node::field("agent_version", agent_version as u64));
+                node.add(Node::from(&AgentVersion::from(agent_version)));
                 node.add(Node::field("reason", reason as u64));
             }
             HeaderType::Type3 {
@@ -647,7 +1111,7 @@ impl From<&Header> for Node {
                 collection_complete,
             } => {
                 node.add(Node::field("timestamp", timestamp));
-                node.add(Node::field("agent_version", agent_version as u64));
+                node.add(Node::from(&AgentVersion::from(agent_version)));
                 node.add(Node::field("reason", reason as u64));
 
                 let mut completion_status_node = Node::section("completion_status");
@@ -667,7 +1131,7 @@ impl From<&Header> for Node {
                 misc,
             } => {
                 node.add(Node::field("timestamp", timestamp));
-                node.add(Node::field("agent_version", agent_version as u64));
+                node.add(Node::from(&AgentVersion::from(agent_version)));
                 node.add(Node::field("reason", reason as u64));
                 node.add(Node::field("whoami", whoami as u64));
                 node.add(Node::field("misc", misc as u64));
@@ -681,7 +1145,7 @@ impl From<&Header> for Node {
                 error_status,
             } => {
                 node.add(Node::field("timestamp", timestamp));
-                node.add(Node::field("agent_version", agent_version as u64));
+                node.add(Node::from(&AgentVersion::from(agent_version)));
                 node.add(Node::field("reason", reason as u64));
                 node.add(Node::field("error_status", error_status as u64));
 
@@ -705,7 +1169,7 @@ impl From<&Header> for Node {
                 collection_complete,
             } => {
                 node.add(Node::field("timestamp", timestamp));
-                node.add(Node::field("agent_version", agent_version as u64));
+                node.add(Node::from(&AgentVersion::from(agent_version)));
                 node.add(Node::field("reason", reason as u64));
                 let mut die_skt_info = Node::section("die_skt_info");
                 die_skt_info.add(Node::field("die_id", die_id as u64));
@@ -737,7 +1201,7 @@ impl From<&Header> for Node {
                 collection_complete,
             } => {
                 node.add(Node::field("timestamp", timestamp));
-                node.add(Node::field("agent_version", agent_version as u64));
+                node.add(Node::from(&AgentVersion::from(agent_version)));
                 node.add(Node::field("reason", reason as u64));
                 node.add(Node::field("die_id", die_id as u64));
                 node.add(Node::field("socket_id", socket_id as u64));
@@ -747,6 +1211,7 @@ impl From<&Header> for Node {
                     collection_complete as u64,
                 ));
             }
+            HeaderType::Unknown { ref raw, .. } => node.add(Node::bytes("raw", raw)),
             _ => (),
         }
 
@@ -0,0 +1,338 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{Header, HeaderType, RecordSize, Version};
+use crate::errata::SERVER_LEGACY_PRODUCT_IDS;
+use crate::error::Error;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Builds a Crash Log record [Header] from its fields.
+///
+/// The version dword is assembled from the record type, product ID, revision and header type,
+/// and the size fields are computed from the size of the payload that follows the header.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::header::{HeaderBuilder, record_types};
+///
+/// let header = HeaderBuilder::new(record_types::MCA)
+///     .product_id(0x7a)
+///     .revision(2)
+///     .type6(1, 0)
+///     .timestamp(0x1234)
+///     .collection_complete(true)
+///     .payload_size(0x100)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(header.record_type().unwrap(), "MCA");
+/// assert_eq!(header.die_id(), Some(1));
+/// assert_eq!(header.record_size(), header.header_size() + 0x100);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HeaderBuilder {
+    record_type: u8,
+    product_id: u32,
+    revision: u32,
+    cldic: bool,
+    header_type: u16,
+    legacy_server: bool,
+    timestamp: u64,
+    agent_version: u32,
+    reason: u32,
+    whoami: u32,
+    misc: u32,
+    error_status: u32,
+    die_id: u8,
+    socket_id: u8,
+    completion_status: Vec<u32>,
+    collection_complete: bool,
+    payload_size: usize,
+    extended_size: usize,
+}
+
+impl HeaderBuilder {
+    /// Returns a new builder of a Type1 header for the given record type (see
+    /// [super::record_types]).
+    pub fn new(record_type: u8) -> Self {
+        Self {
+            record_type,
+            product_id: 0,
+            revision: 0,
+            cldic: false,
+            header_type: 1,
+            legacy_server: false,
+            timestamp: 0,
+            agent_version: 0,
+            reason: 0,
+            whoami: 0,
+            misc: 0,
+            error_status: 0,
+            die_id: 0,
+            socket_id: 0,
+            completion_status: Vec::new(),
+            collection_complete: false,
+            payload_size: 0,
+            extended_size: 0,
+        }
+    }
+
+    /// Sets the ID of the product that generated the record.
+    pub fn product_id(mut self, product_id: u32) -> Self {
+        self.product_id = product_id;
+        self
+    }
+
+    /// Sets the revision of the record.
+    pub fn revision(mut self, revision: u32) -> Self {
+        self.revision = revision;
+        self
+    }
+
+    /// Sets the flag indicating that the record ends with a checksum dword.
+    pub fn cldic(mut self, cldic: bool) -> Self {
+        self.cldic = cldic;
+        self
+    }
+
+    fn header_type(mut self, header_type: u16) -> Self {
+        self.header_type = header_type;
+        self.legacy_server = false;
+        self
+    }
+
+    /// Uses a Type0 header, which only defines the version and the size of the record.
+    pub fn type0(self) -> Self {
+        self.header_type(0)
+    }
+
+    /// Uses a Type1 header, which only defines the version and the size of the record.
+    pub fn type1(self) -> Self {
+        self.header_type(1)
+    }
+
+    /// Uses a Type2 header, which adds the timestamp, agent version and reason fields.
+    pub fn type2(self) -> Self {
+        self.header_type(2)
+    }
+
+    /// Uses a Type3 header, which adds the completion status to the Type2 fields.
+    pub fn type3(self) -> Self {
+        self.header_type(3)
+    }
+
+    /// Uses a Type4 header, which adds the `whoami` and `misc` fields to the Type2 fields.
+    pub fn type4(mut self, whoami: u32, misc: u32) -> Self {
+        self.whoami = whoami;
+        self.misc = misc;
+        self.header_type(4)
+    }
+
+    /// Uses a Type5 header, which adds the error status to the Type3 fields.
+    pub fn type5(mut self, error_status: u32) -> Self {
+        self.error_status = error_status;
+        self.header_type(5)
+    }
+
+    /// Uses a Type6 header, which identifies the die and the socket that generated the record.
+    pub fn type6(mut self, die_id: u8, socket_id: u8) -> Self {
+        self.die_id = die_id;
+        self.socket_id = socket_id;
+        self.header_type(6)
+    }
+
+    /// Uses the Type0 header of the legacy server products. The product ID must be one of
+    /// these products, and the die ID is encoded into the revision of the record.
+    pub fn type0_legacy_server(mut self, die_id: u8, socket_id: u8) -> Self {
+        self.die_id = die_id;
+        self.socket_id = socket_id;
+        self.header_type = 0;
+        self.legacy_server = true;
+        self
+    }
+
+    /// Sets the timestamp of the record collection.
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Sets the version of the agent that collected the record.
+    pub fn agent_version(mut self, agent_version: u32) -> Self {
+        self.agent_version = agent_version;
+        self
+    }
+
+    /// Sets the reason of the record collection.
+    pub fn reason(mut self, reason: u32) -> Self {
+        self.reason = reason;
+        self
+    }
+
+    /// Sets the completion status of the record collection. The Type6 headers store each dword
+    /// of the completion status, the other header types only store the first one.
+    pub fn completion_status(mut self, completion_status: impl Into<Vec<u32>>) -> Self {
+        self.completion_status = completion_status.into();
+        self
+    }
+
+    /// Sets the flag indicating that the record collection has completed.
+    pub fn collection_complete(mut self, collection_complete: bool) -> Self {
+        self.collection_complete = collection_complete;
+        self
+    }
+
+    /// Sets the size in bytes of the payload that follows the header in the main section of the
+    /// record.
+    pub fn payload_size(mut self, payload_size: usize) -> Self {
+        self.payload_size = payload_size;
+        self
+    }
+
+    /// Sets the size in bytes of the extended section of the record.
+    pub fn extended_size(mut self, extended_size: usize) -> Self {
+        self.extended_size = extended_size;
+        self
+    }
+
+    /// Returns the revision of the legacy server record, with the die ID encoded in bits 0, 1
+    /// and 7.
+    fn legacy_server_revision(&self) -> Option<u32> {
+        let encoding = match self.die_id {
+            0 | 4 | 8 | 12 => self.die_id >> 2,
+            9..=11 => (self.die_id - 9) | 0x80,
+            _ => return None,
+        };
+        Some((self.revision & !0x83) | encoding as u32)
+    }
+
+    fn optional_fields(&self) -> HeaderType {
+        let completion_status = self.completion_status.first().copied().unwrap_or_default();
+        match (self.header_type, self.legacy_server) {
+            (_, true) => HeaderType::Type0LegacyServer {
+                timestamp: self.timestamp,
+                agent_version: self.agent_version,
+                reason: self.reason,
+                die_id: self.die_id,
+                socket_id: self.socket_id,
+                completion_status,
+                collection_complete: self.collection_complete,
+            },
+            (0, _) => HeaderType::Type0,
+            (2, _) => HeaderType::Type2 {
+                timestamp: self.timestamp,
+                agent_version: self.agent_version,
+                reason: self.reason,
+            },
+            (3, _) => HeaderType::Type3 {
+                timestamp: self.timestamp,
+                agent_version: self.agent_version,
+                reason: self.reason,
+                completion_status,
+                collection_complete: self.collection_complete,
+            },
+            (4, _) => HeaderType::Type4 {
+                timestamp: self.timestamp,
+                agent_version: self.agent_version,
+                reason: self.reason,
+                whoami: self.whoami,
+                misc: self.misc,
+            },
+            (5, _) => HeaderType::Type5 {
+                timestamp: self.timestamp,
+                agent_version: self.agent_version,
+                reason: self.reason,
+                completion_status,
+                collection_complete: self.collection_complete,
+                error_status: self.error_status,
+            },
+            (6, _) => HeaderType::Type6 {
+                timestamp: self.timestamp,
+                agent_version: self.agent_version,
+                reason: self.reason,
+                die_id: self.die_id,
+                socket_id: self.socket_id,
+                completion_status_size: self.completion_status.len() as u16,
+                completion_status: self.completion_status.clone(),
+                collection_complete: self.collection_complete,
+            },
+            _ => HeaderType::Type1,
+        }
+    }
+
+    /// Builds the header.
+    ///
+    /// The size fields are expressed in the granularity of the record type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidHeader] if a field does not fit in the version dword or in the
+    /// size fields, if the Type6 completion status is longer than 127 dwords, or if the legacy
+    /// server header is used with another product (and vice versa for the Type0 header) or with
+    /// a die ID that cannot be encoded.
+    pub fn build(&self) -> Result<Header, Error> {
+        let legacy_server_product = SERVER_LEGACY_PRODUCT_IDS.contains(&self.product_id);
+        // The Type0 headers of the legacy server products use a specific layout.
+        if self.header_type == 0 && self.legacy_server != legacy_server_product {
+            return Err(Error::InvalidHeader);
+        }
+
+        let revision = if self.legacy_server {
+            self.legacy_server_revision().ok_or(Error::InvalidHeader)?
+        } else {
+            self.revision
+        };
+
+        if revision > 0xFF
+            || self.product_id > 0xFFF
+            || self.record_type > 0x3F
+            || self.completion_status.len() > 0x7F
+        {
+            return Err(Error::InvalidHeader);
+        }
+
+        let version = Version {
+            revision,
+            header_type: self.header_type,
+            product_id: self.product_id,
+            record_type: self.record_type,
+            consumed: false,
+            cldic: self.cldic,
+        };
+        let mut header = Header {
+            version,
+            size: RecordSize::default(),
+            header_type: self.optional_fields(),
+        };
+
+        let granularity = header.record_size_granularity();
+        let record_size = (header.header_size() + self.payload_size).div_ceil(granularity);
+        let extended_record_size = self.extended_size.div_ceil(granularity);
+        if record_size > 0xFFFF || extended_record_size > 0xFFFF {
+            return Err(Error::InvalidHeader);
+        }
+        if let HeaderType::Type0LegacyServer { .. } = header.header_type
+            && extended_record_size > 0
+        {
+            return Err(Error::InvalidHeader);
+        }
+
+        header.size.record_size = record_size as u32;
+        header.size.extended_record_size = extended_record_size as u32;
+        Ok(header)
+    }
+
+    /// Builds a raw record made of the header followed by a zeroed payload and extended section.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [HeaderBuilder::build].
+    pub fn build_record(&self) -> Result<Vec<u8>, Error> {
+        let header = self.build()?;
+        let mut bytes = header.to_bytes();
+        bytes.resize(header.record_size(), 0);
+        Ok(bytes)
+    }
+}
@@ -32,7 +32,12 @@
 //!         "crashlog_data": {
 //!             "mca": {
 //!                 "hdr": {
-//!                     "agent_version": "0x0",
+//!                     "agent_version": {
+//!                         "_value": "0x0",
+//!                         "build": "0x0",
+//!                         "major": "0x0",
+//!                         "minor": "0x0"
+//!                     },
 //!                     "completion_status": {
 //!                         "completion_status": "0x0",
 //!                         "record_collection_completed": "0x0"
@@ -94,39 +99,75 @@
 //!
 //! - `collateral_manager`: provides support for the project-specific decode definitions. See
 //!   [collateral] for more information.
+//! - `compression`: decompresses the gzip and zip archives passed to [CrashLog::from_slice], and
+//!   the compressed record payloads. See [compression] for more information (requires `std`,
+//!   disabled by default).
 //! - `extraction`: provides functions to extract the Crash Log record from the platform.
+//! - `physical_memory`: provides functions to extract the Crash Log records reported through ACPI
+//!   from the physical memory, on any operating system providing access to it. See
+//!   [extract::PhysicalMemory] for more information (requires `extraction`, disabled by default).
 //! - `embedded_collateral_tree`: embeds the collateral tree in the binary (requires
 //!   `collateral_manager`).
+//! - `collateral_verification`: provides [collateral::CollateralManager::with_verifying_key],
+//!   which refuses the collateral items that don't match a signed manifest. See
+//!   [collateral::Verification] for more information (requires `collateral_manager`, disabled by
+//!   default).
 //! - `ffi`: provides a C interface to the library (requires `embedded_collateral_tree`). See [ffi]
 //!   for more information.
 //! - `fs_collateral_tree`: provides support in the collateral manager for reading collateral tree
 //!   from the file system at runtime (requires `std` and `collateral_manager`).
+//! - `archive_collateral_tree`: provides support in the collateral manager for reading the
+//!   collateral tree from a zip, tar, or tar.gz archive without extracting it (requires
+//!   `collateral_manager` and `compression`, disabled by default).
 //! - `serialize`: provides [serde::Serialize] implementation for the [node::Node] objects. This is
 //!   required to export the register tree to JSON.
+//! - `yaml`: provides [node::Node::to_yaml], which exports the register tree to YAML (requires
+//!   `serialize` and `std`, disabled by default).
+//! - `tracing`: instruments the extraction, the region parsing, and the record decoding with
+//!   [tracing](https://docs.rs/tracing) spans (disabled by default).
+//! - `rayon`: provides [CrashLog::decode_parallel], which decodes the records on a
+//!   [rayon](https://docs.rs/rayon) thread pool (requires `std` and `collateral_manager`, disabled
+//!   by default).
+//! - `tokio`: provides asynchronous variants of the extraction functions, which run the
+//!   blocking extraction on the [tokio](https://docs.rs/tokio) blocking thread pool (requires
+//!   `std` and `extraction`, disabled by default).
 //! - `std`: when disabled, the crate won't depend on the Rust's standard library. Please note
 //!   that a memory allocator is still required by this crate in `#![no_std]` environments.
 
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+#[cfg(feature = "collateral_manager")]
+pub mod analysis;
 mod bert;
 #[cfg(feature = "collateral_manager")]
 pub mod collateral;
-mod cper;
+#[cfg(feature = "compression")]
+pub mod compression;
+pub mod cper;
 mod crashlog;
 pub mod errata;
 mod error;
 #[cfg(feature = "extraction")]
-mod extract;
+pub mod extract;
 #[cfg(feature = "ffi")]
 pub mod ffi;
 pub mod header;
 pub mod metadata;
 pub mod node;
+pub mod pmt;
 pub mod prelude;
 pub mod record;
 pub mod region;
+#[cfg(all(feature = "std", feature = "serialize"))]
+pub mod session;
+#[cfg(feature = "collateral_manager")]
+pub mod severity;
+#[cfg(all(feature = "std", feature = "serialize"))]
+pub mod state;
+#[cfg(feature = "collateral_manager")]
+pub mod timeline;
 mod utils;
 
-pub use crashlog::CrashLog;
+pub use crashlog::{CrashLog, CrashLogBuilder};
 pub use error::Error;
@@ -13,7 +13,7 @@ use std::fmt;
 use crate::cper::CperSectionBody;
 
 /// Crash Log Metadata
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct Metadata {
     /// Name of the computer where the Crash Log has been extracted from.
     pub computer: Option<String>,
@@ -22,9 +22,70 @@ pub struct Metadata {
     /// When the Crash Log is extracted from a CPER, this field stores the extra CPER sections that
     /// could be read from the CPER structure.
     pub extra_cper_sections: Vec<CperSectionBody>,
+    /// When the Crash Log is extracted from Intel PMT, this field stores the state of the Crash
+    /// Log endpoints the records have been read from.
+    pub pmt_endpoints: Vec<PmtEndpoint>,
+}
+
+/// State of an Intel PMT Crash Log endpoint
+///
+/// The fields are `None` when the corresponding register is not exposed by the endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct PmtEndpoint {
+    /// Name of the endpoint (e.g. `crashlog0`)
+    pub name: String,
+    /// Identifies the layout and the version of the Crash Log endpoint
+    pub guid: Option<u32>,
+    /// Version of the Crash Log endpoint, which defines the layout of its control and status
+    /// registers. It is read from the discovery header of the endpoint, or from its `version`
+    /// attribute in the linux sysfs, and is never inferred from the registers exposed by the
+    /// endpoint.
+    pub version: Option<u8>,
+    /// Indicates if the Crash Log collection is enabled
+    pub enabled: Option<bool>,
+    /// Indicates if the last Crash Log collection has been requested by software, by writing
+    /// to the `trigger` control of the endpoint, rather than caused by a crash. See
+    /// [`PmtEndpoint::trigger_cause`].
+    pub triggered: Option<bool>,
+    /// Indicates if the Crash Log collection is re-armed for the next crash. The state is
+    /// reported whenever the endpoint exposes it, even if the `rearm` control is not writable by
+    /// the current user (e.g. when not running as root), in which case the endpoint can only be
+    /// re-armed by a privileged user.
+    pub rearmed: Option<bool>,
+    /// Indicates if an error occurred during the Crash Log collection
+    pub error: Option<bool>,
+    /// Indicates if the Crash Log has been consumed
+    pub consumed: Option<bool>,
+    /// Indicates if the Crash Log collection has completed
+    pub dump_complete: Option<bool>,
+}
+
+/// Event that triggered the Crash Log collection of an Intel PMT endpoint
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PmtTriggerCause {
+    /// The collection has been requested by software through the control register
+    Software,
+    /// The collection has been triggered by the platform on a crash
+    Crash,
+}
+
+impl PmtEndpoint {
+    /// Returns the event that triggered the Crash Log collection, or `None` if the collection
+    /// hasn't completed or if its cause cannot be determined from the registers of the endpoint.
+    pub fn trigger_cause(&self) -> Option<PmtTriggerCause> {
+        if !self.dump_complete? {
+            return None;
+        }
+        Some(if self.triggered? {
+            PmtTriggerCause::Software
+        } else {
+            PmtTriggerCause::Crash
+        })
+    }
 }
 
 /// Crash Log Extraction Time
+#[derive(Clone)]
 pub struct Time {
     pub year: u16,
     pub month: u8,
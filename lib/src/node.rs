@@ -2,15 +2,27 @@
 // SPDX-License-Identifier: MIT
 
 //! A tree-like data structure containing the decoded Crash Log registers.
+//!
+//! The nodes are located in the tree with dot-separated paths (example: `mca.bank3.status`).
+//! The dots and the backslashes that are part of a node name are escaped with a backslash in the
+//! paths (example: `pmc.version\.major` for the `version.major` child of the `pmc` node). See
+//! [`split_path`] and [`escape_name`].
 
+use crate::Error;
+use core::ops::Index;
 #[cfg(feature = "std")]
-use std::collections::{BTreeMap, btree_map};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, btree_map},
+};
 
 #[cfg(not(feature = "std"))]
 use alloc::{
+    borrow::Cow,
     collections::{BTreeMap, btree_map},
     format,
     string::String,
+    vec::Vec,
 };
 
 #[cfg(feature = "serialize")]
@@ -28,10 +40,34 @@ pub enum NodeType {
     Record,
     /// Crash Log field
     Field { value: u64 },
+    /// Crash Log field storing a signed integer
+    Signed { value: i64 },
+    /// Crash Log field storing a boolean
+    Bool { value: bool },
+    /// Crash Log field storing a string
+    String { value: String },
+    /// Crash Log field wider than 64 bits, stored as an array of bytes (least significant byte
+    /// first)
+    Bytes { value: Vec<u8> },
+    /// Crash Log field that is not available because the record is truncated
+    Truncated,
+}
+
+/// Order of the children of a node in the serialized output
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChildOrder {
+    /// The children are sorted alphabetically by name.
+    #[default]
+    Sorted,
+    /// The children are listed in the order they have been added to the node.
+    Insertion,
 }
 
 /// Node of the Crash Log register tree
-#[derive(Debug, Default, PartialEq, Eq)]
+///
+/// Two nodes are equal if they have the same content, regardless of the order their children
+/// have been added in.
+#[derive(Debug, Default)]
 pub struct Node {
     /// Name of the node
     pub name: String,
@@ -39,9 +75,25 @@ pub struct Node {
     pub description: String,
     /// Type of the node
     pub kind: NodeType,
+    /// Name associated to the value of the field by the decode definition, if any
+    pub value_name: Option<String>,
     children: BTreeMap<String, Node>,
+    /// Names of the children in insertion order
+    order: Vec<String>,
 }
 
+impl PartialEq for Node {
+    fn eq(&self, other: &Node) -> bool {
+        self.name == other.name
+            && self.description == other.description
+            && self.kind == other.kind
+            && self.value_name == other.value_name
+            && self.children == other.children
+    }
+}
+
+impl Eq for Node {}
+
 impl Node {
     /// Returns a new root node.
     ///
@@ -114,6 +166,86 @@ impl Node {
         }
     }
 
+    /// Returns a new string field node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let node = Node::string("foo", "bar");
+    /// assert_eq!(node.kind, NodeType::String { value: "bar".into() });
+    /// assert_eq!(node.name, "foo");
+    /// ```
+    pub fn string(name: &str, value: &str) -> Node {
+        Node {
+            name: name.to_lowercase(),
+            kind: NodeType::String {
+                value: value.into(),
+            },
+            ..Node::default()
+        }
+    }
+
+    /// Returns a new signed field node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let node = Node::signed("foo", -42);
+    /// assert_eq!(node.kind, NodeType::Signed { value: -42 });
+    /// assert_eq!(node.name, "foo");
+    /// ```
+    pub fn signed(name: &str, value: i64) -> Node {
+        Node {
+            name: name.to_lowercase(),
+            kind: NodeType::Signed { value },
+            ..Node::default()
+        }
+    }
+
+    /// Returns a new boolean field node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let node = Node::boolean("foo", true);
+    /// assert_eq!(node.kind, NodeType::Bool { value: true });
+    /// assert_eq!(node.name, "foo");
+    /// ```
+    pub fn boolean(name: &str, value: bool) -> Node {
+        Node {
+            name: name.to_lowercase(),
+            kind: NodeType::Bool { value },
+            ..Node::default()
+        }
+    }
+
+    /// Returns a new byte array field node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let node = Node::bytes("foo", &[0x12, 0x34]);
+    /// assert_eq!(node.kind, NodeType::Bytes { value: vec![0x12, 0x34] });
+    /// assert_eq!(node.name, "foo");
+    /// ```
+    pub fn bytes(name: &str, value: &[u8]) -> Node {
+        Node {
+            name: name.to_lowercase(),
+            kind: NodeType::Bytes {
+                value: value.to_vec(),
+            },
+            ..Node::default()
+        }
+    }
+
     /// Returns a reference to a child of the node. If the child does not exist, [`None`] is
     /// returned.
     ///
@@ -171,8 +303,8 @@ impl Node {
     /// ```
     pub fn get_by_path(&self, path: &str) -> Option<&Node> {
         let mut ptr = self;
-        for name in path.split('.') {
-            ptr = ptr.get(name)?
+        for name in split_path(path) {
+            ptr = ptr.get(&name)?
         }
         Some(ptr)
     }
@@ -217,6 +349,139 @@ impl Node {
         self.get_by_path(path).and_then(|node| node.value())
     }
 
+    /// Returns the node stored at the given path.
+    ///
+    /// Unlike [`Node::get_by_path`], the returned error names the requested path alongside the
+    /// deepest ancestor that exists in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut foo = Node::section("foo");
+    /// foo.add(Node::field("bar", 42));
+    /// let mut root = Node::root();
+    /// root.add(foo);
+    ///
+    /// assert_eq!(root.try_get_by_path("foo.bar").unwrap().name, "bar");
+    /// assert!(matches!(
+    ///     root.try_get_by_path("foo.baz.qux"),
+    ///     Err(Error::MissingNode(path, ancestor)) if path == "foo.baz.qux" && ancestor == "foo"
+    /// ));
+    /// ```
+    pub fn try_get_by_path(&self, path: &str) -> Result<&Node, Error> {
+        let mut ptr = self;
+        let mut components = split_path(path);
+        loop {
+            // Length of the path of the current node, including the separator
+            let end = path.len() - components.rest.map_or(0, str::len);
+            let Some(name) = components.next() else {
+                break;
+            };
+            ptr = ptr.get(&name).ok_or_else(|| {
+                let ancestor = path[..end].strip_suffix('.').unwrap_or_default();
+                Error::MissingNode(String::from(path), String::from(ancestor))
+            })?;
+        }
+        Ok(ptr)
+    }
+
+    /// Returns the value of the field stored at the given path.
+    ///
+    /// Unlike [`Node::get_value_by_path`], the returned error reports the deepest ancestor of the
+    /// path that can be found, or whether the node is not a field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut foo = Node::section("foo");
+    /// foo.add(Node::field("bar", 42));
+    /// let mut root = Node::root();
+    /// root.add(foo);
+    ///
+    /// assert_eq!(root.try_get_value_by_path("foo.bar").unwrap(), 42);
+    /// assert!(matches!(root.try_get_value_by_path("foo"), Err(Error::InvalidNodeType(_))));
+    /// assert!(matches!(
+    ///     root.try_get_value_by_path("foo.baz.qux"),
+    ///     Err(Error::MissingNode(_, ancestor)) if ancestor == "foo"
+    /// ));
+    /// ```
+    pub fn try_get_value_by_path(&self, path: &str) -> Result<u64, Error> {
+        self.try_get_by_path(path)?
+            .value()
+            .ok_or_else(|| Error::InvalidNodeType(String::from(path)))
+    }
+
+    /// Returns the value of the field stored at the given path.
+    ///
+    /// This is equivalent to [`Node::try_get_value_by_path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut root = Node::root();
+    /// root.add(Node::field("status", 42));
+    ///
+    /// assert_eq!(root.get_u64("status").unwrap(), 42);
+    /// ```
+    pub fn get_u64(&self, path: &str) -> Result<u64, Error> {
+        self.try_get_value_by_path(path)
+    }
+
+    /// Returns the value of the boolean or single-bit field stored at the given path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut root = Node::root();
+    /// root.add(Node::field("valid", 1));
+    /// root.add(Node::boolean("enabled", true));
+    /// root.add(Node::field("status", 42));
+    ///
+    /// assert!(root.get_bool("valid").unwrap());
+    /// assert!(root.get_bool("enabled").unwrap());
+    /// assert!(matches!(root.get_bool("status"), Err(Error::InvalidNodeValue(_, 42))));
+    /// ```
+    pub fn get_bool(&self, path: &str) -> Result<bool, Error> {
+        if let Some(NodeType::Bool { value }) = self.get_by_path(path).map(|node| &node.kind) {
+            return Ok(*value);
+        }
+
+        match self.get_u64(path)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(Error::InvalidNodeValue(String::from(path), value)),
+        }
+    }
+
+    /// Returns the value of the string field stored at the given path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut root = Node::root();
+    /// root.add(Node::string("product", "XYZ"));
+    /// root.add(Node::field("status", 42));
+    ///
+    /// assert_eq!(root.get_str("product").unwrap(), "XYZ");
+    /// assert!(matches!(root.get_str("status"), Err(Error::InvalidNodeType(_))));
+    /// ```
+    pub fn get_str(&self, path: &str) -> Result<&str, Error> {
+        match &self.try_get_by_path(path)?.kind {
+            NodeType::String { value } => Ok(value),
+            _ => Err(Error::InvalidNodeType(String::from(path))),
+        }
+    }
+
     fn merge_instance(&mut self, mut other: Node) {
         let mut instance = 0;
         let name = other.name.clone();
@@ -228,9 +493,20 @@ impl Node {
     }
 
     pub fn merge(&mut self, other: Node) {
-        for (_, child) in other.children {
+        let mut children = other.children;
+        for name in other.order {
+            let Some(child) = children.remove(&name) else {
+                continue;
+            };
             if let Some(self_child) = self.children.get_mut(&child.name) {
-                if let NodeType::Record | NodeType::Field { .. } = self_child.kind {
+                if let NodeType::Record
+                | NodeType::Field { .. }
+                | NodeType::Signed { .. }
+                | NodeType::Bool { .. }
+                | NodeType::String { .. }
+                | NodeType::Bytes { .. }
+                | NodeType::Truncated = self_child.kind
+                {
                     self.merge_instance(child)
                 } else {
                     self_child.merge(child)
@@ -241,12 +517,39 @@ impl Node {
         }
     }
 
+    /// Merges the nodes decoded from the extended section of a record into the nodes decoded
+    /// from the record itself. Unlike [Node::merge], the records present in both trees are
+    /// merged into a single instance.
+    pub(crate) fn merge_extension(&mut self, other: Node) {
+        let mut children = other.children;
+        for name in other.order {
+            let Some(child) = children.remove(&name) else {
+                continue;
+            };
+            match self.children.get_mut(&child.name) {
+                Some(self_child)
+                    if self_child.kind == NodeType::Record && child.kind == NodeType::Record =>
+                {
+                    self_child.merge(child)
+                }
+                _ => {
+                    let mut rest = Node::root();
+                    rest.add(child);
+                    self.merge(rest)
+                }
+            }
+        }
+    }
+
     pub fn add(&mut self, node: Node) {
-        let _ = self.children.insert(node.name.clone(), node);
+        let name = node.name.clone();
+        if self.children.insert(name.clone(), node).is_none() {
+            self.order.push(name);
+        }
     }
 
     pub fn create_hierarchy(&mut self, path: &str) -> &mut Node {
-        self.create_hierarchy_from_iter(path.split("."))
+        self.create_hierarchy_from_iter(split_path(path))
     }
 
     pub(crate) fn create_hierarchy_from_iter<S, I>(&mut self, path: I) -> &mut Node
@@ -288,6 +591,416 @@ impl Node {
             iter: self.children.values(),
         }
     }
+
+    /// Returns the names and the children of the node in the given order.
+    #[cfg(feature = "serialize")]
+    fn ordered_children(&self, order: ChildOrder) -> Vec<(&str, &Node)> {
+        match order {
+            ChildOrder::Sorted => self
+                .children
+                .iter()
+                .map(|(name, child)| (name.as_str(), child))
+                .collect(),
+            ChildOrder::Insertion => self
+                .order
+                .iter()
+                .map(|name| (name.as_str(), &self.children[name]))
+                .collect(),
+        }
+    }
+
+    /// Returns a depth-first iterator over the descendants of the node. The iterator yields the
+    /// dot-separated path of each descendant relative to this node, along with the descendant.
+    /// The parents are yielded before their children, and the siblings are sorted alphabetically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut root = Node::root();
+    /// root.create_hierarchy("foo.bar").add(Node::field("baz", 42));
+    /// root.add(Node::field("qux", 1));
+    ///
+    /// let paths: Vec<String> = root.walk().map(|(path, _)| path).collect();
+    /// assert_eq!(paths, ["foo", "foo.bar", "foo.bar.baz", "qux"]);
+    ///
+    /// let fields: Vec<(String, u64)> = root
+    ///     .walk()
+    ///     .filter_map(|(path, node)| Some((path, node.value()?)))
+    ///     .collect();
+    /// assert_eq!(fields, [("foo.bar.baz".to_string(), 42), ("qux".to_string(), 1)]);
+    /// ```
+    pub fn walk(&self) -> NodeWalk<'_> {
+        let mut walk = NodeWalk { stack: Vec::new() };
+        walk.push_children("", self);
+        walk
+    }
+
+    /// Walks the descendants of the node in depth-first order and calls the `visitor` when
+    /// entering and leaving each of them. See [`NodeVisitor`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::node::NodeVisitor;
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// /// Counts the fields, without looking into the `raw` sections.
+    /// struct FieldCounter(usize);
+    ///
+    /// impl NodeVisitor for FieldCounter {
+    ///     fn enter(&mut self, _path: &str, node: &Node) -> bool {
+    ///         if let NodeType::Field { .. } = node.kind {
+    ///             self.0 += 1;
+    ///         }
+    ///         node.name != "raw"
+    ///     }
+    /// }
+    ///
+    /// let mut root = Node::root();
+    /// root.create_hierarchy("foo").add(Node::field("bar", 42));
+    /// root.create_hierarchy("raw").add(Node::field("baz", 1));
+    ///
+    /// let mut counter = FieldCounter(0);
+    /// root.visit(&mut counter);
+    /// assert_eq!(counter.0, 1);
+    /// ```
+    pub fn visit<V: NodeVisitor + ?Sized>(&self, visitor: &mut V) {
+        self.visit_children("", visitor);
+    }
+
+    fn visit_children<V: NodeVisitor + ?Sized>(&self, path: &str, visitor: &mut V) {
+        for child in self.children.values() {
+            let path = child_path(path, child);
+            if visitor.enter(&path, child) {
+                child.visit_children(&path, visitor);
+            }
+            visitor.leave(&path, child);
+        }
+    }
+
+    /// Keeps only the sub-trees located at the given `paths`, along with their ancestors. The
+    /// paths are relative to this node. The paths that do not exist in the tree are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut root = Node::root();
+    /// root.create_hierarchy("mca.bank0").add(Node::field("status", 1));
+    /// root.create_hierarchy("mca.bank1").add(Node::field("status", 2));
+    /// root.create_hierarchy("pmc").add(Node::field("status", 3));
+    ///
+    /// root.retain(&["mca.bank1", "pmc.does_not_exist"]);
+    /// let paths: Vec<String> = root.walk().map(|(path, _)| path).collect();
+    /// assert_eq!(paths, ["mca", "mca.bank1", "mca.bank1.status"]);
+    /// ```
+    pub fn retain<S: AsRef<str>>(&mut self, paths: &[S]) {
+        self.retain_paths("", paths)
+    }
+
+    fn retain_paths<S: AsRef<str>>(&mut self, path: &str, paths: &[S]) {
+        self.children.retain(|_, child| {
+            let path = child_path(path, child);
+            let prefix = format!("{path}.");
+            if paths.iter().any(|retained| retained.as_ref() == path) {
+                true
+            } else if paths
+                .iter()
+                .any(|retained| retained.as_ref().starts_with(&prefix))
+            {
+                child.retain_paths(&path, paths);
+                !child.children.is_empty()
+            } else {
+                false
+            }
+        });
+        self.order.retain(|name| self.children.contains_key(name));
+    }
+
+    /// Removes the descendants of the node for which the `predicate` returns `true`, along with
+    /// their own descendants. The `predicate` is called with the dot-separated path of each
+    /// descendant relative to this node, the parents first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut root = Node::root();
+    /// root.create_hierarchy("mca.bank0").add(Node::field("status", 1));
+    /// root.create_hierarchy("mca.bank0").add(Node::field("addr", 0xdead));
+    /// root.create_hierarchy("mca.bank1").add(Node::field("status", 0));
+    ///
+    /// // Redact the addresses and drop the empty banks.
+    /// root.prune(|path, node| {
+    ///     let empty = matches!(node.get_u64("status"), Ok(0));
+    ///     node.name == "addr" || (path.starts_with("mca.bank") && empty)
+    /// });
+    /// let paths: Vec<String> = root.walk().map(|(path, _)| path).collect();
+    /// assert_eq!(paths, ["mca", "mca.bank0", "mca.bank0.status"]);
+    /// ```
+    pub fn prune<F: FnMut(&str, &Node) -> bool>(&mut self, mut predicate: F) {
+        self.prune_children("", &mut predicate)
+    }
+
+    fn prune_children<F: FnMut(&str, &Node) -> bool>(&mut self, path: &str, predicate: &mut F) {
+        self.children.retain(|_, child| {
+            let path = child_path(path, child);
+            if predicate(&path, child) {
+                false
+            } else {
+                child.prune_children(&path, predicate);
+                true
+            }
+        });
+        self.order.retain(|name| self.children.contains_key(name));
+    }
+
+    /// Returns the value of the node formatted as in the JSON output, or [`None`] if the node is
+    /// not a field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// assert_eq!(Node::field("foo", 42).formatted_value().unwrap(), "0x2a");
+    /// assert_eq!(Node::section("bar").formatted_value(), None);
+    /// ```
+    pub fn formatted_value(&self) -> Option<String> {
+        match &self.kind {
+            NodeType::Field { value } => Some(format!("0x{value:x}")),
+            NodeType::Signed { value } => Some(format!("{value}")),
+            NodeType::Bool { value } => Some(format!("{value}")),
+            NodeType::String { value } => Some(value.clone()),
+            NodeType::Bytes { value } => Some(hex(value)),
+            NodeType::Truncated => Some("truncated".into()),
+            NodeType::Root | NodeType::Section | NodeType::Record => None,
+        }
+    }
+
+    /// Flattens the descendants of the node into a CSV document (RFC 4180).
+    ///
+    /// The document starts with a `path,value,description` header, followed by one row per
+    /// field, in the order of [`Node::walk`]. The path is the dot-separated path of the field
+    /// relative to this node, and the value is formatted as in the JSON output. The fields
+    /// expanded into children (bitfields) are listed before their children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut field = Node::field("baz", 42);
+    /// field.description = "Baz, the field".into();
+    /// let mut root = Node::root();
+    /// root.create_hierarchy("foo.bar").add(field);
+    /// root.add(Node::string("qux", "PMC"));
+    ///
+    /// assert_eq!(
+    ///     root.to_flat_csv(),
+    ///     "path,value,description\nfoo.bar.baz,0x2a,\"Baz, the field\"\nqux,PMC,\n"
+    /// );
+    /// ```
+    pub fn to_flat_csv(&self) -> String {
+        let mut csv = String::from("path,value,description\n");
+        for (path, node) in self.walk() {
+            let Some(value) = node.formatted_value() else {
+                continue;
+            };
+            let row = [path.as_str(), value.as_str(), node.description.as_str()].map(csv_field);
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// Quotes a CSV field if it contains a delimiter, a quote, or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.into()
+    }
+}
+
+/// Returns the path of a child node from the path of its parent, escaping the name of the child
+/// with [`escape_name`].
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::node;
+/// use intel_crashlog::prelude::*;
+///
+/// assert_eq!(node::child_path("", &Node::field("foo", 0)), "foo");
+/// assert_eq!(node::child_path("pmc", &Node::field("version.major", 0)), "pmc.version\\.major");
+/// ```
+pub fn child_path(path: &str, child: &Node) -> String {
+    if path.is_empty() {
+        escape_name(&child.name).into_owned()
+    } else {
+        format!("{path}.{}", escape_name(&child.name))
+    }
+}
+
+/// Escapes the dots and the backslashes of a node name, so that it can be used as a component of
+/// a path.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::node;
+///
+/// assert_eq!(node::escape_name("status"), "status");
+/// assert_eq!(node::escape_name("version.major"), "version\\.major");
+/// ```
+pub fn escape_name(name: &str) -> Cow<'_, str> {
+    if !name.contains(['.', '\\']) {
+        return Cow::Borrowed(name);
+    }
+
+    let mut escaped = String::with_capacity(name.len() + 1);
+    for c in name.chars() {
+        if c == '.' || c == '\\' {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    Cow::Owned(escaped)
+}
+
+/// Joins the names of nodes into a dot-separated path, escaping them with [`escape_name`].
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::node;
+///
+/// assert_eq!(node::join_path(&["pmc", "version.major"]), "pmc.version\\.major");
+/// ```
+pub fn join_path<S: AsRef<str>>(names: &[S]) -> String {
+    let mut path = String::new();
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            path.push('.');
+        }
+        path.push_str(&escape_name(name.as_ref()));
+    }
+    path
+}
+
+/// Splits a dot-separated path into the names of the nodes it consists of. The dots preceded by
+/// a backslash are part of the names (see [`escape_name`]).
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::node;
+///
+/// let names: Vec<_> = node::split_path("pmc.version\\.major").collect();
+/// assert_eq!(names, ["pmc", "version.major"]);
+/// ```
+pub fn split_path(path: &str) -> PathComponents<'_> {
+    PathComponents { rest: Some(path) }
+}
+
+/// An iterator over the unescaped names of the components of a path.
+///
+/// This struct is created by the [`split_path`] function.
+pub struct PathComponents<'a> {
+    /// Part of the path left to split, [`None`] once the last component has been yielded
+    rest: Option<&'a str>,
+}
+
+impl<'a> Iterator for PathComponents<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        let mut escaped = false;
+        let mut end = None;
+        for (i, c) in rest.char_indices() {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '.' {
+                end = Some(i);
+                break;
+            }
+        }
+
+        let name = match end {
+            Some(end) => {
+                self.rest = Some(&rest[end + 1..]);
+                &rest[..end]
+            }
+            None => {
+                self.rest = None;
+                rest
+            }
+        };
+
+        if !name.contains('\\') {
+            return Some(Cow::Borrowed(name));
+        }
+
+        let mut unescaped = String::with_capacity(name.len());
+        let mut chars = name.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => unescaped.push(chars.next().unwrap_or('\\')),
+                c => unescaped.push(c),
+            }
+        }
+        Some(Cow::Owned(unescaped))
+    }
+}
+
+/// Visitor of the nodes of a register tree.
+///
+/// See [`Node::visit`].
+pub trait NodeVisitor {
+    /// Called when entering a node, before its children are visited. The `path` of the node is
+    /// relative to the visited node. Returns `false` to skip the children of the node.
+    fn enter(&mut self, _path: &str, _node: &Node) -> bool {
+        true
+    }
+
+    /// Called when leaving a node, after its children have been visited.
+    fn leave(&mut self, _path: &str, _node: &Node) {}
+}
+
+/// Returns a reference to a child of the node.
+///
+/// # Panics
+///
+/// Panics if the child does not exist. Use [`Node::get`] to handle missing children.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::prelude::*;
+///
+/// let mut foo = Node::section("foo");
+/// foo.add(Node::field("bar", 42));
+/// let mut root = Node::root();
+/// root.add(foo);
+///
+/// assert_eq!(root["foo"]["bar"].value(), Some(42));
+/// ```
+impl Index<&str> for Node {
+    type Output = Node;
+
+    fn index(&self, name: &str) -> &Node {
+        self.get(name)
+            .unwrap_or_else(|| panic!("No child named {name} in node {}", self.name))
+    }
 }
 
 #[cfg(feature = "serialize")]
@@ -296,35 +1009,267 @@ impl Serialize for Node {
     where
         S: Serializer,
     {
-        match self.kind {
+        SerializedNode {
+            node: self,
+            descriptions: false,
+            order: ChildOrder::Sorted,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Serializable view of a [Node] tree that can include the descriptions of the nodes.
+///
+/// See [`Node::with_descriptions`] and [`Node::with_order`].
+#[cfg(feature = "serialize")]
+pub struct SerializedNode<'a> {
+    node: &'a Node,
+    descriptions: bool,
+    order: ChildOrder,
+}
+
+#[cfg(feature = "serialize")]
+impl Node {
+    /// Returns a serializable view of the node that includes the descriptions of the nodes.
+    ///
+    /// The nodes that have a description are serialized as a map storing the description in the
+    /// `_description` entry. The value of the fields is then stored in the `_value` entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut field = Node::field("bar", 42);
+    /// field.description = "Bar field".into();
+    /// let mut section = Node::section("foo");
+    /// section.add(field);
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(section.with_descriptions()).unwrap(),
+    ///     serde_json::json!({ "bar": { "_value": "0x2a", "_description": "Bar field" } })
+    /// );
+    /// assert_eq!(
+    ///     serde_json::to_value(&section).unwrap(),
+    ///     serde_json::json!({ "bar": "0x2a" })
+    /// );
+    /// ```
+    pub fn with_descriptions(&self) -> SerializedNode<'_> {
+        SerializedNode {
+            node: self,
+            descriptions: true,
+            order: ChildOrder::Sorted,
+        }
+    }
+
+    /// Returns a serializable view of the node that lists the children in the given order.
+    ///
+    /// The children are sorted alphabetically by default, which makes the output of the same
+    /// register tree identical regardless of the order the fields have been decoded in.
+    /// [`ChildOrder::Insertion`] preserves the order of the decode definitions instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::node::ChildOrder;
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut section = Node::section("foo");
+    /// section.add(Node::field("bar", 1));
+    /// section.add(Node::field("baz", 2));
+    /// section.add(Node::field("abc", 3));
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_string(&section.with_order(ChildOrder::Insertion)).unwrap(),
+    ///     r#"{"bar":"0x1","baz":"0x2","abc":"0x3"}"#
+    /// );
+    /// assert_eq!(
+    ///     serde_json::to_string(&section).unwrap(),
+    ///     r#"{"abc":"0x3","bar":"0x1","baz":"0x2"}"#
+    /// );
+    /// ```
+    pub fn with_order(&self, order: ChildOrder) -> SerializedNode<'_> {
+        SerializedNode {
+            node: self,
+            descriptions: false,
+            order,
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'a> SerializedNode<'a> {
+    /// Lists the children of the serialized nodes in the given order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::node::ChildOrder;
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut field = Node::field("bar", 42);
+    /// field.description = "Bar field".into();
+    /// let mut section = Node::section("foo");
+    /// section.add(field);
+    /// section.add(Node::field("abc", 1));
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_string(&section.with_descriptions().with_order(ChildOrder::Insertion))
+    ///         .unwrap(),
+    ///     r#"{"bar":{"_value":"0x2a","_description":"Bar field"},"abc":"0x1"}"#
+    /// );
+    /// ```
+    pub fn with_order(self, order: ChildOrder) -> SerializedNode<'a> {
+        SerializedNode { order, ..self }
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl Node {
+    /// Serializes the node and its children into a YAML document.
+    ///
+    /// The document has the same structure as the JSON representation of the node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut section = Node::section("foo");
+    /// section.add(Node::field("bar", 42));
+    ///
+    /// assert_eq!(section.to_yaml().unwrap(), "bar: '0x2a'\n");
+    /// ```
+    pub fn to_yaml(&self) -> Result<String, Error> {
+        Ok(serde_yaml_ng::to_string(self)?)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl SerializedNode<'_> {
+    fn child<'b>(&self, node: &'b Node) -> SerializedNode<'b> {
+        SerializedNode {
+            node,
+            descriptions: self.descriptions,
+            order: self.order,
+        }
+    }
+
+    fn description(&self) -> Option<&str> {
+        Some(self.node.description.as_str())
+            .filter(|description| self.descriptions && !description.is_empty())
+    }
+
+    /// Serializes the node as a map storing its value (if any), the optional entries, and its
+    /// children.
+    fn serialize_map<S, V>(&self, serializer: S, value: Option<V>) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        V: Serialize,
+    {
+        let node = self.node;
+        let len = node.children.len()
+            + value.is_some() as usize
+            + node.value_name.is_some() as usize
+            + self.description().is_some() as usize;
+        let mut map = serializer.serialize_map(Some(len))?;
+        if let Some(value) = value {
+            map.serialize_entry("_value", &value)?;
+        }
+        if let Some(name) = &node.value_name {
+            map.serialize_entry("_name", name)?;
+        }
+        if let Some(description) = self.description() {
+            map.serialize_entry("_description", description)?;
+        }
+        for (k, v) in node.ordered_children(self.order) {
+            map.serialize_entry(k, &self.child(v))?;
+        }
+        map.end()
+    }
+}
+
+/// Returns the hexadecimal representation of a little-endian array of bytes.
+fn hex(bytes: &[u8]) -> String {
+    let digits: String = bytes
+        .iter()
+        .rev()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    format!("0x{digits}")
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for SerializedNode<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.node;
+        let is_leaf =
+            node.children.is_empty() && node.value_name.is_none() && self.description().is_none();
+
+        match node.kind {
+            NodeType::Field { value } if is_leaf => {
+                serializer.serialize_str(&format!("0x{value:x}"))
+            }
             NodeType::Field { value } => {
-                if self.children.is_empty() {
-                    serializer.serialize_str(&format!("0x{value:x}"))
-                } else {
-                    let mut map = serializer.serialize_map(Some(self.children.len() + 1))?;
-                    map.serialize_entry("_value", &format!("0x{value:x}"))?;
-                    for (k, v) in self.children.iter() {
-                        map.serialize_entry(k, v)?;
-                    }
-                    map.end()
-                }
+                self.serialize_map(serializer, Some(format!("0x{value:x}")))
             }
+            NodeType::Signed { value } if is_leaf => serializer.serialize_str(&format!("{value}")),
+            NodeType::Signed { value } => self.serialize_map(serializer, Some(format!("{value}"))),
+            NodeType::Bool { value } if is_leaf => serializer.serialize_bool(value),
+            NodeType::Bool { value } => self.serialize_map(serializer, Some(value)),
+            NodeType::String { ref value } if is_leaf => serializer.serialize_str(value),
+            NodeType::String { ref value } => self.serialize_map(serializer, Some(value.clone())),
+            NodeType::Bytes { ref value } if is_leaf => serializer.serialize_str(&hex(value)),
+            NodeType::Bytes { ref value } => self.serialize_map(serializer, Some(hex(value))),
+            NodeType::Truncated if is_leaf => serializer.serialize_str("truncated"),
+            NodeType::Truncated => self.serialize_map(serializer, Some("truncated")),
             NodeType::Root => {
                 let mut map = serializer.serialize_map(Some(1))?;
-                map.serialize_entry("crashlog_data", &self.children)?;
-                map.end()
-            }
-            _ => {
-                let mut map = serializer.serialize_map(Some(self.children.len()))?;
-                for (k, v) in self.children.iter() {
-                    map.serialize_entry(k, v)?;
-                }
+                map.serialize_entry(
+                    "crashlog_data",
+                    &SerializedChildren {
+                        node,
+                        descriptions: self.descriptions,
+                        order: self.order,
+                    },
+                )?;
                 map.end()
             }
+            _ => self.serialize_map(serializer, None::<&str>),
         }
     }
 }
 
+/// Serializable view of the children of a [Node].
+#[cfg(feature = "serialize")]
+struct SerializedChildren<'a> {
+    node: &'a Node,
+    descriptions: bool,
+    order: ChildOrder,
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for SerializedChildren<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let parent = SerializedNode {
+            node: self.node,
+            descriptions: self.descriptions,
+            order: self.order,
+        };
+        let mut map = serializer.serialize_map(Some(self.node.children.len()))?;
+        for (k, v) in self.node.ordered_children(self.order) {
+            map.serialize_entry(k, &parent.child(v))?;
+        }
+        map.end()
+    }
+}
+
 /// An iterator over the children of a node.
 ///
 /// This struct is created by the [`children`] method on a [`Node`].
@@ -341,3 +1286,31 @@ impl<'a> Iterator for NodeChildren<'a> {
         self.iter.next()
     }
 }
+
+/// A depth-first iterator over the descendants of a node.
+///
+/// This struct is created by the [`walk`] method on a [`Node`].
+///
+/// [`walk`]: Node::walk
+pub struct NodeWalk<'a> {
+    /// Nodes left to visit along with their paths, the next one last
+    stack: Vec<(String, &'a Node)>,
+}
+
+impl<'a> NodeWalk<'a> {
+    fn push_children(&mut self, path: &str, node: &'a Node) {
+        for child in node.children.values().rev() {
+            self.stack.push((child_path(path, child), child));
+        }
+    }
+}
+
+impl<'a> Iterator for NodeWalk<'a> {
+    type Item = (String, &'a Node);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (path, node) = self.stack.pop()?;
+        self.push_children(&path, node);
+        Some((path, node))
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Offline dumps of the Crash Log regions exposed through Intel PMT.
+
+#[cfg(test)]
+mod tests;
+
+use crate::metadata::PmtEndpoint;
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// Crash Log endpoint of the Out-of-Band Management Services Module
+const CRASH_TYPE_OOBMSM: u8 = 1;
+
+// Status bits stored in the first dword of the discovery header
+/// The Crash Log collection is re-armed (version 2)
+const STATUS_REARMED: u32 = 1 << 25;
+/// An error occurred during the Crash Log collection (version 2)
+const STATUS_ERROR: u32 = 1 << 26;
+/// The Crash Log has been consumed (version 2)
+const STATUS_CONSUMED: u32 = 1 << 27;
+/// The Crash Log collection is disabled
+const STATUS_DISABLED: u32 = 1 << 28;
+/// The Crash Log collection has been triggered by software (version 0)
+const STATUS_TRIGGERED: u32 = 1 << 30;
+/// The Crash Log collection has completed
+const STATUS_COMPLETE: u32 = 1 << 31;
+/// Size of the discovery header in bytes
+pub const DISCOVERY_HEADER_SIZE: usize = 16;
+
+/// Discovery header of an Intel PMT Crash Log endpoint
+#[derive(Debug, Clone)]
+pub struct DiscoveryHeader {
+    /// Raw value of the first dword, which stores the capabilities and the status of the
+    /// endpoint
+    pub control: u32,
+    /// Method used to access the Crash Log region
+    pub access_type: u8,
+    /// Type of the Crash Log endpoint
+    pub crash_type: u8,
+    /// Version of the Crash Log endpoint
+    pub version: u8,
+    /// Identifies the layout of the Crash Log region
+    pub guid: u32,
+    /// Size of the Crash Log region in dwords
+    pub size: u32,
+}
+
+impl DiscoveryHeader {
+    pub fn from_slice(slice: &[u8]) -> Option<Self> {
+        let control = u32::from_le_bytes(slice.get(0..4)?.try_into().ok()?);
+
+        let header = DiscoveryHeader {
+            control,
+            access_type: (control & 0xF) as u8,
+            crash_type: ((control >> 4) & 0xF) as u8,
+            version: ((control >> 16) & 0xF) as u8,
+            guid: u32::from_le_bytes(slice.get(4..8)?.try_into().ok()?),
+            // The base offset of the region in the PMT MMIO space (bytes 8..12) is irrelevant
+            // in a dump, where the region directly follows its discovery header.
+            size: u32::from_le_bytes(slice.get(12..16)?.try_into().ok()?),
+        };
+
+        if header.crash_type != CRASH_TYPE_OOBMSM || header.size == 0 {
+            return None;
+        }
+
+        Some(header)
+    }
+
+    /// Returns the size of the Crash Log region in bytes.
+    pub fn region_size(&self) -> usize {
+        self.size as usize * 4
+    }
+
+    /// Decodes the state of the Crash Log endpoint from the status bits of the discovery header.
+    /// Only the status bits defined by the version of the endpoint are decoded.
+    pub fn endpoint(&self, name: String) -> PmtEndpoint {
+        let status = |bit: u32| Some(self.control & bit != 0);
+        let mut endpoint = PmtEndpoint {
+            name,
+            guid: Some(self.guid),
+            version: Some(self.version),
+            ..PmtEndpoint::default()
+        };
+
+        match self.version {
+            0 => {
+                endpoint.triggered = status(STATUS_TRIGGERED);
+            }
+            2 => {
+                endpoint.rearmed = status(STATUS_REARMED);
+                endpoint.error = status(STATUS_ERROR);
+                endpoint.consumed = status(STATUS_CONSUMED);
+            }
+            version => {
+                log::debug!("Unknown Crash Log endpoint version: {version}");
+                return endpoint;
+            }
+        }
+        endpoint.enabled = status(STATUS_DISABLED).map(|disabled| !disabled);
+        endpoint.dump_complete = status(STATUS_COMPLETE);
+        endpoint
+    }
+}
+
+/// Crash Log region copied from Intel PMT alongside its discovery header
+pub struct PmtDumpEntry {
+    pub header: DiscoveryHeader,
+    pub region: Vec<u8>,
+}
+
+/// Sequence of Crash Log regions copied from Intel PMT
+pub struct PmtDump {
+    pub entries: Vec<PmtDumpEntry>,
+}
+
+impl PmtDump {
+    /// Parses a sequence of discovery headers, each followed by the corresponding Crash Log
+    /// region.
+    ///
+    /// Returns `None` if the slice is not entirely covered by valid entries.
+    pub fn from_slice(s: &[u8]) -> Option<Self> {
+        let mut entries = Vec::new();
+        let mut cursor = 0;
+
+        while cursor < s.len() {
+            let header = DiscoveryHeader::from_slice(&s[cursor..])?;
+            let begin = cursor + DISCOVERY_HEADER_SIZE;
+            let end = begin + header.region_size();
+            let region = s.get(begin..end)?.to_vec();
+
+            log::debug!("Found PMT Crash Log region: guid={:#x}", header.guid);
+            entries.push(PmtDumpEntry { header, region });
+            cursor = end;
+        }
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        Some(PmtDump { entries })
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::{DiscoveryHeader, PmtDump};
+use crate::metadata::PmtTriggerCause;
+
+fn discovery_header(guid: u32, size: u32) -> Vec<u8> {
+    [0x10011u32, guid, 0, size]
+        .iter()
+        .flat_map(|dword| dword.to_le_bytes())
+        .collect()
+}
+
+#[test]
+fn discovery_header_from_slice() {
+    let header = DiscoveryHeader::from_slice(&discovery_header(0x1234, 0xd0)).unwrap();
+    assert_eq!(header.access_type, 1);
+    assert_eq!(header.crash_type, 1);
+    assert_eq!(header.version, 1);
+    assert_eq!(header.guid, 0x1234);
+    assert_eq!(header.region_size(), 0x340);
+}
+
+#[test]
+fn endpoint() {
+    let mut bytes = discovery_header(0x1234, 0xd0);
+    bytes[0..4].copy_from_slice(&0x8e020011u32.to_le_bytes());
+    let endpoint = DiscoveryHeader::from_slice(&bytes)
+        .unwrap()
+        .endpoint("crashlog0".into());
+    assert_eq!(endpoint.guid, Some(0x1234));
+    assert_eq!(endpoint.version, Some(2));
+    assert_eq!(endpoint.enabled, Some(true));
+    assert_eq!(endpoint.rearmed, Some(true));
+    assert_eq!(endpoint.error, Some(true));
+    assert_eq!(endpoint.consumed, Some(true));
+    assert_eq!(endpoint.dump_complete, Some(true));
+    assert_eq!(endpoint.triggered, None);
+
+    bytes[0..4].copy_from_slice(&0xd0000011u32.to_le_bytes());
+    let endpoint = DiscoveryHeader::from_slice(&bytes)
+        .unwrap()
+        .endpoint("crashlog0".into());
+    assert_eq!(endpoint.version, Some(0));
+    assert_eq!(endpoint.enabled, Some(false));
+    assert_eq!(endpoint.rearmed, None);
+    assert_eq!(endpoint.trigger_cause(), Some(PmtTriggerCause::Software));
+
+    // The status bits of the unknown versions are ignored.
+    let endpoint = DiscoveryHeader::from_slice(&discovery_header(0x1234, 0xd0))
+        .unwrap()
+        .endpoint("crashlog0".into());
+    assert_eq!(endpoint.version, Some(1));
+    assert_eq!(endpoint.enabled, None);
+}
+
+#[test]
+fn from_slice() {
+    let region = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let mut dump = discovery_header(0x1234, region.len() as u32 / 4);
+    dump.extend_from_slice(&region);
+    dump.extend_from_slice(&discovery_header(0x5678, region.len() as u32 / 4));
+    dump.extend_from_slice(&region);
+
+    let dump = PmtDump::from_slice(&dump).unwrap();
+    assert_eq!(dump.entries.len(), 2);
+    assert_eq!(dump.entries[1].header.guid, 0x5678);
+    assert_eq!(dump.entries[1].region, region);
+}
+
+#[test]
+fn truncated() {
+    let region = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let mut dump = discovery_header(0x1234, region.len() as u32 / 4 + 1);
+    dump.extend_from_slice(&region);
+    assert!(PmtDump::from_slice(&dump).is_none());
+}
+
+#[test]
+fn raw_region() {
+    let region = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    assert!(PmtDump::from_slice(&region).is_none());
+}
@@ -5,10 +5,23 @@
 
 mod core;
 mod decode;
+mod definition;
+mod encode;
+mod lazy;
+mod report;
+mod tor;
 
-use crate::header::Header;
+use crate::Error;
+use crate::header::{Header, RecordSize};
+use crate::node::Node;
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+pub use definition::{DecodeDefinition, DecodeDefinitionIssue};
+pub use lazy::LazyRecord;
+pub use report::{DecodeReport, DecodeWarning, RecordReport, RegionGap};
 
 /// A single Crash Log record
 #[derive(Default)]
@@ -26,18 +39,198 @@ pub struct Record {
 pub struct Context {
     /// Header of the parent record
     pub parent_header: Option<Header>,
+    /// Indicates that the payload of the record is compressed. The record headers do not define
+    /// a compression flag, so it must be set by the caller for the agents known to compress
+    /// their payloads.
+    pub payload_compressed: bool,
+    /// Indicates that the compressed payload of the record has been decompressed (see
+    /// [Record::decompressed])
+    pub decompressed: bool,
+}
+
+/// A decoder that handles the records of a given type without relying on the decode definitions
+/// stored in the collateral tree.
+///
+/// See [`crate::collateral::CollateralManager::register_decoder`].
+pub trait RecordDecoder {
+    /// Decodes the `record` into a [Node] tree. The children of the returned node are placed
+    /// under the root path of the record, like the nodes decoded from the decode definitions.
+    fn decode(&self, record: &Record) -> Result<Node, Error>;
+}
+
+/// Options controlling the decoding of the Crash Log records.
+///
+/// See [`crate::collateral::CollateralManager::set_decode_options`].
+#[derive(Clone, Debug, Default)]
+pub struct DecodeOptions {
+    /// Adds a `raw` node to the decoded records. The node contains the hexadecimal
+    /// representation of the byte ranges that are not covered by the decode definitions, indexed
+    /// by their offset in the record.
+    pub raw: bool,
+    /// Decodes the incomplete records (truncated data or collection not completed) in tolerant
+    /// mode: only the fields fully contained in the available data are decoded, and the other
+    /// fields are marked as [`crate::node::NodeType::Truncated`].
+    pub tolerant: bool,
+    /// Restricts the decoding to the records of the given types (e.g. `MCA`, `PCORE`). All the
+    /// record types are decoded if empty.
+    pub record_types: Vec<String>,
+    /// Restricts the decoding to the records generated by the sockets with the given IDs. All the
+    /// sockets are decoded if empty.
+    pub sockets: Vec<u8>,
+    /// Restricts the decoding to the records generated by the dies with the given IDs. All the
+    /// dies are decoded if empty.
+    pub dies: Vec<u8>,
+}
+
+impl DecodeOptions {
+    /// Returns `true` if the `record` passes the record type, socket, and die filters of the
+    /// options. The records stored in a Box record inherit the location of their parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeOptions;
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let record = Record {
+    ///     header: Header::from_slice(&data).unwrap().unwrap(),
+    ///     data,
+    ///     ..Record::default()
+    /// };
+    ///
+    /// let mut options = DecodeOptions::default();
+    /// assert!(options.selects(&record));
+    /// options.record_types = vec!["PCORE".into()];
+    /// assert!(!options.selects(&record));
+    /// options.record_types.push("mca".into());
+    /// assert!(options.selects(&record));
+    /// ```
+    pub fn selects(&self, record: &Record) -> bool {
+        let record_type = record.header.record_type().ok();
+        let (socket_id, die_id) = record.split_key(SplitKey::Die);
+
+        (self.record_types.is_empty()
+            || self.record_types.iter().any(|selected| {
+                record_type.is_some_and(|record_type| selected.eq_ignore_ascii_case(record_type))
+            }))
+            && (self.sockets.is_empty() || self.sockets.contains(&socket_id))
+            && (self.dies.is_empty() || die_id.is_some_and(|die_id| self.dies.contains(&die_id)))
+    }
+}
+
+/// Built-in keys used to sort the Crash Log records.
+///
+/// See [`crate::CrashLog::sort_records_by`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortKey {
+    /// Sorts the records by the timestamp stored in their header. Records that don't have any
+    /// timestamp are placed first.
+    Timestamp,
+    /// Sorts the records by socket ID, then by die ID. Records that don't have any die ID are
+    /// placed before the other records of the same socket.
+    SocketDie,
+    /// Sorts the records by record type.
+    RecordType,
+}
+
+/// Built-in keys used to split a Crash Log into several Crash Logs.
+///
+/// See [`crate::CrashLog::split_by`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitKey {
+    /// Groups the records by socket ID.
+    Socket,
+    /// Groups the records by socket ID and die ID.
+    Die,
 }
 
 impl Record {
+    /// Returns the payload of the record, located between the header and the checksum.
+    ///
+    /// The payload is returned as stored in the record, even if it is compressed (see
+    /// [Record::decompressed_payload]).
     pub fn payload(&self) -> &[u8] {
         let begin = self.header.header_size();
         let end = if self.header.version.cldic {
             // Checksum is present at the end of the record
-            self.data.len() - 4
+            self.data.len().saturating_sub(4)
         } else {
             self.data.len()
         };
-        &self.data[begin..end]
+        self.data.get(begin..end).unwrap_or_default()
+    }
+
+    /// Returns the payload of the record, decompressed if the context flags it as compressed (see
+    /// [Context::payload_compressed]). The payload is decompressed on each call.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [Record::decompressed].
+    pub fn decompressed_payload(&self) -> Result<Cow<'_, [u8]>, Error> {
+        if !self.context.payload_compressed || self.context.decompressed {
+            return Ok(Cow::Borrowed(self.payload()));
+        }
+        Ok(Cow::Owned(self.inflate_payload()?))
+    }
+
+    /// Returns a copy of the record whose compressed payload is replaced by its decompressed
+    /// content, or `None` if the payload of the record is not compressed.
+    ///
+    /// The raw header of the copy is left untouched and the checksum is dropped, so that the
+    /// offsets of the decode definitions apply to the decompressed payload. The payloads are
+    /// compressed as zlib streams (see [crate::compression::inflate]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidArchive] if the payload is not a valid zlib stream, and
+    /// [Error::CompressedPayload] if the `compression` feature is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let record = Record {
+    ///     header: Header::from_slice(&data).unwrap().unwrap(),
+    ///     data,
+    ///     ..Record::default()
+    /// };
+    /// assert!(record.decompressed().unwrap().is_none());
+    /// ```
+    pub fn decompressed(&self) -> Result<Option<Record>, Error> {
+        if !self.context.payload_compressed || self.context.decompressed {
+            return Ok(None);
+        }
+
+        let mut data = self
+            .data
+            .get(..self.header.header_size())
+            .ok_or(Error::InvalidHeader)?
+            .to_vec();
+        data.extend(self.inflate_payload()?);
+
+        let mut header = self.header.clone();
+        header.version.cldic = false;
+        Ok(Some(Record {
+            header,
+            data,
+            context: Context {
+                decompressed: true,
+                ..self.context.clone()
+            },
+        }))
+    }
+
+    #[cfg(feature = "compression")]
+    fn inflate_payload(&self) -> Result<Vec<u8>, Error> {
+        crate::compression::inflate(self.payload())
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn inflate_payload(&self) -> Result<Vec<u8>, Error> {
+        Err(Error::CompressedPayload)
     }
 
     pub fn checksum(&self) -> Option<bool> {
@@ -53,4 +246,163 @@ impl Record {
 
         Some(checksum == 0)
     }
+
+    /// Computes the value of the checksum dword (CLDIC) of the record: the value stored in the
+    /// last dword of the record that makes the sum of all the dwords of the record equal to zero.
+    ///
+    /// Returns [`None`] if the record does not have a checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    /// let mut region = Region::from_slice(&data).unwrap();
+    /// let record = &mut region.records[0];
+    ///
+    /// let checksum = record.compute_checksum().unwrap();
+    /// let end = record.data.len();
+    /// assert_eq!(record.data[end - 4..], checksum.to_le_bytes());
+    ///
+    /// // Patch the record and update its checksum
+    /// record.data[0x20] ^= 0xff;
+    /// assert_eq!(record.checksum(), Some(false));
+    /// assert!(record.update_checksum());
+    /// assert_eq!(record.checksum(), Some(true));
+    /// ```
+    pub fn compute_checksum(&self) -> Option<u32> {
+        if !self.header.version.cldic {
+            return None;
+        }
+
+        let end = self.data.len().checked_sub(4)?;
+        let sum = self.data[..end]
+            .chunks(4)
+            .map(|dword_slice| u32::from_le_bytes(dword_slice.try_into().unwrap_or([0; 4])))
+            .fold(0, |acc: u32, dword| acc.wrapping_add(dword));
+
+        Some(sum.wrapping_neg())
+    }
+
+    /// Updates the checksum dword (CLDIC) stored at the end of the record to match its content.
+    ///
+    /// Returns `false` if the record does not have a checksum.
+    pub fn update_checksum(&mut self) -> bool {
+        let Some(checksum) = self.compute_checksum() else {
+            return false;
+        };
+
+        let end = self.data.len();
+        self.data[end - 4..].copy_from_slice(&checksum.to_le_bytes());
+        true
+    }
+
+    /// Appends a checksum dword (CLDIC) to a record that does not have one, or updates the
+    /// existing one. The CLDIC bit of the record version and the size fields of the header are
+    /// updated accordingly.
+    ///
+    /// Returns `false`, and leaves the record unchanged, if the raw record is too short to store a
+    /// valid header or if its size cannot be encoded in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev2.crashlog").unwrap();
+    /// let mut region = Region::from_slice(&data).unwrap();
+    /// let record = &mut region.records[0];
+    /// assert_eq!(record.checksum(), None);
+    /// let len = record.data.len();
+    ///
+    /// assert!(record.add_checksum());
+    /// assert_eq!(record.checksum(), Some(true));
+    /// assert_eq!(record.data.len(), len + 4);
+    /// assert_eq!(record.header.record_size(), len + 4);
+    /// ```
+    pub fn add_checksum(&mut self) -> bool {
+        let mut record = Record {
+            header: self.header.clone(),
+            data: self.data.clone(),
+            context: self.context.clone(),
+        };
+
+        if !record.header.version.cldic {
+            let Some(version) = record.data.first_chunk_mut::<4>() else {
+                return false;
+            };
+            *version = (u32::from_le_bytes(*version) | 1 << 30).to_le_bytes();
+            record.header.version.cldic = true;
+            record.data.extend_from_slice(&[0; 4]);
+        }
+
+        if record.rebuild().is_err() {
+            return false;
+        }
+        record.update_checksum();
+        *self = record;
+        true
+    }
+
+    /// Updates the size fields of the record header to match the length of the raw record and
+    /// reloads the header from the raw record. The raw record is padded with zeros to the
+    /// granularity of the record size. The checksum of the record is left untouched, see
+    /// [Record::update_checksum].
+    ///
+    /// Returns [Error::InvalidHeader] if the raw record is too short to store a valid header, and
+    /// [Error::InvalidRecordSize] if its length cannot be encoded in the header. The raw record
+    /// is left untouched in both cases.
+    pub(crate) fn rebuild(&mut self) -> Result<(), Error> {
+        if self.data.len() < self.header.header_size() {
+            return Err(Error::InvalidHeader);
+        }
+
+        let granularity = self.header.record_size_granularity();
+        let size = self.data.len().div_ceil(granularity);
+        let extended_record_size = self.header.size.extended_record_size as usize;
+        let legacy = self.header.version.into_errata().type0_legacy_server;
+        let record_size = match size.checked_sub(extended_record_size) {
+            Some(record_size) if record_size <= 0xFFFF => record_size,
+            _ => {
+                log::warn!("Record size cannot be encoded: {}B", self.data.len());
+                return Err(Error::InvalidRecordSize(self.data.len()));
+            }
+        };
+
+        self.data.resize(size * granularity, 0);
+        if legacy {
+            self.data[16..18].copy_from_slice(&(record_size as u16).to_le_bytes());
+        } else {
+            let size = RecordSize {
+                record_size: record_size as u32,
+                extended_record_size: extended_record_size as u32,
+            };
+            size.write(&mut self.data[..self.header.header_size()]);
+        }
+
+        self.header = Header::from_slice(&self.data)?.ok_or(Error::InvalidHeader)?;
+        Ok(())
+    }
+
+    pub(crate) fn sort_key(&self, key: SortKey) -> (Option<u64>, Option<u64>) {
+        match key {
+            SortKey::Timestamp => (self.header.timestamp(), None),
+            SortKey::SocketDie => (
+                Some(self.header.socket_id().into()),
+                self.header.die_id().map(u64::from),
+            ),
+            SortKey::RecordType => (Some(self.header.version.record_type.into()), None),
+        }
+    }
+
+    pub(crate) fn split_key(&self, key: SplitKey) -> (u8, Option<u8>) {
+        // Records stored in a Box record follow their parent, so that the Box is never split
+        let header = self.context.parent_header.as_ref().unwrap_or(&self.header);
+
+        match key {
+            SplitKey::Socket => (header.socket_id(), None),
+            SplitKey::Die => (header.socket_id(), header.die_id()),
+        }
+    }
 }
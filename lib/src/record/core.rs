@@ -2,36 +2,75 @@
 // SPDX-License-Identifier: MIT
 
 use super::Record;
+#[cfg(feature = "collateral_manager")]
+use super::definition::DecodeDefinition;
 use crate::Error;
 #[cfg(feature = "collateral_manager")]
 use crate::collateral::{CollateralManager, CollateralTree};
 use crate::node::Node;
+#[cfg(all(not(feature = "std"), feature = "collateral_manager"))]
+use alloc::sync::Arc;
 #[cfg(not(feature = "std"))]
-use alloc::format;
+use alloc::{format, vec::Vec};
+#[cfg(feature = "collateral_manager")]
+use core::ops::Range;
+#[cfg(all(feature = "std", feature = "collateral_manager"))]
+use std::sync::Arc;
+
+/// Decode definitions of a core record
+#[cfg(feature = "collateral_manager")]
+pub(crate) struct CoreDecodeDefs {
+    /// Decode definitions of the subsections, in order of preference
+    subsections: Vec<(&'static str, Arc<DecodeDefinition>)>,
+    /// Decode definition of the extended section of the record
+    extension: Option<Arc<DecodeDefinition>>,
+}
 
 impl Record {
     #[cfg(feature = "collateral_manager")]
-    pub(super) fn decode_as_core_record<T: CollateralTree>(
+    pub(super) fn load_core_decode_defs<T: CollateralTree>(
         &self,
         cm: &mut CollateralManager<T>,
+    ) -> CoreDecodeDefs {
+        let subsections = ["thread", "core"]
+            .into_iter()
+            .filter_map(|subsection_name| {
+                let decode_def = format!("layout_{subsection_name}.csv");
+                let definition = self.load_decode_def(cm, &decode_def).ok()?;
+                Some((subsection_name, definition))
+            })
+            .collect();
+
+        let extension = self.load_extension_decode_def(cm, &["layout_sq.csv", "layout_module.csv"]);
+
+        CoreDecodeDefs {
+            subsections,
+            extension,
+        }
+    }
+
+    #[cfg(feature = "collateral_manager")]
+    pub(super) fn decode_as_core_record(
+        &self,
+        definitions: &CoreDecodeDefs,
+        covered: &mut Option<Vec<Range<usize>>>,
+        available: Option<usize>,
     ) -> Result<Node, Error> {
         let mut section = Node::section(self.header.record_type()?);
 
-        for subsection_name in ["thread", "core"] {
-            let decode_def = format!("layout_{subsection_name}.csv");
-            let Ok(mut root) = self.decode_with_decode_def(cm, &decode_def, 0) else {
-                continue;
-            };
+        for (subsection_name, definition) in definitions.subsections.iter() {
+            let mut tracked = covered.as_ref().map(|_| Vec::new());
+            let mut root = self.decode_and_track(definition, 0, &mut tracked, available);
 
-            if let Some(offset) = self.header.extended_record_offset() {
-                for decode_def in ["layout_sq.csv", "layout_module.csv"] {
-                    let Ok(extension) = self.decode_with_decode_def(cm, decode_def, offset) else {
-                        continue;
-                    };
-
-                    root.merge(extension);
-                    break;
-                }
+            if let (Some(offset), Some(extension)) =
+                (self.header.extended_record_offset(), &definitions.extension)
+            {
+                root.merge_extension(self.decode_and_track(
+                    extension,
+                    offset,
+                    &mut tracked,
+                    available,
+                ));
             }
 
             let Some(subsection) = root.get(subsection_name) else {
@@ -46,6 +85,9 @@ impl Record {
                 });
 
             section.create_hierarchy_from_iter(hierarchy).merge(root);
+            if let (Some(covered), Some(tracked)) = (covered.as_mut(), tracked) {
+                covered.extend(tracked);
+            }
 
             let mut root = Node::root();
             root.add(section);
@@ -3,25 +3,122 @@
 
 use super::Record;
 #[cfg(feature = "collateral_manager")]
-use crate::collateral::{CollateralManager, CollateralTree};
+use super::core::CoreDecodeDefs;
+use super::definition::{DecodeDefinition, DecodeDefinitionEntry, Endianness, FieldType};
+#[cfg(feature = "collateral_manager")]
+use super::{DecodeOptions, DecodeWarning};
+#[cfg(feature = "collateral_manager")]
+use crate::collateral::{CollateralManager, CollateralTree, ItemPath};
 use crate::error::Error;
 use crate::header::record_types;
 use crate::node::Node;
 use crate::node::NodeType;
 #[cfg(not(feature = "std"))]
-use alloc::{borrow::ToOwned, str, string::String, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
+#[cfg(all(not(feature = "std"), feature = "collateral_manager"))]
+use alloc::{sync::Arc, vec};
+#[cfg(feature = "collateral_manager")]
+use core::ops::Range;
 use log::debug;
-#[cfg(feature = "std")]
-use std::str;
+#[cfg(all(feature = "std", feature = "collateral_manager"))]
+use std::sync::Arc;
+
+/// Maximum number of nested includes in the decode definitions
+#[cfg(feature = "collateral_manager")]
+const MAX_INCLUDE_DEPTH: usize = 8;
 
-const DELIMITER: char = ';';
+/// Name of the generic decode definition of the extended section of the records
+#[cfg(feature = "collateral_manager")]
+const EXTENSION_DECODE_DEF: &str = "layout_ext.csv";
+
+/// Adds a child node for each bit of the bitfield. The names of the bits are taken from the
+/// decode definition if available.
+fn expand_bitfield(
+    node: &mut Node,
+    definition: &DecodeDefinition,
+    bitfield: &str,
+    value: u64,
+    size: usize,
+) {
+    if let Some(bits) = definition.bitfields.get(bitfield) {
+        for bit in bits.iter().filter(|bit| bit.bit < size) {
+            let mut child = Node::field(&bit.name, (value >> bit.bit) & 1);
+            child.description = bit.description.clone();
+            node.add(child);
+        }
+    } else {
+        debug!("No names defined for bitfield {bitfield}");
+        for bit in 0..size {
+            node.add(Node::field(&format!("bit{bit}"), (value >> bit) & 1));
+        }
+    }
+}
+
+/// Returns the record node of the decoded tree (first node below the root).
+#[cfg(feature = "collateral_manager")]
+fn record_node_mut(root: &mut Node) -> Option<&mut Node> {
+    let name = root.children().next()?.name.clone();
+    root.get_mut(&name)
+}
+
+/// Adds a node to the record node of the decoded tree.
+#[cfg(feature = "collateral_manager")]
+fn add_to_record(root: &mut Node, node: Node) {
+    if let Some(record) = record_node_mut(root) {
+        record.add(node);
+    } else {
+        root.add(node);
+    }
+}
+
+/// Adds the date of the record collection to the header node of the decoded tree.
+#[cfg(feature = "collateral_manager")]
+fn add_time(root: &mut Node, time: &str) {
+    if let Some(record) = record_node_mut(root) {
+        record
+            .create_hierarchy("hdr")
+            .add(Node::string("time", time));
+    }
+}
+
+/// Adds the names of the triggers of the record collection under the `reason` field of the header
+/// node of the decoded tree.
+#[cfg(feature = "collateral_manager")]
+fn add_reasons(root: &mut Node, reason: u32, names: &[&str]) {
+    if let Some(record) = record_node_mut(root) {
+        let node = record.create_hierarchy("hdr.reason");
+        node.kind = NodeType::Field {
+            value: reason as u64,
+        };
+        for name in names {
+            node.add(Node::boolean(name, true));
+        }
+    }
+}
+
+/// Decode definitions loaded from the collateral tree to decode a record.
+#[cfg(feature = "collateral_manager")]
+pub(crate) enum DecodePlan {
+    /// The record is decoded using a single decode definition, and its extended section using
+    /// the decode definition of the extension, if any.
+    Definition {
+        layout: Result<Arc<DecodeDefinition>, Error>,
+        extension: Option<Arc<DecodeDefinition>>,
+    },
+    /// The record is decoded as a core record.
+    Core(CoreDecodeDefs),
+    /// The record is decoded by a custom decoder registered in the collateral manager.
+    Decoder,
+    /// The payload of the record must be decompressed before the decode definitions are loaded.
+    Compressed,
+}
 
-#[derive(Default, Debug)]
-struct DecodeDefinitionEntry {
-    pub name: String,
-    pub offset: usize,
-    pub size: usize,
-    pub description: String,
+/// Decoded record, before the decode options and the post-decode hooks are applied.
+#[cfg(feature = "collateral_manager")]
+pub(crate) struct PartialDecode {
+    node: Result<Node, Error>,
+    /// Bits of the record covered by the decode definitions. Unknown for the custom decoders.
+    covered: Option<Vec<Range<usize>>>,
 }
 
 impl Record {
@@ -50,6 +147,75 @@ impl Record {
         Some(value)
     }
 
+    /// Reads a field of any size as an array of bytes, the least significant byte first.
+    fn read_bytes(&self, offset: usize, size: usize) -> Option<Vec<u8>> {
+        (0..size)
+            .step_by(8)
+            .map(|bit| {
+                self.read_field(offset + bit, (size - bit).min(8))
+                    .map(|byte| byte as u8)
+            })
+            .collect()
+    }
+
+    fn read_string(&self, offset: usize, size: usize) -> Option<String> {
+        let bytes = self.data.get(offset / 8..(offset + size) / 8)?;
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        Some(String::from_utf8_lossy(&bytes[..end]).into_owned())
+    }
+
+    /// Returns a node storing the hexadecimal representation of the byte ranges of the record
+    /// that are not covered by the `covered` bit ranges. Returns `None` if the whole record is
+    /// covered.
+    #[cfg(feature = "collateral_manager")]
+    fn raw_node(&self, covered: &mut [Range<usize>]) -> Option<Node> {
+        covered.sort_unstable_by_key(|range| range.start);
+
+        let width = format!("{:x}", self.data.len()).len();
+        let mut raw = Node::section("raw");
+        let mut add_range = |start: usize, end: usize| {
+            if let Some(bytes) = self.data.get(start..end).filter(|bytes| !bytes.is_empty()) {
+                let hex: Vec<String> = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+                raw.add(Node::string(&format!("0x{start:0width$x}"), &hex.join(" ")));
+            }
+        };
+
+        let mut position = 0;
+        for range in covered.iter() {
+            add_range(position, (range.start / 8).min(self.data.len()));
+            position = position.max(range.end.div_ceil(8));
+        }
+        add_range(position, self.data.len());
+
+        raw.children().next().is_some().then_some(raw)
+    }
+
+    /// Decodes the record using the decode definition and adds the bits covered by the fields of
+    /// the definition to `covered`. The fields that end after the `available` bits are marked
+    /// as truncated.
+    #[cfg(feature = "collateral_manager")]
+    pub(super) fn decode_and_track(
+        &self,
+        definition: &DecodeDefinition,
+        offset: usize,
+        covered: &mut Option<Vec<Range<usize>>>,
+        available: Option<usize>,
+    ) -> Node {
+        if let Some(covered) = covered {
+            covered.extend(definition.coverage(offset));
+        }
+        self.decode_section(definition, offset * 8, None, available)
+    }
+
+    /// Returns the number of bits available in the record if the record is incomplete and the
+    /// tolerant mode is enabled.
+    #[cfg(feature = "collateral_manager")]
+    fn available_bits(&self, options: &DecodeOptions) -> Option<usize> {
+        let incomplete = self.data.len() < self.header.record_size()
+            || self.header.collection_complete() == Some(false);
+        (options.tolerant && incomplete).then_some(self.data.len() * 8)
+    }
+
     /// Decodes a section of the [Record] located at the given `offset` into a [Node] tree using an
     /// arbitrary decode definition (`layout`).
     ///
@@ -57,11 +223,34 @@ impl Record {
     /// contain the following columns:
     /// - `name`:
     ///   Dot-separated path to the field in the decode output (example: `aaa.bbb.ccc`).
-    ///   The path can be relative to the previous entry (example: `..bar.baz`).
+    ///   The path can be relative to the previous entry (example: `..bar.baz`). The dots that
+    ///   are part of a name are escaped with a backslash (example: `aaa.version\.major`).
     /// - `offset`: offset of the field in the record in bits.
     /// - `size`: size of the field in bits.
     /// - `description`: description of the field.
     ///
+    /// The following columns are optional:
+    /// - `values`: comma-separated list of `value=NAME` pairs that associate a name to the values
+    ///   of the field (example: `0=OK,0x1=TIMEOUT`). The name is stored in [Node::value_name].
+    /// - `valid_if`: condition on the value of a previously decoded field, of the form
+    ///   `path==value` or `path!=value` (example: `hdr.whoami.core_type==1`). The path is
+    ///   relative to the record node. The field is only decoded if the condition is satisfied.
+    /// - `endianness`: byte order of the field, `little` (default) or `big`. The size of the
+    ///   big-endian fields must be a multiple of 8 bits.
+    /// - `type`: type of the field, `uint` (default), `int`, `bool`, or `string`. The `int`
+    ///   fields are sign-extended from their size. The `bool` fields are true if any of their
+    ///   bits is set. The string fields are decoded as UTF-8 up to the first nul character and
+    ///   must be aligned on bytes.
+    /// - `bitfield`: name of the bitfield used to expand the field into per-bit children. The
+    ///   names of the bits are provided by a companion definition (see
+    ///   [`DecodeDefinition::add_bitfields`]). If the bitfield is unknown, the children are named
+    ///   `bitN`. Numeric values only indicate that the field belongs to a register and don't
+    ///   expand the field.
+    ///
+    /// An entry named `include=<path>` includes the entries of another decode definition (see
+    /// [`DecodeDefinition::from_csv_with_includes`]). The includes are only supported when the
+    /// decode definition is read from the collateral tree.
+    ///
     /// # Examples
     ///
     /// ```
@@ -80,69 +269,185 @@ impl Record {
     /// let field = root.get_by_path("foo.bar").unwrap();
     /// assert_eq!(field.kind, NodeType::Field { value: 0x42 });
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip(self, layout))
+    )]
     pub fn decode_with_csv(&self, layout: &[u8], offset: usize) -> Result<Node, Error> {
+        Ok(self.decode_with_definition(&DecodeDefinition::from_csv(layout)?, offset))
+    }
+
+    /// Decodes a section of the [Record] located at the given `offset` into a [Node] tree using a
+    /// parsed decode definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeDefinition;
+    ///
+    /// let record = Record {
+    ///     header: Header::default(),
+    ///     data: vec![0x42],
+    ///     ..Record::default()
+    /// };
+    ///
+    /// let csv = "name;offset;size;description;bitfield
+    /// foo.bar;0;8;;0";
+    /// let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    ///
+    /// let root = record.decode_with_definition(&definition, 0);
+    /// assert_eq!(root.get_value_by_path("foo.bar"), Some(0x42));
+    /// ```
+    pub fn decode_with_definition(&self, definition: &DecodeDefinition, offset: usize) -> Node {
+        self.decode_with_definition_bits(definition, offset * 8, None)
+    }
+
+    /// Decodes a section of the [Record] located at the given `bit_offset` into a [Node] tree
+    /// using a parsed decode definition.
+    ///
+    /// Unlike [`Record::decode_with_definition`], the section does not need to be aligned on
+    /// bytes. When `bit_length` is set, only the fields fully contained in the first
+    /// `bit_length` bits of the section are decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeDefinition;
+    ///
+    /// let record = Record {
+    ///     header: Header::default(),
+    ///     data: vec![0x42, 0x01],
+    ///     ..Record::default()
+    /// };
+    ///
+    /// let csv = "name;offset;size;description
+    /// foo.bar;0;8;
+    /// foo.baz;8;4;";
+    /// let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    ///
+    /// let root = record.decode_with_definition_bits(&definition, 4, Some(8));
+    /// assert_eq!(root.get_value_by_path("foo.bar"), Some(0x14));
+    /// assert!(root.get_by_path("foo.baz").is_none());
+    /// ```
+    pub fn decode_with_definition_bits(
+        &self,
+        definition: &DecodeDefinition,
+        bit_offset: usize,
+        bit_length: Option<usize>,
+    ) -> Node {
+        self.decode_section(definition, bit_offset, bit_length, None)
+    }
+
+    fn decode_section(
+        &self,
+        definition: &DecodeDefinition,
+        bit_offset: usize,
+        bit_length: Option<usize>,
+        available: Option<usize>,
+    ) -> Node {
         let mut root = Node::root();
 
-        let csv = str::from_utf8(layout)?;
-        let mut columns = Vec::new();
-        let mut current_path = Vec::new();
+        for entry in definition.entries.iter() {
+            let top = &entry.path[0];
+            if entry.absolute && root.get(top).is_none() {
+                // Top-level is assumed to be the record name
+                root.add(Node::record(top));
+            }
 
-        for (i, line) in csv.lines().enumerate() {
-            if i == 0 {
-                columns = line.split(DELIMITER).collect();
-                debug!("CSV columns: {columns:?}");
+            if bit_length.is_some_and(|length| entry.offset + entry.size > length) {
+                debug!("Skipping {}: out of the section", entry.path.join("."));
                 continue;
             }
 
-            let mut entry = DecodeDefinitionEntry::default();
-
-            for (i, field) in line.split(DELIMITER).enumerate() {
-                if let Some(column) = columns.get(i) {
-                    match *column {
-                        "name" => entry.name = field.into(),
-                        "offset" => entry.offset = field.parse()?,
-                        "size" => entry.size = field.parse()?,
-                        "description" => entry.description = field.into(),
-                        _ => (),
-                    }
+            if let Some(condition) = &entry.valid_if {
+                let satisfied = root
+                    .get(top)
+                    .is_some_and(|record| condition.is_satisfied(record));
+                if !satisfied {
+                    debug!("Skipping {}: condition not satisfied", entry.path.join("."));
+                    continue;
                 }
             }
 
-            if entry.name.is_empty() {
+            let node = root.create_hierarchy_from_iter(&entry.path);
+            let end = bit_offset + entry.offset + entry.size;
+            if available.is_some_and(|available| end > available) {
+                // The wide entries that have children define sections, not fields.
+                if entry.size <= 64
+                    || entry.field_type == FieldType::String
+                    || !definition.has_children(entry)
+                {
+                    node.kind = NodeType::Truncated;
+                    node.description = entry.description.clone();
+                }
                 continue;
             }
 
-            let mut segments = entry.name.split(".");
-            let Some(top) = segments.next() else {
-                continue;
-            };
-
-            if !top.is_empty() {
-                // Absolute path
-                current_path.clear();
-                current_path.push(top.to_owned());
+            self.decode_entry(node, definition, entry, bit_offset);
+        }
+        root
+    }
 
-                if root.get(top).is_none() {
-                    // Top-level is assumed to be the record name
-                    root.add(Node::record(top));
+    /// Decodes the field described by a decode definition entry into `node`. The section
+    /// described by the decode definition is located at `bit_offset`.
+    pub(super) fn decode_entry(
+        &self,
+        node: &mut Node,
+        definition: &DecodeDefinition,
+        entry: &DecodeDefinitionEntry,
+        bit_offset: usize,
+    ) {
+        let field_offset = bit_offset + entry.offset;
+        match entry.field_type {
+            FieldType::Unsigned if entry.size > 64 => {
+                // The wide entries that have children define sections, not fields.
+                if !definition.has_children(entry)
+                    && let Some(mut value) = self.read_bytes(field_offset, entry.size)
+                {
+                    if entry.endianness == Endianness::Big {
+                        value.reverse();
+                    }
+                    node.kind = NodeType::Bytes { value };
                 }
             }
-
-            for segment in segments {
-                if segment.is_empty() {
-                    let _ = current_path.pop();
-                } else {
-                    current_path.push(segment.to_owned());
+            FieldType::Unsigned => {
+                if let Some(mut value) = self.read_field(field_offset, entry.size) {
+                    if entry.endianness == Endianness::Big && entry.size > 0 {
+                        value = value.swap_bytes() >> (64 - entry.size);
+                    }
+                    node.kind = NodeType::Field { value };
+                    node.value_name = entry.value_name(value);
+                    if let Some(bitfield) = &entry.bitfield {
+                        expand_bitfield(node, definition, bitfield, value, entry.size);
+                    }
                 }
             }
-
-            let node = root.create_hierarchy_from_iter(&current_path);
-            node.description = entry.description;
-            if let Some(value) = self.read_field(offset * 8 + entry.offset, entry.size) {
-                node.kind = NodeType::Field { value }
+            FieldType::Signed => {
+                if let Some(mut value) = self.read_field(field_offset, entry.size) {
+                    if entry.endianness == Endianness::Big {
+                        value = value.swap_bytes() >> (64 - entry.size);
+                    }
+                    node.value_name = entry.value_name(value);
+                    let shift = 64 - entry.size;
+                    node.kind = NodeType::Signed {
+                        value: ((value << shift) as i64) >> shift,
+                    };
+                }
+            }
+            FieldType::Bool => {
+                if let Some(value) = self.read_field(field_offset, entry.size) {
+                    node.kind = NodeType::Bool { value: value != 0 };
+                }
+            }
+            FieldType::String => {
+                if let Some(value) = self.read_string(field_offset, entry.size) {
+                    node.kind = NodeType::String { value };
+                }
             }
         }
-        Ok(root)
+        node.description = entry.description.clone();
     }
 
     /// Decodes the [Record] header into a [Node] tree.
@@ -162,7 +467,11 @@ impl Record {
 
     fn decode_header(&self) -> Node {
         let mut record = Node::record(self.header.record_type().unwrap_or("record"));
-        record.add(Node::from(&self.header));
+        let mut header = Node::from(&self.header);
+        if self.context.payload_compressed {
+            header.add(Node::field("payload_compressed", 1));
+        }
+        record.add(header);
 
         let mut root = Node::root();
         root.add(record);
@@ -201,51 +510,369 @@ impl Record {
 
     /// Decodes a section of the [Record] located at the given `offset` into a [Node] tree using
     /// an arbitrary decode definition stored in the collateral tree.
+    ///
+    /// The decode definitions included by the decode definition are resolved relative to its
+    /// directory in the collateral tree. The names of the bits of the bitfields are read from the
+    /// `bitfields.csv` item stored next to the decode definition, if present.
     #[cfg(feature = "collateral_manager")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, cm))
+    )]
     pub fn decode_with_decode_def<T: CollateralTree>(
         &self,
         cm: &mut CollateralManager<T>,
         decode_def: &str,
         offset: usize,
     ) -> Result<Node, Error> {
-        let paths = self.header.decode_definitions_paths(cm)?;
+        self.decode_with_decode_def_bits(cm, decode_def, offset * 8, None)
+    }
 
-        let mut root = Node::root();
+    /// Decodes a section of the [Record] located at the given `bit_offset` into a [Node] tree
+    /// using an arbitrary decode definition stored in the collateral tree.
+    ///
+    /// When `bit_length` is set, only the fields fully contained in the first `bit_length` bits
+    /// of the section are decoded. See [`Record::decode_with_definition_bits`].
+    #[cfg(feature = "collateral_manager")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, cm))
+    )]
+    pub fn decode_with_decode_def_bits<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+        decode_def: &str,
+        bit_offset: usize,
+        bit_length: Option<usize>,
+    ) -> Result<Node, Error> {
+        let definition = self.load_decode_def(cm, decode_def)?;
+        Ok(self.decode_with_definition_bits(&definition, bit_offset, bit_length))
+    }
 
-        for mut path in paths {
-            path.push(decode_def);
-            let Ok(layout) = cm.get_item_with_header(&self.header, path) else {
+    /// Loads a decode definition from the collateral tree, including its bitfields and the decode
+    /// definitions it includes. The resolver of the collateral manager is invoked if the decode
+    /// definition is missing.
+    #[cfg(feature = "collateral_manager")]
+    pub(super) fn load_decode_def<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+        decode_def: &str,
+    ) -> Result<Arc<DecodeDefinition>, Error> {
+        if let Some(definition) = cm.cached_definition(&self.header, decode_def) {
+            return Ok(definition);
+        }
+
+        let mut definition = self.find_decode_def(cm, decode_def);
+        if let Err(Error::MissingDecodeDefinitions(_)) = definition
+            && cm.resolve_decode_def(&self.header, decode_def)
+        {
+            definition = self.find_decode_def(cm, decode_def);
+        }
+
+        let definition = Arc::new(definition?);
+        cm.cache_definition(&self.header, decode_def, Arc::clone(&definition));
+        Ok(definition)
+    }
+
+    /// Loads the decode definition of the extended section of the record, if the record has an
+    /// extended section. The record-specific `candidates` are tried first, in order of
+    /// preference, then the generic `layout_ext.csv` decode definition.
+    #[cfg(feature = "collateral_manager")]
+    pub(super) fn load_extension_decode_def<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+        candidates: &[&str],
+    ) -> Option<Arc<DecodeDefinition>> {
+        self.header.extended_record_offset()?;
+        candidates
+            .iter()
+            .copied()
+            .chain([EXTENSION_DECODE_DEF])
+            .find_map(|decode_def| self.load_decode_def(cm, decode_def).ok())
+    }
+
+    /// Looks up a decode definition in the collateral tree, including its bitfields and the
+    /// decode definitions it includes.
+    #[cfg(feature = "collateral_manager")]
+    pub(super) fn find_decode_def<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+        decode_def: &str,
+    ) -> Result<DecodeDefinition, Error> {
+        let paths = self.header.decode_definitions_paths(cm)?;
+
+        for path in paths {
+            let Ok(layout) = cm.get_item_with_header(&self.header, path.join(decode_def)) else {
                 continue;
             };
-            root.merge(self.decode_with_csv(layout, offset)?);
-            return Ok(root);
+            let layout = layout.to_vec();
+            let mut definition =
+                DecodeDefinition::from_csv_with_includes(&layout, &mut |include| {
+                    self.load_included_decode_def(cm, path.join(include), 1)
+                })?;
+
+            if let Ok(bitfields) = cm.get_item_with_header(&self.header, path.join("bitfields.csv"))
+            {
+                definition.add_bitfields(bitfields)?;
+            }
+
+            return Ok(definition);
         }
 
         Err(Error::MissingDecodeDefinitions(self.header.version.clone()))
     }
 
+    /// Loads a decode definition included by another decode definition. The includes are
+    /// resolved relative to the directory of the including decode definition.
+    #[cfg(feature = "collateral_manager")]
+    fn load_included_decode_def<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+        path: ItemPath,
+        depth: usize,
+    ) -> Result<DecodeDefinition, Error> {
+        if depth > MAX_INCLUDE_DEPTH {
+            return Err(Error::InvalidDecodeDefinition(format!(
+                "too many nested includes while including {path}"
+            )));
+        }
+
+        let layout = cm
+            .get_item_with_header(&self.header, path.clone())?
+            .to_vec();
+        let dir = path.join("..");
+        DecodeDefinition::from_csv_with_includes(&layout, &mut |include| {
+            self.load_included_decode_def(cm, dir.join(include), depth + 1)
+        })
+    }
+
     /// Decodes the whole [Record] into a [Node] tree using the decode definitions stored in the
     /// collateral tree.
+    ///
+    /// The extended section of the records, whose size is given by the `extended_record_size`
+    /// header field, is decoded using the `layout_ext.csv` decode definition, if present. The
+    /// PCORE and ECORE records use `layout_sq.csv` or `layout_module.csv` instead when available.
+    ///
+    /// The Table of Requests (TOR) dump of the UNCORE records is decoded into a `tor` section
+    /// using the `tor.csv` decode definition, if present. See [`Record::decode_tor`].
     #[cfg(feature = "collateral_manager")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(record = %self.header))
+    )]
     pub fn decode<T: CollateralTree>(&self, cm: &mut CollateralManager<T>) -> Node {
+        self.decode_reporting(cm, &mut Vec::new())
+    }
+
+    /// Decodes the whole [Record] into a [Node] tree using the decode definitions stored in the
+    /// collateral tree, and reports the issues found in the record.
+    ///
+    /// Unlike [`Record::decode`], which only logs the decoding errors, this function returns
+    /// them along with the integrity issues of the record (truncated data, invalid checksum).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeWarning;
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// let record = Record {
+    ///     header: Header::default(),
+    ///     data: vec![0; 16],
+    ///     ..Record::default()
+    /// };
+    ///
+    /// let (_, warnings) = record.decode_with_report(&mut cm);
+    /// assert!(matches!(warnings[0], DecodeWarning::DecodeFailed(_)));
+    /// ```
+    #[cfg(feature = "collateral_manager")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(record = %self.header))
+    )]
+    pub fn decode_with_report<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+    ) -> (Node, Vec<DecodeWarning>) {
+        let mut warnings = Vec::new();
+        let root = self.decode_reporting(cm, &mut warnings);
+
+        let expected = self.header.record_size();
+        if self.data.len() < expected {
+            warnings.push(DecodeWarning::Truncated {
+                expected,
+                actual: self.data.len(),
+            });
+        }
+
+        if self.checksum() == Some(false) {
+            warnings.push(DecodeWarning::InvalidChecksum);
+        }
+
+        (root, warnings)
+    }
+
+    #[cfg(feature = "collateral_manager")]
+    fn decode_reporting<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+        warnings: &mut Vec<DecodeWarning>,
+    ) -> Node {
+        match self.decompressed() {
+            Ok(Some(record)) => return record.decode_reporting(cm, warnings),
+            Ok(None) => (),
+            Err(err) => {
+                let partial = PartialDecode {
+                    node: Err(err),
+                    covered: None,
+                };
+                return self.complete_decode(cm, partial, warnings);
+            }
+        }
+
+        let plan = self.plan_decode(cm);
+        let partial = self
+            .decode_planned(plan, cm.decode_options())
+            .unwrap_or_else(|| PartialDecode {
+                node: cm
+                    .decoder(&self.header)
+                    .map_or(Err(Error::InternalError), |decoder| decoder.decode(self)),
+                covered: None,
+            });
+        self.complete_decode(cm, partial, warnings)
+    }
+
+    /// Loads the decode definitions required to decode the whole [Record].
+    #[cfg(feature = "collateral_manager")]
+    pub(crate) fn plan_decode<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+    ) -> DecodePlan {
+        if self.context.payload_compressed && !self.context.decompressed {
+            return DecodePlan::Compressed;
+        }
+
         let is_core = ((self.header.version.record_type == record_types::PCORE)
             || (self.header.version.record_type == record_types::ECORE))
             && !self.header.version.into_errata().type0_legacy_server_box;
 
-        let record = if is_core {
-            self.decode_as_core_record(cm)
-        } else {
-            self.decode_with_decode_def(cm, "layout.csv", 0)
+        let layout_override = self
+            .header
+            .record_type()
+            .ok()
+            .and_then(|record_type| cm.layout_override(record_type));
+
+        let layout = match (layout_override, cm.decoder(&self.header)) {
+            (Some(layout), _) => DecodeDefinition::from_csv(layout).map(Arc::new),
+            (None, Some(_)) => return DecodePlan::Decoder,
+            (None, None) if is_core => return DecodePlan::Core(self.load_core_decode_defs(cm)),
+            (None, None) => self.load_decode_def(cm, "layout.csv"),
         };
+        DecodePlan::Definition {
+            layout,
+            extension: self.load_extension_decode_def(cm, &[]),
+        }
+    }
 
-        let record_node = match record {
+    /// Decodes the [Record] using the decode definitions loaded by [`Record::plan_decode`].
+    /// Returns `None` if the record must be decoded by a custom decoder or has a compressed
+    /// payload.
+    ///
+    /// The collateral manager is not needed at this stage, which allows the records to be
+    /// decoded concurrently.
+    #[cfg(feature = "collateral_manager")]
+    pub(crate) fn decode_planned(
+        &self,
+        plan: DecodePlan,
+        options: &DecodeOptions,
+    ) -> Option<PartialDecode> {
+        // Bits of the record covered by the decode definitions
+        let mut covered = Some(Vec::new());
+        let available = self.available_bits(options);
+        let node = match plan {
+            DecodePlan::Definition { layout, extension } => layout.map(|layout| {
+                let mut root = self.decode_and_track(&layout, 0, &mut covered, available);
+                if let (Some(offset), Some(extension)) =
+                    (self.header.extended_record_offset(), extension)
+                {
+                    root.merge_extension(self.decode_and_track(
+                        &extension,
+                        offset,
+                        &mut covered,
+                        available,
+                    ));
+                }
+                root
+            }),
+            DecodePlan::Core(definitions) => {
+                self.decode_as_core_record(&definitions, &mut covered, available)
+            }
+            DecodePlan::Decoder | DecodePlan::Compressed => return None,
+        };
+        Some(PartialDecode { node, covered })
+    }
+
+    /// Applies the decode options and the post-decode hooks to the decoded [Record], and places
+    /// it under its root path.
+    #[cfg(feature = "collateral_manager")]
+    pub(crate) fn complete_decode<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+        partial: PartialDecode,
+        warnings: &mut Vec<DecodeWarning>,
+    ) -> Node {
+        let mut covered = partial.covered;
+        let mut record_node = match partial.node {
             Ok(node) => node,
             Err(err) => {
                 log::warn!("Cannot decode record: {err}. Only the header fields will be decoded.");
+                warnings.push(DecodeWarning::DecodeFailed(err));
+                let header = 0..self.header.header_size() * 8;
+                covered = Some(vec![header]);
                 self.decode_header()
             }
         };
 
+        if self.header.version.record_type == record_types::UNCORE
+            && let Some((tor, dump)) = self.decode_tor_using_cm(cm)
+        {
+            add_to_record(&mut record_node, tor);
+            if let Some(covered) = covered.as_mut() {
+                covered.push(dump);
+            }
+        }
+
+        if cm.decode_options().raw
+            && let Some(mut covered) = covered
+            && let Some(raw) = self.raw_node(&mut covered)
+        {
+            add_to_record(&mut record_node, raw);
+        }
+
+        if let Some(time) = self.header.time(cm) {
+            add_time(&mut record_node, &time);
+        }
+
+        if self.context.payload_compressed
+            && let Some(record) = record_node_mut(&mut record_node)
+        {
+            record
+                .create_hierarchy("hdr")
+                .add(Node::field("payload_compressed", 1));
+        }
+
+        let reasons = self.header.reasons(cm);
+        if let Some(reason) = self.header.reason()
+            && !reasons.is_empty()
+        {
+            add_reasons(&mut record_node, reason, &reasons);
+        }
+
+        for hook in cm.post_decode_hooks() {
+            hook(&mut record_node, self);
+        }
+
         let mut root = Node::root();
         let record_root = if let Some(custom_root) = self.get_root_path_using_cm(cm) {
             root.create_hierarchy(&custom_root)
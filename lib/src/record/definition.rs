@@ -0,0 +1,696 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::error::Error;
+use crate::node::{self, Node};
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, format, str, string::String, vec::Vec};
+use core::ops::Range;
+#[cfg(not(feature = "std"))]
+use core::{fmt, mem};
+use log::debug;
+#[cfg(feature = "std")]
+use std::{collections::BTreeMap, fmt, mem, str};
+
+const DELIMITER: char = ';';
+
+/// A parsed decode definition describing the layout of the fields of a Crash Log record.
+///
+/// See [`crate::record::Record::decode_with_csv`] for the expected format.
+#[derive(Clone, Debug, Default)]
+pub struct DecodeDefinition {
+    pub(crate) entries: Vec<DecodeDefinitionEntry>,
+    /// Named bits of the bitfields, indexed by bitfield name
+    pub(crate) bitfields: BTreeMap<String, Vec<BitDefinition>>,
+}
+
+/// Name of a bit in a bitfield.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BitDefinition {
+    pub bit: usize,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DecodeDefinitionEntry {
+    /// Line of the entry in the decode definition
+    pub line: usize,
+    /// Absolute path to the field in the decode output
+    pub path: Vec<String>,
+    /// Indicates if the path of the entry is absolute in the decode definition
+    pub absolute: bool,
+    pub offset: usize,
+    pub size: usize,
+    pub description: String,
+    pub values: Vec<(u64, String)>,
+    pub valid_if: Option<Condition>,
+    pub endianness: Endianness,
+    pub field_type: FieldType,
+    /// Name of the bitfield used to expand the field into per-bit children
+    pub bitfield: Option<String>,
+}
+
+/// Type of the value stored in a field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum FieldType {
+    /// Unsigned integer
+    #[default]
+    Unsigned,
+    /// Signed integer (two's complement)
+    Signed,
+    /// Boolean, true if any bit of the field is set
+    Bool,
+    /// ASCII or UTF-8 string, terminated by the end of the field or by a nul character
+    String,
+}
+
+impl FieldType {
+    /// Parses the `type` column of the decode definitions.
+    fn parse(field: &str) -> Result<Self, Error> {
+        match field.trim() {
+            "" | "uint" => Ok(FieldType::Unsigned),
+            "int" => Ok(FieldType::Signed),
+            "bool" => Ok(FieldType::Bool),
+            "string" => Ok(FieldType::String),
+            other => Err(Error::InvalidDecodeDefinition(format!(
+                "invalid field type: {other}"
+            ))),
+        }
+    }
+}
+
+/// Byte order of a field in the record.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Endianness {
+    #[default]
+    Little,
+    Big,
+}
+
+impl Endianness {
+    /// Parses the `endianness` column of the decode definitions.
+    fn parse(field: &str) -> Result<Self, Error> {
+        match field.trim() {
+            "" | "little" | "le" => Ok(Endianness::Little),
+            "big" | "be" => Ok(Endianness::Big),
+            other => Err(Error::InvalidDecodeDefinition(format!(
+                "invalid endianness: {other}"
+            ))),
+        }
+    }
+}
+
+/// Condition that must be satisfied by the value of another field for a field to be decoded.
+#[derive(Clone, Debug)]
+pub(crate) struct Condition {
+    /// Path of the discriminator field relative to the record node
+    path: String,
+    value: u64,
+    equal: bool,
+}
+
+impl Condition {
+    /// Parses the `valid_if` column of the decode definitions. The column contains an expression
+    /// of the form `path==value` or `path!=value` (example: `hdr.whoami.core_type==1`).
+    fn parse(field: &str) -> Result<Option<Self>, Error> {
+        let field = field.trim();
+        if field.is_empty() {
+            return Ok(None);
+        }
+
+        let (path, value, equal) = if let Some((path, value)) = field.split_once("!=") {
+            (path, value, false)
+        } else if let Some((path, value)) = field.split_once("==") {
+            (path, value, true)
+        } else {
+            return Err(Error::InvalidDecodeDefinition(format!(
+                "invalid condition: {field}"
+            )));
+        };
+
+        Ok(Some(Condition {
+            path: path.trim().into(),
+            value: parse_u64(value.trim())?,
+            equal,
+        }))
+    }
+
+    /// Evaluates the condition on the record node. The condition is not satisfied if the
+    /// discriminator field has not been decoded.
+    pub fn is_satisfied(&self, record: &Node) -> bool {
+        self.is_satisfied_by(record.get_value_by_path(&self.path))
+    }
+
+    /// Evaluates the condition on the value of the discriminator field, if decoded.
+    pub fn is_satisfied_by(&self, value: Option<u64>) -> bool {
+        value.is_some_and(|value| (value == self.value) == self.equal)
+    }
+
+    /// Returns the path of the discriminator field relative to the record node.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// Parses an include directive (`include=<path>` in the `name` column). Returns the path of the
+/// included decode definition and the offset at which it must be included.
+fn parse_include<'a>(columns: &[&str], line: &'a str) -> Result<Option<(&'a str, usize)>, Error> {
+    let mut path = None;
+    let mut offset = "";
+
+    for (i, field) in line.split(DELIMITER).enumerate() {
+        match columns.get(i) {
+            Some(&"name") => path = field.trim().strip_prefix("include="),
+            Some(&"offset") => offset = field.trim(),
+            _ => (),
+        }
+    }
+
+    let Some(path) = path else {
+        return Ok(None);
+    };
+
+    let offset = if offset.is_empty() {
+        0
+    } else {
+        offset.parse()?
+    };
+    Ok(Some((path, offset)))
+}
+
+/// Parses the `bitfield` column of the decode definitions. The numeric values only indicate if
+/// the field belongs to a register and don't require the field to be expanded.
+fn parse_bitfield(field: &str) -> Option<String> {
+    let field = field.trim();
+    if field.is_empty() || field.chars().all(|c| c.is_ascii_digit()) {
+        None
+    } else {
+        Some(field.into())
+    }
+}
+
+fn parse_u64(value: &str) -> Result<u64, Error> {
+    Ok(if let Some(hex) = value.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16)?
+    } else {
+        value.parse()?
+    })
+}
+
+impl DecodeDefinitionEntry {
+    /// Parses a line of the decode definition. Returns `None` if the entry has no name.
+    fn parse(columns: &[&str], line: &str) -> Result<Option<Self>, Error> {
+        let mut entry = DecodeDefinitionEntry::default();
+        let mut name = "";
+
+        for (i, field) in line.split(DELIMITER).enumerate() {
+            if let Some(column) = columns.get(i) {
+                match *column {
+                    "name" => name = field,
+                    "offset" => entry.offset = field.parse()?,
+                    "size" => entry.size = field.parse()?,
+                    "description" => entry.description = field.into(),
+                    "values" => entry.values = DecodeDefinitionEntry::parse_values(field)?,
+                    "valid_if" => entry.valid_if = Condition::parse(field)?,
+                    "endianness" => entry.endianness = Endianness::parse(field)?,
+                    "type" => entry.field_type = FieldType::parse(field)?,
+                    "bitfield" => entry.bitfield = parse_bitfield(field),
+                    _ => (),
+                }
+            }
+        }
+
+        if name.is_empty() {
+            return Ok(None);
+        }
+
+        if entry.endianness == Endianness::Big && entry.size % 8 != 0 {
+            return Err(Error::InvalidDecodeDefinition(format!(
+                "the size of the big-endian field {name} is not a multiple of 8 bits"
+            )));
+        }
+
+        if matches!(entry.field_type, FieldType::Signed | FieldType::Bool)
+            && !(1..=64).contains(&entry.size)
+        {
+            return Err(Error::InvalidDecodeDefinition(format!(
+                "the size of the field {name} must be between 1 and 64 bits"
+            )));
+        }
+
+        if entry.field_type == FieldType::String && (entry.offset % 8 != 0 || entry.size % 8 != 0) {
+            return Err(Error::InvalidDecodeDefinition(format!(
+                "the string field {name} is not aligned on bytes"
+            )));
+        }
+
+        // The path is resolved by the caller.
+        entry.path = node::split_path(name)
+            .map(|name| name.into_owned())
+            .collect();
+        Ok(Some(entry))
+    }
+
+    /// Parses the `values` column of the decode definitions. The column contains a
+    /// comma-separated list of `value=NAME` pairs (example: `0=OK,1=TIMEOUT`).
+    fn parse_values(field: &str) -> Result<Vec<(u64, String)>, Error> {
+        field
+            .split(',')
+            .map(|pair| pair.trim())
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (value, name) = pair.split_once('=').ok_or_else(|| {
+                    Error::InvalidDecodeDefinition(format!("invalid named value: {pair}"))
+                })?;
+                Ok((parse_u64(value.trim())?, name.trim().into()))
+            })
+            .collect()
+    }
+
+    pub fn value_name(&self, value: u64) -> Option<String> {
+        self.values
+            .iter()
+            .find(|(v, _)| *v == value)
+            .map(|(_, name)| name.clone())
+    }
+}
+
+/// Issue found in a decode definition by [`DecodeDefinition::validate`].
+///
+/// The line numbers start at 1 and include the line that stores the names of the columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeDefinitionIssue {
+    /// The entry cannot be parsed.
+    Malformed { line: usize, reason: String },
+    /// The path of the field is already used by the entry located at the `previous` line.
+    DuplicatePath {
+        line: usize,
+        path: String,
+        previous: usize,
+    },
+    /// The field overlaps the field located at the `other` line.
+    Overlap {
+        line: usize,
+        path: String,
+        other: usize,
+    },
+    /// The field ends after the end of the record.
+    OutOfBounds {
+        line: usize,
+        path: String,
+        /// Bit following the last bit of the field
+        end_bit: usize,
+        /// Size of the record in bits
+        record_bits: usize,
+    },
+}
+
+impl fmt::Display for DecodeDefinitionIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeDefinitionIssue::Malformed { line, reason } => {
+                write!(f, "line {line}: malformed entry: {reason}")
+            }
+            DecodeDefinitionIssue::DuplicatePath {
+                line,
+                path,
+                previous,
+            } => write!(
+                f,
+                "line {line}: {path} is already defined at line {previous}"
+            ),
+            DecodeDefinitionIssue::Overlap { line, path, other } => {
+                write!(
+                    f,
+                    "line {line}: {path} overlaps the field defined at line {other}"
+                )
+            }
+            DecodeDefinitionIssue::OutOfBounds {
+                line,
+                path,
+                end_bit,
+                record_bits,
+            } => write!(
+                f,
+                "line {line}: {path} ends at bit {end_bit} but the record is {record_bits} bits \
+                 long"
+            ),
+        }
+    }
+}
+
+impl DecodeDefinition {
+    /// Parses a CSV-encoded decode definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::record::DecodeDefinition;
+    ///
+    /// let csv = "name;offset;size;description
+    /// foo.bar;0;8;";
+    /// assert!(DecodeDefinition::from_csv(csv.as_bytes()).is_ok());
+    ///
+    /// let csv = "name;offset;size;description
+    /// foo.bar;zero;8;";
+    /// assert!(DecodeDefinition::from_csv(csv.as_bytes()).is_err());
+    /// ```
+    pub fn from_csv(layout: &[u8]) -> Result<Self, Error> {
+        DecodeDefinition::from_csv_with_includes(layout, &mut |path| {
+            Err(Error::InvalidDecodeDefinition(format!(
+                "cannot resolve the included decode definition {path}"
+            )))
+        })
+    }
+
+    /// Parses a CSV-encoded decode definition that can include other decode definitions.
+    ///
+    /// An entry whose name is `include=<path>` is replaced by the entries of the decode
+    /// definition returned by `include` for this `path`. The offset column of the entry, if set,
+    /// is added to the offsets of the included fields. The entries that follow the include can
+    /// redefine the included fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeDefinition;
+    ///
+    /// let record = Record {
+    ///     header: Header::default(),
+    ///     data: vec![0x12, 0x34],
+    ///     ..Record::default()
+    /// };
+    ///
+    /// let common = "name;offset;size;description
+    /// foo.status;0;8;";
+    /// let csv = "name;offset;size;description
+    /// include=common.csv;8;;
+    /// foo.bar;0;8;";
+    ///
+    /// let definition = DecodeDefinition::from_csv_with_includes(csv.as_bytes(), &mut |path| {
+    ///     assert_eq!(path, "common.csv");
+    ///     DecodeDefinition::from_csv(common.as_bytes())
+    /// })
+    /// .unwrap();
+    ///
+    /// let root = record.decode_with_definition(&definition, 0);
+    /// assert_eq!(root.get_value_by_path("foo.status"), Some(0x34));
+    /// assert_eq!(root.get_value_by_path("foo.bar"), Some(0x12));
+    /// ```
+    pub fn from_csv_with_includes(
+        layout: &[u8],
+        include: &mut dyn FnMut(&str) -> Result<DecodeDefinition, Error>,
+    ) -> Result<Self, Error> {
+        DecodeDefinition::parse(str::from_utf8(layout)?, |_, err| Err(err), include)
+    }
+
+    /// Appends the entries of another decode definition, shifted by `offset` bits.
+    fn append(&mut self, other: DecodeDefinition, offset: usize) {
+        self.entries
+            .extend(other.entries.into_iter().map(|mut entry| {
+                entry.offset += offset;
+                entry
+            }));
+        for (name, mut bits) in other.bitfields {
+            self.bitfields.entry(name).or_default().append(&mut bits);
+        }
+    }
+
+    /// Returns `true` if other entries of the decode definition are located under the given
+    /// `entry` in the decode output.
+    pub(crate) fn has_children(&self, entry: &DecodeDefinitionEntry) -> bool {
+        self.entries
+            .iter()
+            .any(|other| other.path.len() > entry.path.len() && other.path.starts_with(&entry.path))
+    }
+
+    /// Returns the ranges of bits covered by the fields of the decode definition when the
+    /// definition is applied at the given `offset` (in bytes).
+    pub(crate) fn coverage(&self, offset: usize) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.entries
+            .iter()
+            .filter(|entry| entry.size > 0)
+            .map(move |entry| {
+                let start = offset * 8 + entry.offset;
+                start..start + entry.size
+            })
+    }
+
+    /// Adds the names of the bits of the bitfields referenced by the `bitfield` column of the
+    /// decode definition.
+    ///
+    /// The companion definition must be CSV-encoded, use semi-colons as delimiters, and contain
+    /// the following columns:
+    /// - `bitfield`: name of the bitfield.
+    /// - `bit`: index of the bit in the field.
+    /// - `name`: name of the bit in the decode output.
+    /// - `description`: description of the bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeDefinition;
+    ///
+    /// let record = Record {
+    ///     header: Header::default(),
+    ///     data: vec![0x05],
+    ///     ..Record::default()
+    /// };
+    ///
+    /// let csv = "name;offset;size;description;bitfield
+    /// foo.status;0;8;;status_bits";
+    /// let bitfields = "bitfield;bit;name;description
+    /// status_bits;0;valid;Valid bit
+    /// status_bits;1;overflow;Overflow bit";
+    ///
+    /// let mut definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    /// definition.add_bitfields(bitfields.as_bytes()).unwrap();
+    ///
+    /// let root = record.decode_with_definition(&definition, 0);
+    /// assert_eq!(root.get_value_by_path("foo.status"), Some(5));
+    /// assert_eq!(root.get_value_by_path("foo.status.valid"), Some(1));
+    /// assert_eq!(root.get_value_by_path("foo.status.overflow"), Some(0));
+    /// ```
+    pub fn add_bitfields(&mut self, csv: &[u8]) -> Result<(), Error> {
+        let csv = str::from_utf8(csv)?;
+        let mut columns = Vec::new();
+
+        for (i, line) in csv.lines().enumerate() {
+            if i == 0 {
+                columns = line.split(DELIMITER).collect();
+                continue;
+            }
+
+            let mut bitfield = "";
+            let mut bit = BitDefinition::default();
+            for (i, field) in line.split(DELIMITER).enumerate() {
+                if let Some(column) = columns.get(i) {
+                    match *column {
+                        "bitfield" => bitfield = field.trim(),
+                        "bit" => bit.bit = field.trim().parse()?,
+                        "name" => bit.name = field.trim().into(),
+                        "description" => bit.description = field.into(),
+                        _ => (),
+                    }
+                }
+            }
+
+            if bitfield.is_empty() || bit.name.is_empty() {
+                continue;
+            }
+
+            self.bitfields.entry(bitfield.into()).or_default().push(bit);
+        }
+
+        Ok(())
+    }
+
+    /// Parses the decode definition line by line. The errors are reported to `on_error`, which
+    /// decides whether the parsing must be aborted.
+    fn parse(
+        csv: &str,
+        mut on_error: impl FnMut(usize, Error) -> Result<(), Error>,
+        include: &mut dyn FnMut(&str) -> Result<DecodeDefinition, Error>,
+    ) -> Result<Self, Error> {
+        let mut definition = DecodeDefinition::default();
+        let mut columns = Vec::new();
+        let mut current_path: Vec<String> = Vec::new();
+
+        for (i, line) in csv.lines().enumerate() {
+            if i == 0 {
+                columns = line.split(DELIMITER).collect();
+                debug!("CSV columns: {columns:?}");
+                continue;
+            }
+
+            match parse_include(&columns, line) {
+                Ok(Some((path, offset))) => {
+                    match include(path) {
+                        Ok(included) => definition.append(included, offset),
+                        Err(err) => on_error(i + 1, err)?,
+                    }
+                    continue;
+                }
+                Ok(None) => (),
+                Err(err) => {
+                    on_error(i + 1, err)?;
+                    continue;
+                }
+            }
+
+            let mut entry = match DecodeDefinitionEntry::parse(&columns, line) {
+                Ok(Some(entry)) => entry,
+                Ok(None) => continue,
+                Err(err) => {
+                    on_error(i + 1, err)?;
+                    continue;
+                }
+            };
+
+            let mut segments = mem::take(&mut entry.path).into_iter();
+            let Some(top) = segments.next() else {
+                continue;
+            };
+
+            if !top.is_empty() {
+                // Absolute path
+                current_path.clear();
+                current_path.push(top);
+                entry.absolute = true;
+            }
+
+            for segment in segments {
+                if segment.is_empty() {
+                    let _ = current_path.pop();
+                } else {
+                    current_path.push(segment);
+                }
+            }
+
+            if current_path.is_empty() {
+                continue;
+            }
+
+            entry.line = i + 1;
+            entry.path = current_path.clone();
+            definition.entries.push(entry);
+        }
+
+        Ok(definition)
+    }
+
+    /// Parses a CSV-encoded decode definition and reports the issues found in it: malformed
+    /// entries, duplicate paths, overlapping fields, and fields that exceed the size of the
+    /// record (`record_size`, in bytes).
+    ///
+    /// Fields are not reported as overlapping when one of them is the parent of the other or when
+    /// one of them is conditional (`valid_if` column).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::record::{DecodeDefinition, DecodeDefinitionIssue};
+    ///
+    /// let csv = "name;offset;size;description
+    /// foo.bar;0;8;
+    /// foo.baz;4;8;
+    /// foo.bar;16;8;
+    /// foo.qux;24;16;";
+    ///
+    /// let issues = DecodeDefinition::validate(csv.as_bytes(), Some(4));
+    /// assert_eq!(issues, vec![
+    ///     DecodeDefinitionIssue::DuplicatePath { line: 4, path: "foo.bar".into(), previous: 2 },
+    ///     DecodeDefinitionIssue::OutOfBounds {
+    ///         line: 5, path: "foo.qux".into(), end_bit: 40, record_bits: 32,
+    ///     },
+    ///     DecodeDefinitionIssue::Overlap { line: 3, path: "foo.baz".into(), other: 2 },
+    /// ]);
+    /// ```
+    pub fn validate(layout: &[u8], record_size: Option<usize>) -> Vec<DecodeDefinitionIssue> {
+        let mut issues = Vec::new();
+
+        let csv = match str::from_utf8(layout) {
+            Ok(csv) => csv,
+            Err(err) => {
+                issues.push(DecodeDefinitionIssue::Malformed {
+                    line: 0,
+                    reason: format!("{err}"),
+                });
+                return issues;
+            }
+        };
+
+        // The included decode definitions are validated separately.
+        let Ok(definition) = DecodeDefinition::parse(
+            csv,
+            |line, err| {
+                issues.push(DecodeDefinitionIssue::Malformed {
+                    line,
+                    reason: format!("{err}"),
+                });
+                Ok(())
+            },
+            &mut |_| Ok(DecodeDefinition::default()),
+        ) else {
+            return issues;
+        };
+
+        let mut paths: Vec<(String, &DecodeDefinitionEntry)> = Vec::new();
+        for entry in definition.entries.iter() {
+            let path = node::join_path(&entry.path);
+
+            if entry.valid_if.is_none()
+                && let Some((_, previous)) = paths
+                    .iter()
+                    .find(|(other, previous)| *other == path && previous.valid_if.is_none())
+            {
+                issues.push(DecodeDefinitionIssue::DuplicatePath {
+                    line: entry.line,
+                    path: path.clone(),
+                    previous: previous.line,
+                });
+            }
+
+            if let Some(record_size) = record_size
+                && entry.offset + entry.size > record_size * 8
+            {
+                issues.push(DecodeDefinitionIssue::OutOfBounds {
+                    line: entry.line,
+                    path: path.clone(),
+                    end_bit: entry.offset + entry.size,
+                    record_bits: record_size * 8,
+                });
+            }
+
+            paths.push((path, entry));
+        }
+
+        let mut fields: Vec<&(String, &DecodeDefinitionEntry)> = paths
+            .iter()
+            .filter(|(_, entry)| entry.size > 0 && entry.valid_if.is_none())
+            .collect();
+        fields.sort_by_key(|(_, entry)| (entry.offset, entry.line));
+
+        let mut active: Vec<&DecodeDefinitionEntry> = Vec::new();
+        for (path, entry) in fields {
+            active.retain(|other| other.offset + other.size > entry.offset);
+            for other in active.iter() {
+                let related =
+                    entry.path.starts_with(&other.path) || other.path.starts_with(&entry.path);
+                if !related {
+                    issues.push(DecodeDefinitionIssue::Overlap {
+                        line: entry.line,
+                        path: path.clone(),
+                        other: other.line,
+                    });
+                }
+            }
+            active.push(*entry);
+        }
+
+        issues
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::definition::{DecodeDefinition, DecodeDefinitionEntry, Endianness, FieldType};
+use crate::error::Error;
+use crate::node::{self, Node, NodeType};
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+/// Writes the `size` least significant bits of `value` at the given bit `offset`.
+fn write_field(data: &mut [u8], offset: usize, size: usize, value: u64) {
+    let mut bit = 0;
+
+    while bit < size {
+        let chunk_size = 8;
+        let chunk = (offset + bit) / chunk_size;
+        let bit_offset = (offset + bit) % chunk_size;
+        let len = (size - bit).min(chunk_size - bit_offset);
+        let mask = (((1u16 << len) - 1) as u8) << bit_offset;
+        data[chunk] = (data[chunk] & !mask) | ((((value >> bit) as u8) << bit_offset) & mask);
+        bit += len;
+    }
+}
+
+impl DecodeDefinition {
+    /// Returns the size in bytes of the section described by the decode definition.
+    fn encoded_size(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|entry| (entry.offset + entry.size).div_ceil(8))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Encodes a [Node] tree into the raw content of a record. This is the reverse operation of
+    /// [`crate::record::Record::decode_with_definition`].
+    ///
+    /// The bits that are not covered by the fields of the `root` tree are set to zero. See
+    /// [`DecodeDefinition::encode_into`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeDefinition;
+    ///
+    /// let csv = "name;offset;size;description;endianness
+    /// foo.bar;0;4;;
+    /// foo.baz;4;12;;
+    /// foo.be;16;16;;big";
+    /// let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    ///
+    /// let record = Record {
+    ///     data: vec![0x21, 0x43, 0x12, 0x34],
+    ///     ..Record::default()
+    /// };
+    /// let root = record.decode_with_definition(&definition, 0);
+    /// assert_eq!(definition.encode(&root).unwrap(), record.data);
+    /// ```
+    pub fn encode(&self, root: &Node) -> Result<Vec<u8>, Error> {
+        let mut data = vec![0; self.encoded_size()];
+        self.encode_into(root, &mut data, 0)?;
+        Ok(data)
+    }
+
+    /// Writes the fields of a [Node] tree into the raw content of a record (`data`), in the
+    /// section located at the given `offset` (in bytes). The `data` is extended if it is too
+    /// short to store the fields.
+    ///
+    /// The fields are written in the order of the decode definition: the subfields take
+    /// precedence over their parent fields. The fields missing from the tree, the fields whose
+    /// condition is not satisfied, and the sections wider than 64 bits are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeDefinition;
+    ///
+    /// let csv = "name;offset;size;description
+    /// foo.bar;0;8;
+    /// foo.baz;8;8;";
+    /// let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    ///
+    /// let mut root = Node::root();
+    /// root.create_hierarchy("foo.baz").kind = NodeType::Field { value: 0x42 };
+    ///
+    /// let mut data = vec![0x11, 0x22, 0x33];
+    /// definition.encode_into(&root, &mut data, 1).unwrap();
+    /// assert_eq!(data, [0x11, 0x22, 0x42]);
+    /// ```
+    pub fn encode_into(&self, root: &Node, data: &mut Vec<u8>, offset: usize) -> Result<(), Error> {
+        let size = offset + self.encoded_size();
+        if data.len() < size {
+            data.resize(size, 0);
+        }
+
+        for entry in self.entries.iter() {
+            let path = node::join_path(&entry.path);
+            let Some(node) = root.get_by_path(&path) else {
+                continue;
+            };
+
+            if let Some(condition) = &entry.valid_if {
+                let satisfied = root
+                    .get(&entry.path[0])
+                    .is_some_and(|record| condition.is_satisfied(record));
+                if !satisfied {
+                    continue;
+                }
+            }
+
+            encode_entry(data, entry, node, offset * 8 + entry.offset, &path)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn encode_entry(
+    data: &mut [u8],
+    entry: &DecodeDefinitionEntry,
+    node: &Node,
+    offset: usize,
+    path: &str,
+) -> Result<(), Error> {
+    match (entry.field_type, &node.kind) {
+        (FieldType::Unsigned, NodeType::Field { value }) => {
+            let value = *value;
+            if entry.size > 64 {
+                return Ok(());
+            }
+            if entry.size < 64 && value >> entry.size != 0 {
+                return Err(Error::InvalidNodeValue(path.into(), value));
+            }
+            write_integer(data, entry, offset, value);
+        }
+        (FieldType::Signed, NodeType::Signed { value }) => {
+            // The bits above the sign bit must all be copies of it.
+            if !matches!(value >> (entry.size - 1), 0 | -1) {
+                return Err(Error::InvalidNodeValue(path.into(), *value as u64));
+            }
+            let value = *value as u64;
+            let value = if entry.size < 64 {
+                value & ((1 << entry.size) - 1)
+            } else {
+                value
+            };
+            write_integer(data, entry, offset, value);
+        }
+        (FieldType::Bool, NodeType::Bool { value }) => {
+            write_integer(data, entry, offset, *value as u64);
+        }
+        (FieldType::Unsigned, NodeType::Bytes { value }) => {
+            if value.len() != entry.size.div_ceil(8) {
+                return Err(Error::InvalidNodeValue(path.into(), value.len() as u64));
+            }
+
+            let mut bytes = value.clone();
+            if entry.endianness == Endianness::Big {
+                bytes.reverse();
+            }
+            for (i, byte) in bytes.into_iter().enumerate() {
+                let bit = i * 8;
+                write_field(data, offset + bit, (entry.size - bit).min(8), byte as u64);
+            }
+        }
+        (FieldType::String, NodeType::String { value }) => {
+            let bytes = &mut data[offset / 8..(offset + entry.size) / 8];
+            let len = value.len().min(bytes.len());
+            bytes.fill(0);
+            bytes[..len].copy_from_slice(&value.as_bytes()[..len]);
+        }
+        // Sections and records defined in the decode definition
+        (_, NodeType::Section | NodeType::Record) => (),
+        // Fields missing from a truncated record
+        (_, NodeType::Truncated) => (),
+        _ => return Err(Error::InvalidNodeType(path.into())),
+    }
+
+    Ok(())
+}
+
+/// Writes an integer of at most 64 bits in the byte order of the entry.
+fn write_integer(data: &mut [u8], entry: &DecodeDefinitionEntry, offset: usize, value: u64) {
+    let raw = if entry.endianness == Endianness::Big && entry.size > 0 {
+        (value << (64 - entry.size)).swap_bytes()
+    } else {
+        value
+    };
+    write_field(data, offset, entry.size, raw);
+}
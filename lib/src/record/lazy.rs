@@ -0,0 +1,173 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::Record;
+use super::definition::DecodeDefinition;
+#[cfg(feature = "collateral_manager")]
+use crate::collateral::{CollateralManager, CollateralTree};
+#[cfg(feature = "collateral_manager")]
+use crate::error::Error;
+use crate::node::{self, Node, split_path};
+#[cfg(all(not(feature = "std"), feature = "collateral_manager"))]
+use alloc::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+use core::mem;
+use core::ops::Bound;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(all(feature = "std", feature = "collateral_manager"))]
+use std::sync::Arc;
+
+/// A [Record] whose fields are decoded on access.
+///
+/// Only the fields requested through [`LazyRecord::get`] are decoded, which avoids materializing
+/// the whole [Node] tree of the large records when only a few fields are needed.
+pub struct LazyRecord<'a> {
+    record: &'a Record,
+    definition: DecodeDefinition,
+    offset: usize,
+    /// Indices of the decode definition entries, indexed by the path of the fields
+    index: BTreeMap<String, Vec<usize>>,
+}
+
+impl<'a> LazyRecord<'a> {
+    /// Creates a lazily decoded view of the section of the `record` located at the given `offset`
+    /// using a parsed decode definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::{DecodeDefinition, LazyRecord};
+    ///
+    /// let record = Record {
+    ///     header: Header::default(),
+    ///     data: vec![0x42, 0x01],
+    ///     ..Record::default()
+    /// };
+    ///
+    /// let csv = "name;offset;size;description
+    /// foo.bar;0;8;
+    /// foo.baz;8;8;";
+    /// let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    ///
+    /// let lazy = LazyRecord::new(&record, definition, 0);
+    /// assert_eq!(lazy.get_value("foo.bar"), Some(0x42));
+    /// assert_eq!(lazy.get("foo").unwrap().children().count(), 2);
+    /// assert!(lazy.get("foo.qux").is_none());
+    /// ```
+    pub fn new(record: &'a Record, definition: DecodeDefinition, offset: usize) -> Self {
+        let mut index: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (i, entry) in definition.entries.iter().enumerate() {
+            index
+                .entry(node::join_path(&entry.path))
+                .or_default()
+                .push(i);
+        }
+
+        LazyRecord {
+            record,
+            definition,
+            offset,
+            index,
+        }
+    }
+
+    /// Decodes the node located at the given `path` (example: `mca.bank3.status`), including
+    /// its children. Returns [`None`] if the decode definition does not define the node.
+    pub fn get(&self, path: &str) -> Option<Node> {
+        self.decode(path, self.definition.entries.len())
+    }
+
+    /// Decodes the value of the field located at the given `path`.
+    pub fn get_value(&self, path: &str) -> Option<u64> {
+        self.get(path)?.value()
+    }
+
+    /// Decodes the node located at `path` using the entries of the decode definition that
+    /// precede the entry `before`. The conditions of the entries are evaluated on the fields
+    /// defined before them, as done when the whole record is decoded.
+    fn decode(&self, path: &str, before: usize) -> Option<Node> {
+        let mut entries: Vec<usize> = self
+            .index
+            .range::<str, _>((Bound::Included(path), Bound::Unbounded))
+            .take_while(|(name, _)| name.starts_with(path))
+            .filter(|(name, _)| name.len() == path.len() || name[path.len()..].starts_with('.'))
+            .flat_map(|(_, entries)| entries.iter().copied())
+            .filter(|&i| i < before)
+            .collect();
+        entries.sort_unstable();
+
+        let mut root = Node::root();
+        for i in entries {
+            let entry = &self.definition.entries[i];
+            let top = &entry.path[0];
+
+            if let Some(condition) = &entry.valid_if {
+                let discriminator = format!("{}.{}", node::escape_name(top), condition.path());
+                let value = self.decode(&discriminator, i).and_then(|node| node.value());
+                if !condition.is_satisfied_by(value) {
+                    continue;
+                }
+            }
+
+            if entry.absolute && root.get(top).is_none() {
+                root.add(Node::record(top));
+            }
+
+            let node = root.create_hierarchy_from_iter(&entry.path);
+            self.record
+                .decode_entry(node, &self.definition, entry, self.offset * 8);
+        }
+
+        let mut node = &mut root;
+        for name in split_path(path) {
+            node = node.get_mut(&name)?;
+        }
+        Some(mem::take(node))
+    }
+}
+
+impl Record {
+    /// Returns a lazily decoded view of the [Record] using the decode definition stored in the
+    /// collateral tree.
+    ///
+    /// The paths passed to [`LazyRecord::get`] are relative to the root of the decode definition
+    /// and don't include the custom root path of the record, if any. The core records, which are
+    /// decoded using several decode definitions, are not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    ///
+    /// let mut cm = CollateralManager::embedded_tree().unwrap();
+    /// cm.override_layout("MCA", b"name;offset;size;description\nmca.version;0;8;".to_vec())
+    ///     .unwrap();
+    ///
+    /// let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let record = &crashlog.regions[0].records[0];
+    ///
+    /// let lazy = record.decode_lazy(&mut cm).unwrap();
+    /// assert_eq!(lazy.get_value("mca.version"), Some(data[0] as u64));
+    /// ```
+    #[cfg(feature = "collateral_manager")]
+    pub fn decode_lazy<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+    ) -> Result<LazyRecord<'_>, Error> {
+        let layout_override = self
+            .header
+            .record_type()
+            .ok()
+            .and_then(|record_type| cm.layout_override(record_type));
+
+        let definition = match layout_override {
+            Some(layout) => DecodeDefinition::from_csv(layout)?,
+            None => Arc::unwrap_or_clone(self.load_decode_def(cm, "layout.csv")?),
+        };
+        Ok(LazyRecord::new(self, definition, 0))
+    }
+}
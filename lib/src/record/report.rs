@@ -0,0 +1,94 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use crate::error::Error;
+use crate::header::Header;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Issue found while decoding a Crash Log record.
+#[derive(Debug)]
+pub enum DecodeWarning {
+    /// The content of the record cannot be decoded. Only the header fields are decoded.
+    DecodeFailed(Error),
+    /// The record is shorter than the size declared in its header.
+    Truncated { expected: usize, actual: usize },
+    /// The checksum of the record is invalid.
+    InvalidChecksum,
+}
+
+impl fmt::Display for DecodeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeWarning::DecodeFailed(err) => write!(f, "cannot decode record: {err}"),
+            DecodeWarning::Truncated { expected, actual } => write!(
+                f,
+                "record is truncated: {actual} bytes available out of {expected}"
+            ),
+            DecodeWarning::InvalidChecksum => write!(f, "invalid checksum"),
+        }
+    }
+}
+
+/// Warnings reported for a single Crash Log record.
+#[derive(Debug)]
+pub struct RecordReport {
+    /// Index of the region storing the record in the Crash Log
+    pub region: usize,
+    /// Index of the record in the region
+    pub record: usize,
+    /// Header of the record
+    pub header: Header,
+    /// Issues found while decoding the record
+    pub warnings: Vec<DecodeWarning>,
+}
+
+/// Bytes of a Crash Log region skipped to resynchronize on the next record after a corrupted
+/// record.
+///
+/// See [`crate::region::Region::from_slice_with_recovery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionGap {
+    /// Index of the region in the Crash Log
+    pub region: usize,
+    /// Offset of the skipped bytes in the region
+    pub offset: usize,
+    /// Number of skipped bytes
+    pub size: usize,
+}
+
+impl fmt::Display for RegionGap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "region {}: {} bytes skipped at offset {:#x}",
+            self.region, self.size, self.offset
+        )
+    }
+}
+
+/// Issues found while decoding a Crash Log.
+///
+/// See [`crate::CrashLog::decode_with_report`].
+#[derive(Debug, Default)]
+pub struct DecodeReport {
+    /// Reports of the records that have at least one warning
+    pub records: Vec<RecordReport>,
+    /// Corrupted bytes skipped while parsing the regions in recovery mode
+    pub gaps: Vec<RegionGap>,
+}
+
+impl DecodeReport {
+    /// Returns `true` if no issue has been found while decoding the Crash Log.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty() && self.gaps.is_empty()
+    }
+
+    /// Returns an iterator over all the warnings of the report.
+    pub fn warnings(&self) -> impl Iterator<Item = &DecodeWarning> {
+        self.records
+            .iter()
+            .flat_map(|record| record.warnings.iter())
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::Record;
+use super::definition::DecodeDefinition;
+#[cfg(feature = "collateral_manager")]
+use crate::collateral::{CollateralManager, CollateralTree};
+use crate::error::Error;
+use crate::node::Node;
+#[cfg(not(feature = "std"))]
+use alloc::format;
+#[cfg(all(not(feature = "std"), feature = "collateral_manager"))]
+use alloc::sync::Arc;
+#[cfg(feature = "collateral_manager")]
+use core::ops::Range;
+#[cfg(all(feature = "std", feature = "collateral_manager"))]
+use std::sync::Arc;
+
+/// Name of the decode definition describing the TOR dump of the records
+#[cfg(feature = "collateral_manager")]
+const TOR_DECODE_DEF: &str = "tor.csv";
+
+impl Record {
+    /// Decodes the Table of Requests (TOR) dump stored in the [Record] into a `tor` section
+    /// whose children are the valid TOR entries, named `entryN` after their index in the dump.
+    ///
+    /// The decode definition describes the dump using three kinds of entries:
+    /// - `tor`: offset and size of the dump in the record, in bits.
+    /// - `tor.entry`: size of a TOR entry in bits. The dump is split into as many entries as it
+    ///   can hold.
+    /// - `tor.entry.<field>`: fields of a TOR entry (e.g. `valid`, `opcode`, `address`). Their
+    ///   offsets are relative to the beginning of the entry. If the entry has a `valid` field,
+    ///   the entries where it is zero are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidDecodeDefinition] if the decode definition doesn't define the `tor`
+    /// and `tor.entry` entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::record::DecodeDefinition;
+    ///
+    /// let record = Record {
+    ///     header: Header::default(),
+    ///     data: vec![0, 0, 0x23, 0x81, 0, 0, 0x45, 0x81],
+    ///     ..Record::default()
+    /// };
+    ///
+    /// let csv = "name;offset;size;description
+    /// tor;16;48;
+    /// tor.entry;0;16;
+    /// tor.entry.opcode;0;8;
+    /// tor.entry.address;8;7;
+    /// tor.entry.valid;15;1;";
+    /// let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    ///
+    /// let tor = record.decode_tor(&definition).unwrap();
+    /// assert_eq!(tor.get_value_by_path("entry0.opcode"), Some(0x23));
+    /// assert_eq!(tor.get_value_by_path("entry0.address"), Some(0x01));
+    /// assert!(tor.get("entry1").is_none());
+    /// assert_eq!(tor.get_value_by_path("entry2.opcode"), Some(0x45));
+    /// ```
+    pub fn decode_tor(&self, definition: &DecodeDefinition) -> Result<Node, Error> {
+        let find = |path: &[&str]| {
+            definition
+                .entries
+                .iter()
+                .find(|entry| entry.path == path)
+                .ok_or_else(|| {
+                    Error::InvalidDecodeDefinition(format!("missing {} entry", path.join(".")))
+                })
+        };
+        let dump = find(&["tor"])?;
+        let entry_size = find(&["tor", "entry"])?.size;
+        if entry_size == 0 {
+            return Err(Error::InvalidDecodeDefinition(
+                "the TOR entries cannot be empty".into(),
+            ));
+        }
+
+        // The fields of the entries are decoded relative to the entry node.
+        let fields = DecodeDefinition {
+            entries: definition
+                .entries
+                .iter()
+                .filter(|entry| {
+                    entry.path.len() > 2 && entry.path[0] == "tor" && entry.path[1] == "entry"
+                })
+                .map(|entry| {
+                    let mut entry = entry.clone();
+                    entry.path.drain(..2);
+                    entry.absolute = false;
+                    entry
+                })
+                .collect(),
+            bitfields: definition.bitfields.clone(),
+        };
+
+        let mut tor = Node::section("tor");
+        tor.description = dump.description.clone();
+        for index in 0..dump.size / entry_size {
+            let offset = dump.offset + index * entry_size;
+            let root = self.decode_with_definition_bits(&fields, offset, Some(entry_size));
+            if root.get_value_by_path("valid") == Some(0) {
+                continue;
+            }
+            let mut entry = Node::section(&format!("entry{index}"));
+            entry.merge(root);
+            tor.add(entry);
+        }
+        Ok(tor)
+    }
+
+    /// Decodes the TOR dump of the record using the `tor.csv` decode definition stored in the
+    /// collateral tree, if any. Returns the `tor` section and the bits covered by the dump.
+    #[cfg(feature = "collateral_manager")]
+    pub(super) fn decode_tor_using_cm<T: CollateralTree>(
+        &self,
+        cm: &mut CollateralManager<T>,
+    ) -> Option<(Node, Range<usize>)> {
+        let definition = match cm.cached_definition(&self.header, TOR_DECODE_DEF) {
+            Some(definition) => definition,
+            None => {
+                // The TOR dump is optional: the resolver is not invoked.
+                let definition = self
+                    .find_decode_def(cm, TOR_DECODE_DEF)
+                    .inspect_err(|err| log::debug!("No TOR decode definition: {err}"))
+                    .map(Arc::new)
+                    .ok()?;
+                cm.cache_definition(&self.header, TOR_DECODE_DEF, Arc::clone(&definition));
+                definition
+            }
+        };
+
+        let dump = definition
+            .entries
+            .iter()
+            .find(|entry| entry.path == ["tor"])
+            .map(|dump| dump.offset..dump.offset + dump.size)?;
+        match self.decode_tor(&definition) {
+            Ok(tor) => Some((tor, dump)),
+            Err(err) => {
+                log::warn!("Cannot decode the TOR dump: {err}");
+                None
+            }
+        }
+    }
+}
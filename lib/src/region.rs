@@ -3,12 +3,16 @@
 
 //! Provides access to the records stored in a Crash Log region.
 
+mod builder;
+
 use crate::cper::section::{CperSectionBody, fer};
 use crate::error::Error;
-use crate::header::Header;
+use crate::header::{Header, Version};
 use crate::record::Record;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
+pub use builder::RegionBuilder;
+use core::ops::Range;
 
 /// A container for one or several Crash Log records.
 ///
@@ -17,15 +21,59 @@ use alloc::vec::Vec;
 #[derive(Default)]
 pub struct Region {
     pub records: Vec<Record>,
+    /// Accounting of the bytes processed while parsing the region
+    pub metrics: ParseMetrics,
+    /// Appends a termination marker after the records when the region is serialized. Only set by
+    /// [Region::rebuild].
+    pub terminated: bool,
+}
+
+/// Accounting of the bytes processed while parsing a Crash Log region.
+///
+/// These numbers are computed by [Region::from_slice] and can be used to detect truncated or
+/// corrupted regions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParseMetrics {
+    /// Number of bytes consumed as valid records
+    pub record_bytes: usize,
+    /// Number of bytes skipped because no valid record could be decoded from them
+    pub skipped_bytes: usize,
+    /// Number of termination markers found after the last record
+    pub termination_markers: usize,
+    /// Number of bytes found after the termination markers
+    pub trailing_bytes: usize,
+    /// Number of bytes missing from the last record if the region is truncated
+    pub missing_bytes: usize,
+    /// Byte ranges skipped to resynchronize on the next record after a corrupted record. Only
+    /// filled by [Region::from_slice_with_recovery].
+    pub gaps: Vec<Range<usize>>,
+}
+
+/// Marks the end of the records stored in a region
+const TERMINATION_MARKER: [u8; 4] = [0; 4];
+
+impl ParseMetrics {
+    fn count_termination_markers(&mut self, bytes: &[u8]) {
+        self.termination_markers = bytes
+            .chunks_exact(4)
+            .take_while(|dword| Version::from_slice(dword).is_none())
+            .count();
+        self.trailing_bytes = bytes.len() - self.termination_markers * 4;
+    }
+
+    fn add_gap(&mut self, gap: Range<usize>) {
+        self.skipped_bytes += gap.len();
+        self.gaps.push(gap);
+    }
 }
 
 impl Region {
-    pub(crate) fn from_cper_section(section: &CperSectionBody) -> Option<Self> {
+    pub(crate) fn from_cper_section(section: &CperSectionBody, recover: bool) -> Option<Self> {
         match section {
             CperSectionBody::FirmwareErrorRecord(fer) => {
                 let guid = fer.header.guid;
                 if guid == fer::guids::RECORD_ID_CRASHLOG {
-                    Region::from_slice(&fer.payload).ok()
+                    Region::parse(&fer.payload, recover).ok()
                 } else {
                     log::info!("Ignoring unknown Firmware Error Record: {}", guid);
                     None
@@ -41,7 +89,45 @@ impl Region {
         }
     }
 
+    /// Parses the Crash Log records stored in a raw region.
+    ///
+    /// The parsing stops at the first termination marker or at the first record that cannot be
+    /// parsed. See [Region::from_slice_with_recovery] to keep parsing past corrupted records.
     pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        Self::parse(bytes, false)
+    }
+
+    /// Parses the Crash Log records stored in a raw region, resynchronizing on the next record
+    /// when a record has an invalid header or a bogus size.
+    ///
+    /// The region is scanned forward from the corrupted record, 32 bits at a time, for the next
+    /// plausible record: a record of a known type whose size is not null and fits in the region.
+    /// The skipped bytes are reported in [ParseMetrics::gaps]. If no plausible record follows,
+    /// the parsing ends like in [Region::from_slice].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::region::Region;
+    ///
+    /// let record = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    /// let bytes = [[0xff; 0x10].as_slice(), &record].concat();
+    ///
+    /// assert!(Region::from_slice(&bytes).is_err());
+    ///
+    /// let region = Region::from_slice_with_recovery(&bytes).unwrap();
+    /// assert_eq!(region.records.len(), 1);
+    /// assert_eq!(region.metrics.gaps, vec![0..0x10]);
+    /// ```
+    pub fn from_slice_with_recovery(bytes: &[u8]) -> Result<Self, Error> {
+        Self::parse(bytes, true)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(size = bytes.len()))
+    )]
+    pub(crate) fn parse(bytes: &[u8], recover: bool) -> Result<Self, Error> {
         let mut region = Region::default();
         let mut cursor = 0;
 
@@ -50,14 +136,21 @@ impl Region {
                 Ok(Some(header)) => header,
                 Ok(None) => {
                     log::debug!("Found termination marker at offset {cursor}");
+                    region.metrics.count_termination_markers(&bytes[cursor..]);
                     break;
                 }
                 Err(err) => {
                     log::warn!("Cannot decode record header: {err}");
+                    if recover && let Some(next) = Self::resynchronize(bytes, cursor) {
+                        region.metrics.add_gap(cursor..next);
+                        cursor = next;
+                        continue;
+                    }
                     if region.records.is_empty() {
                         // Return the error if no record can be decoded
                         return Err(err);
                     }
+                    region.metrics.skipped_bytes += bytes.len() - cursor;
                     break;
                 }
             };
@@ -72,18 +165,38 @@ impl Region {
                     "{} record has an empty size. Skipping.",
                     header.record_type().unwrap_or("UNKNOWN")
                 );
+                if recover && let Some(next) = Self::resynchronize(bytes, cursor) {
+                    region.metrics.add_gap(cursor..next);
+                    cursor = next;
+                    continue;
+                }
+                region.metrics.skipped_bytes += bytes.len() - cursor;
                 break;
             }
 
             let limit = cursor + record_size;
+            if limit > bytes.len()
+                && recover
+                && let Some(next) = Self::resynchronize(bytes, cursor)
+            {
+                log::warn!(
+                    "{} record overruns the region. Resuming at offset {next:#x}.",
+                    header.record_type().unwrap_or("UNKNOWN")
+                );
+                region.metrics.add_gap(cursor..next);
+                cursor = next;
+                continue;
+            }
             if limit > bytes.len() {
                 log::warn!(
                     "Truncated record detected: record is expected to be {}B but is {}B",
                     record_size,
                     bytes.len() - cursor
-                )
+                );
+                region.metrics.missing_bytes = limit - bytes.len();
             }
 
+            region.metrics.record_bytes += limit.min(bytes.len()) - cursor;
             region.records.push(Record {
                 header,
                 data: bytes[cursor..limit.min(bytes.len())].into(),
@@ -100,11 +213,98 @@ impl Region {
         Ok(region)
     }
 
+    /// Returns the size of the sequence of plausible Crash Log records starting at the beginning
+    /// of the slice. A record is considered plausible if its type is known and if it fits in the
+    /// slice.
+    fn carvable_size(bytes: &[u8]) -> usize {
+        let mut cursor = 0;
+        while let Some(size) = Self::plausible_record_size(&bytes[cursor..]) {
+            cursor += size;
+        }
+        cursor
+    }
+
+    /// Returns the size of the record starting at the beginning of the slice if the record is
+    /// plausible: its type is known, and its size is not null and fits in the slice.
+    fn plausible_record_size(bytes: &[u8]) -> Option<usize> {
+        let header = Header::from_slice(bytes).ok()??;
+        let size = header.record_size();
+        (header.record_type().is_ok() && size > 0 && size <= bytes.len()).then_some(size)
+    }
+
+    /// Returns the offset of the next plausible record following the corrupted record found at
+    /// the given offset. The candidate offsets are 32 bits apart, starting from the corrupted
+    /// record.
+    fn resynchronize(bytes: &[u8], offset: usize) -> Option<usize> {
+        let next = (offset + 4..bytes.len())
+            .step_by(4)
+            .find(|&cursor| Self::plausible_record_size(&bytes[cursor..]).is_some())?;
+        log::info!("Resynchronized on the record found at offset {next:#x}");
+        Some(next)
+    }
+
+    /// Searches for the Crash Log regions stored at arbitrary offsets in a byte slice, and
+    /// returns them along with their offsets. The regions are expected to be aligned on 32 bits.
+    pub(crate) fn carve_from_slice(bytes: &[u8]) -> Vec<(usize, Self)> {
+        let mut regions = Vec::new();
+        let mut cursor = 0;
+
+        while cursor + 4 <= bytes.len() {
+            let size = Self::carvable_size(&bytes[cursor..]);
+            if size == 0 {
+                cursor += 4;
+                continue;
+            }
+
+            match Region::from_slice(&bytes[cursor..cursor + size]) {
+                Ok(region) => {
+                    log::debug!("Found Crash Log region at offset {cursor:#x} ({size} bytes)");
+                    regions.push((cursor, region))
+                }
+                Err(err) => log::debug!("Ignoring region at offset {cursor:#x}: {err}"),
+            }
+            cursor += size.next_multiple_of(4);
+        }
+
+        regions
+    }
+
+    /// Regenerates the headers of the records stored in the region after the records have been
+    /// edited. The size fields of the headers are updated to match the actual length of the
+    /// records and the records that cannot store a valid header are removed from the region.
+    ///
+    /// The [ParseMetrics] of the region are reset to describe the rebuilt region, which is
+    /// terminated by a single termination marker when serialized (see [Region::terminated]).
+    pub fn rebuild(&mut self) {
+        self.records.retain_mut(|record| match record.rebuild() {
+            Ok(()) => true,
+            Err(err) => {
+                log::warn!(
+                    "Removing invalid record from region: {} ({err})",
+                    record.header
+                );
+                false
+            }
+        });
+
+        self.metrics = ParseMetrics {
+            record_bytes: self.records.iter().map(|record| record.data.len()).sum(),
+            termination_markers: 1,
+            ..ParseMetrics::default()
+        };
+        self.terminated = true;
+    }
+
+    /// Serializes the records stored in the region. A termination marker is appended after the
+    /// last record if the region has been rebuilt.
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::new();
         for record in self.records.iter() {
             bytes.append(&mut record.data.clone());
         }
+        if self.terminated {
+            bytes.extend_from_slice(&TERMINATION_MARKER);
+        }
         bytes
     }
 }
@@ -0,0 +1,121 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use super::Region;
+use crate::error::Error;
+use crate::header::Header;
+use crate::record::Record;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Builds a raw Crash Log region from a sequence of records.
+///
+/// The size fields of the record headers are regenerated to match the length of the records, and
+/// the records are followed by termination markers. The checksums of the records are kept as is,
+/// unless [RegionBuilder::update_checksums] is set. See [Record::add_checksum] to add a checksum
+/// to a record.
+///
+/// # Examples
+///
+/// ```
+/// use intel_crashlog::region::RegionBuilder;
+///
+/// let mca = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+/// let agent = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+///
+/// let region = RegionBuilder::new()
+///     .add_record(mca)
+///     .add_record(agent)
+///     .size(0x1000)
+///     .build()
+///     .unwrap();
+/// assert_eq!(region.records.len(), 2);
+/// assert_eq!(region.records[0].header.record_type().unwrap(), "MCA");
+/// assert!(region.metrics.termination_markers > 0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RegionBuilder {
+    records: Vec<Vec<u8>>,
+    size: Option<usize>,
+    update_checksums: bool,
+}
+
+impl RegionBuilder {
+    /// Returns a new builder of an empty region.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a raw record to the region. The record must start with a valid header, whose size
+    /// fields are regenerated when the region is built. The record is padded with
+    /// zeros to the granularity of the record size.
+    pub fn add_record(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.records.push(data.into());
+        self
+    }
+
+    /// Sets the size of the region in bytes. The space left after the records is filled with
+    /// termination markers. By default, a single termination marker follows the records.
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Updates the checksums of the records that have one to match their content when the region
+    /// is built.
+    pub fn update_checksums(mut self) -> Self {
+        self.update_checksums = true;
+        self
+    }
+
+    /// Returns the records of the region with their regenerated headers.
+    fn records(&self) -> Result<Vec<Record>, Error> {
+        let mut records = Vec::new();
+        for data in self.records.iter() {
+            let header = Header::from_slice(data)?.ok_or(Error::InvalidHeader)?;
+            let mut record = Record {
+                header,
+                data: data.clone(),
+                ..Default::default()
+            };
+            record.rebuild()?;
+            if self.update_checksums {
+                record.update_checksum();
+            }
+            records.push(record);
+        }
+        Ok(records)
+    }
+
+    /// Returns the raw content of the region.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidHeader] if a record does not start with a valid header,
+    /// [Error::InvalidRecordSize] if the size of a record cannot be encoded in its header, and
+    /// [Error::RegionOverflow] if the records do not fit in the size of the region.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        for mut record in self.records()? {
+            bytes.append(&mut record.data);
+        }
+
+        let size = self.size.unwrap_or(bytes.len() + 4);
+        if bytes.len() > size {
+            return Err(Error::RegionOverflow(bytes.len(), size));
+        }
+        bytes.resize(size, 0);
+        Ok(bytes)
+    }
+
+    /// Builds the region. The records are parsed back from the raw content of the region, like
+    /// [Region::from_slice] does.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [RegionBuilder::to_bytes], and [Error::EmptyRegion] if the region
+    /// has no record.
+    pub fn build(&self) -> Result<Region, Error> {
+        Region::from_slice(&self.to_bytes()?)
+    }
+}
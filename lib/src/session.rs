@@ -0,0 +1,164 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Grouping of the Crash Logs captured during the same incident.
+//!
+//! A session bundle is a directory that stores the captures alongside an index file named
+//! `session.json`:
+//!
+//! ```text
+//! incident/
+//! ├── session.json
+//! ├── acpi.crashlog
+//! ├── pmt.crashlog
+//! └── bmc.crashlog
+//! ```
+//!
+//! The index stores the metadata shared by all the captures and the list of the captures:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "metadata": {
+//!     "incident": "1234"
+//!   },
+//!   "captures": [
+//!     { "name": "acpi", "source": "acpi", "file": "acpi.crashlog" }
+//!   ]
+//! }
+//! ```
+
+use crate::CrashLog;
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Name of the index file of the session bundles
+pub const INDEX_FILE_NAME: &str = "session.json";
+
+const INDEX_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct Index {
+    version: u32,
+    #[serde(default)]
+    metadata: BTreeMap<String, String>,
+    captures: Vec<IndexEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct IndexEntry {
+    name: String,
+    source: String,
+    file: String,
+}
+
+/// Crash Log captured during an incident
+pub struct Capture {
+    /// Name of the capture, unique within the session
+    pub name: String,
+    /// Source the Crash Log has been extracted from (e.g. `acpi`, `pmt` or `bmc`)
+    pub source: String,
+    /// Crash Log records
+    pub crashlog: CrashLog,
+}
+
+/// Crash Logs captured during the same incident
+#[derive(Default)]
+pub struct Session {
+    /// Information shared by all the captures (e.g. incident ID, system serial number)
+    pub metadata: BTreeMap<String, String>,
+    /// Captures of the session
+    pub captures: Vec<Capture>,
+}
+
+impl Session {
+    /// Adds a Crash Log to the session.
+    pub fn add(&mut self, name: &str, source: &str, crashlog: CrashLog) {
+        self.captures.push(Capture {
+            name: name.into(),
+            source: source.into(),
+            crashlog,
+        })
+    }
+
+    /// Returns the capture with the given name.
+    pub fn get(&self, name: &str) -> Option<&Capture> {
+        self.captures.iter().find(|capture| capture.name == name)
+    }
+
+    /// Reads the session bundle stored in the `dir` directory.
+    pub fn read(dir: &Path) -> Result<Self, Error> {
+        let index: Index = serde_json::from_slice(&std::fs::read(dir.join(INDEX_FILE_NAME))?)?;
+        if index.version != INDEX_VERSION {
+            return Err(Error::InvalidSession(format!(
+                "unsupported version {}",
+                index.version
+            )));
+        }
+
+        let mut session = Session {
+            metadata: index.metadata,
+            captures: Vec::new(),
+        };
+
+        for entry in index.captures {
+            check_file_name(&entry.file)?;
+            let crashlog = CrashLog::from_slice(&std::fs::read(dir.join(&entry.file))?)?;
+            session.add(&entry.name, &entry.source, crashlog);
+        }
+
+        Ok(session)
+    }
+
+    /// Writes the session bundle into the `dir` directory. The directory is created if needed.
+    pub fn write(&self, dir: &Path) -> Result<(), Error> {
+        let mut captures: Vec<IndexEntry> = Vec::new();
+        for capture in self.captures.iter() {
+            if captures.iter().any(|entry| entry.name == capture.name) {
+                return Err(Error::InvalidSession(format!(
+                    "duplicated capture name: {}",
+                    capture.name
+                )));
+            }
+
+            let file = format!("{}.crashlog", capture.name);
+            check_file_name(&file)?;
+            captures.push(IndexEntry {
+                name: capture.name.clone(),
+                source: capture.source.clone(),
+                file,
+            });
+        }
+
+        std::fs::create_dir_all(dir)?;
+        for (capture, entry) in self.captures.iter().zip(captures.iter()) {
+            std::fs::write(dir.join(&entry.file), capture.crashlog.to_bytes())?;
+        }
+
+        let index = Index {
+            version: INDEX_VERSION,
+            metadata: self.metadata.clone(),
+            captures,
+        };
+        std::fs::write(
+            dir.join(INDEX_FILE_NAME),
+            serde_json::to_vec_pretty(&index)?,
+        )?;
+        Ok(())
+    }
+}
+
+/// Ensures the capture files are stored directly in the session directory.
+fn check_file_name(file: &str) -> Result<(), Error> {
+    let path = Path::new(file);
+    if path.file_name().map(|name| name == path.as_os_str()) != Some(true)
+        || file == INDEX_FILE_NAME
+    {
+        return Err(Error::InvalidSession(format!(
+            "invalid capture file: {file}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Classification of the errors reported in the Crash Log records.
+//!
+//! The severity is derived from the architectural `MCi_STATUS` registers decoded from the
+//! records. A register is recognized as a field named `status` stored in a `bank*` section of
+//! the `mca` sub-tree (e.g. `mca.core0.bank3.status`).
+
+use crate::CrashLog;
+use crate::collateral::{CollateralManager, CollateralTree};
+use crate::cper::Cper;
+use crate::node::{Node, NodeType};
+use crate::region::Region;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// `MCi_STATUS.VAL`: the register contains valid error information
+const MCI_STATUS_VAL: u64 = 1 << 63;
+/// `MCi_STATUS.UC`: the error has not been corrected
+const MCI_STATUS_UC: u64 = 1 << 61;
+
+/// Severity of the errors reported in the Crash Log records
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// No valid machine check error is reported.
+    #[default]
+    Informational,
+    /// Only corrected machine check errors are reported.
+    Corrected,
+    /// At least one valid uncorrected machine check error is reported.
+    Fatal,
+}
+
+impl Severity {
+    fn from_mci_status(status: u64) -> Self {
+        if status & MCI_STATUS_VAL == 0 {
+            Severity::Informational
+        } else if status & MCI_STATUS_UC == 0 {
+            Severity::Corrected
+        } else {
+            Severity::Fatal
+        }
+    }
+
+    /// Derives the severity from the machine check banks stored in a register tree.
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::severity::Severity;
+    ///
+    /// let mut bank = Node::section("bank0");
+    /// bank.add(Node::field("status", 0x8000000000000000));
+    /// let mut mca = Node::section("mca");
+    /// mca.add(bank);
+    /// let mut root = Node::root();
+    /// root.add(mca);
+    ///
+    /// assert_eq!(Severity::from_node(&root), Severity::Corrected);
+    /// ```
+    pub fn from_node(node: &Node) -> Self {
+        Self::from_mca_node(node, false)
+    }
+
+    fn from_mca_node(node: &Node, in_mca: bool) -> Self {
+        let in_mca = in_mca || node.name == "mca";
+        let is_bank = in_mca && node.name.starts_with("bank");
+
+        node.children()
+            .map(|child| match child.kind {
+                NodeType::Field { value } if is_bank && child.name == "status" => {
+                    Self::from_mci_status(value)
+                }
+                _ => Self::from_mca_node(child, in_mca),
+            })
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl Region {
+    /// Returns the severity of the errors reported in the records of the region.
+    pub fn severity<T: CollateralTree>(&self, cm: &mut CollateralManager<T>) -> Severity {
+        self.records
+            .iter()
+            .map(|record| Severity::from_node(&record.decode(cm)))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl CrashLog {
+    /// Returns the severity of the errors reported in the Crash Log.
+    pub fn severity<T: CollateralTree>(&self, cm: &mut CollateralManager<T>) -> Severity {
+        self.regions
+            .iter()
+            .map(|region| region.severity(cm))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Exports the [CrashLog] as a CPER file, similarly to [CrashLog::to_bytes], but derives the
+    /// severity of the CPER record and sections from the content of the Crash Log regions
+    /// instead of reporting all of them as fatal.
+    pub fn to_bytes_with_cm<T: CollateralTree>(&self, cm: &mut CollateralManager<T>) -> Vec<u8> {
+        let severities: Vec<Severity> = self
+            .regions
+            .iter()
+            .map(|region| region.severity(cm))
+            .collect();
+        Cper::from_crashlog_with_severities(self, &severities).to_bytes()
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Tracking of the Crash Logs already extracted from the platform.
+//!
+//! The Crash Logs exposed by the platform (ACPI BERT, Intel PMT, Windows event logs) remain
+//! available after they have been extracted. The [ExtractionState] records a fingerprint of the
+//! Crash Logs already emitted so repeated extractions only report the new ones. Only the
+//! fingerprints of the [MAX_SEEN] most recent Crash Logs are kept, so the state doesn't grow
+//! without bound on the long-running systems.
+//!
+//! ```
+//! use intel_crashlog::prelude::*;
+//! use intel_crashlog::state::ExtractionState;
+//!
+//! let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+//! let mut state = ExtractionState::default();
+//!
+//! let crashlogs = state.filter_new(vec![CrashLog::from_slice(&data).unwrap()]);
+//! assert_eq!(crashlogs.len(), 1);
+//! let crashlogs = state.filter_new(vec![CrashLog::from_slice(&data).unwrap()]);
+//! assert_eq!(crashlogs.len(), 0);
+//! ```
+
+use crate::CrashLog;
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::Path;
+
+const STATE_VERSION: u32 = 1;
+
+/// Maximum number of Crash Logs tracked by the [ExtractionState]. The platform only exposes a
+/// handful of Crash Logs at a time, so the oldest fingerprints can safely be forgotten.
+pub const MAX_SEEN: usize = 1024;
+
+/// Fingerprints of the Crash Logs already extracted, the oldest first
+#[derive(Default, Serialize, Deserialize)]
+pub struct ExtractionState {
+    version: u32,
+    seen: VecDeque<u64>,
+}
+
+/// Computes a fingerprint of the Crash Log records that doesn't depend on the extraction
+/// metadata, using the 64-bit FNV-1a hash function.
+fn fingerprint(crashlog: &CrashLog) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for region in crashlog.regions.iter() {
+        for byte in region.to_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    hash
+}
+
+impl ExtractionState {
+    /// Loads the state stored at the given `path`. An empty state is returned if the file doesn't
+    /// exist yet.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match std::fs::read(path) {
+            Ok(data) => Ok(serde_json::from_slice(&data)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Stores the state at the given `path`.
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let state = Self {
+            version: STATE_VERSION,
+            seen: self.seen.clone(),
+        };
+        Ok(std::fs::write(path, serde_json::to_vec(&state)?)?)
+    }
+
+    /// Returns true if the Crash Log hasn't been extracted before.
+    pub fn is_new(&self, crashlog: &CrashLog) -> bool {
+        !self.seen.contains(&fingerprint(crashlog))
+    }
+
+    /// Records the Crash Log as extracted. Returns true if it hasn't been extracted before.
+    ///
+    /// The oldest Crash Log is forgotten when more than [MAX_SEEN] Crash Logs are recorded.
+    pub fn mark(&mut self, crashlog: &CrashLog) -> bool {
+        let fingerprint = fingerprint(crashlog);
+        if self.seen.contains(&fingerprint) {
+            return false;
+        }
+        if self.seen.len() >= MAX_SEEN {
+            self.seen.pop_front();
+        }
+        self.seen.push_back(fingerprint);
+        true
+    }
+
+    /// Returns the Crash Logs that haven't been extracted before and records them as extracted.
+    pub fn filter_new(&mut self, crashlogs: Vec<CrashLog>) -> Vec<CrashLog> {
+        crashlogs
+            .into_iter()
+            .filter(|crashlog| self.mark(crashlog))
+            .collect()
+    }
+
+    /// Forgets all the Crash Logs extracted so far.
+    pub fn clear(&mut self) {
+        self.seen.clear()
+    }
+}
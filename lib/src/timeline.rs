@@ -0,0 +1,137 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+//! Alignment of the timestamps of the records captured on different sockets.
+//!
+//! The agents of each socket timestamp their records using their own counter, which is not
+//! synchronized with the counters of the other sockets. The timestamps are therefore aligned on a
+//! reference event ([Anchor]) captured on each socket.
+
+use crate::CrashLog;
+use crate::collateral::{CollateralManager, CollateralTree};
+use crate::record::SplitKey;
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+/// Reference event used to align the timestamps of a socket.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    /// Uses the earliest timestamp captured on each socket.
+    Earliest,
+    /// Uses the timestamp of the first record of the given type captured on each socket (see
+    /// [`crate::header::record_types`]). Falls back to the earliest timestamp if the socket
+    /// doesn't have any record of this type.
+    RecordType(u8),
+}
+
+/// Timestamp of a Crash Log record aligned on the anchor of its socket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlignedTimestamp {
+    /// Index of the region storing the record
+    pub region: usize,
+    /// Index of the record in the region
+    pub record: usize,
+    /// ID of the socket that generated the record
+    pub socket_id: u8,
+    /// Raw timestamp stored in the record header
+    pub raw: u64,
+    /// Number of counter ticks elapsed since the anchor of the socket
+    pub ticks: i64,
+    /// Time elapsed since the anchor of the socket in nanoseconds. Only available if the
+    /// frequency of the counter is defined in the collateral tree.
+    pub nanoseconds: Option<i64>,
+    /// Indicates that the aligned timestamp may be inaccurate: the record has an empty timestamp,
+    /// the anchor event cannot be found on the socket, or the counter frequency is unknown.
+    pub uncertain: bool,
+}
+
+impl CrashLog {
+    /// Aligns the timestamps of the records on the [Anchor] of their socket.
+    ///
+    /// The records that don't have any timestamp are ignored. The frequency of the counters is
+    /// read from the `timestamp_frequency` field of the `target_info.json` collateral item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use intel_crashlog::prelude::*;
+    /// use intel_crashlog::timeline::Anchor;
+    ///
+    /// let data = std::fs::read("tests/samples/dummy.bert").unwrap();
+    /// let crashlog = CrashLog::from_slice(&data).unwrap();
+    /// let cm = CollateralManager::embedded_tree().unwrap();
+    ///
+    /// let timestamps = crashlog.align_timestamps(&cm, Anchor::Earliest);
+    /// assert_eq!(timestamps.len(), 2);
+    /// assert_eq!(timestamps[0].ticks, 0);
+    /// ```
+    pub fn align_timestamps<T: CollateralTree>(
+        &self,
+        cm: &CollateralManager<T>,
+        anchor: Anchor,
+    ) -> Vec<AlignedTimestamp> {
+        let records = self.regions.iter().enumerate().flat_map(|(i, region)| {
+            region
+                .records
+                .iter()
+                .enumerate()
+                .map(move |(j, record)| (i, j, record))
+        });
+
+        // Anchor timestamp of each socket, and whether the anchor event has been found
+        let mut anchors: BTreeMap<u8, (u64, bool)> = BTreeMap::new();
+        for (_, _, record) in records.clone() {
+            let Some(timestamp) = record
+                .header
+                .timestamp()
+                .filter(|timestamp| *timestamp != 0)
+            else {
+                continue;
+            };
+
+            let is_anchor = matches!(
+                anchor,
+                Anchor::RecordType(record_type) if record_type == record.header.version.record_type
+            );
+
+            let (socket_id, _) = record.split_key(SplitKey::Socket);
+            let entry = anchors.entry(socket_id).or_insert((timestamp, is_anchor));
+            if is_anchor && !entry.1 {
+                *entry = (timestamp, true);
+            } else if !entry.1 {
+                entry.0 = entry.0.min(timestamp);
+            }
+        }
+
+        records
+            .filter_map(|(region, record_index, record)| {
+                let raw = record.header.timestamp()?;
+                let (socket_id, _) = record.split_key(SplitKey::Socket);
+                let (anchor_timestamp, found) =
+                    anchors.get(&socket_id).copied().unwrap_or((raw, false));
+
+                let ticks = raw.wrapping_sub(anchor_timestamp) as i64;
+                let nanoseconds = cm
+                    .target_info
+                    .get(&record.header.product_id())
+                    .and_then(|target_info| target_info.timestamp_frequency)
+                    .filter(|frequency| *frequency != 0)
+                    .map(|frequency| (ticks as i128 * 1_000_000_000 / frequency as i128) as i64);
+
+                let missing_anchor = matches!(anchor, Anchor::RecordType(_)) && !found;
+
+                Some(AlignedTimestamp {
+                    region,
+                    record: record_index,
+                    socket_id,
+                    raw,
+                    ticks,
+                    nanoseconds,
+                    uncertain: raw == 0 || missing_anchor || nanoseconds.is_none(),
+                })
+            })
+            .collect()
+    }
+}
@@ -3,6 +3,8 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::collections::BTreeMap;
+#[cfg(all(not(feature = "std"), feature = "collateral_manager"))]
+use alloc::{format, string::String};
 #[cfg(feature = "std")]
 use std::collections::HashMap;
 
@@ -10,3 +12,35 @@ use std::collections::HashMap;
 pub type Map<K, T> = HashMap<K, T>;
 #[cfg(not(feature = "std"))]
 pub type Map<K, T> = BTreeMap<K, T>;
+
+/// Formats a Unix time into an ISO-8601 date in UTC.
+#[cfg(feature = "collateral_manager")]
+pub(crate) fn iso8601(seconds: u64, nanoseconds: u32) -> String {
+    let days = seconds / 86400;
+    let time = seconds % 86400;
+
+    // Conversion of the number of days since 1970-01-01 into a civil date, using eras of 400
+    // years that start on March 1st.
+    let z = days + 719468;
+    let era = z / 146097;
+    let day_of_era = z % 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as u64;
+
+    let mut date = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    );
+    if nanoseconds != 0 {
+        date.push_str(&format!(".{nanoseconds:09}"));
+    }
+    date.push('Z');
+    date
+}
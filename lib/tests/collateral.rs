@@ -1,7 +1,10 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
-use intel_crashlog::collateral::PVSS;
+use intel_crashlog::collateral::{
+    BundleTree, EmbeddedTree, FileSystemTree, MemoryCollateralTree, PVSS,
+};
+use intel_crashlog::header::{HeaderBuilder, record_types};
 use intel_crashlog::prelude::*;
 use std::path::Path;
 
@@ -58,3 +61,576 @@ fn out_of_tree() {
             .is_err()
     );
 }
+
+#[test]
+fn bundle() {
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let root = crashlog.decode(&mut cm);
+
+    let bundle = cm.export_bundle().unwrap();
+    let content = std::str::from_utf8(&bundle).unwrap();
+    assert!(content.contains("decode-defs/MCA"));
+    assert!(!content.contains("decode-defs/CRASHLOG_AGENT"));
+    assert!(!content.contains("ABC"));
+
+    let mut cm = CollateralManager::bundle(&bundle).unwrap();
+    assert_eq!(cm.target_info.len(), 1);
+    assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+    assert_eq!(crashlog.decode(&mut cm), root);
+}
+
+#[test]
+fn bundle_binary_items_and_overrides() {
+    let pvss = PVSS {
+        product: "XYZ".into(),
+        ..PVSS::default()
+    };
+    let mut tree = MemoryCollateralTree::new();
+    tree.add_item(
+        pvss.clone(),
+        "target_info.json",
+        br#"{"product": "XYZ", "product_id": "0x7A"}"#.to_vec(),
+    );
+    tree.add_item(pvss.clone(), "blob.bin", vec![0xff, 0x00, 0xfe]);
+
+    let mut cm = CollateralManager::new(tree).unwrap();
+    assert!(cm.get_item_with_pvss(pvss.clone(), "blob.bin").is_ok());
+    cm.override_layout(
+        "MCA",
+        b"name;offset;size;description\nmca.foo;0;8;".to_vec(),
+    )
+    .unwrap();
+    let bundle = cm.export_bundle().unwrap();
+
+    let mut cm = CollateralManager::bundle(&bundle).unwrap();
+    assert_eq!(
+        cm.get_item_with_pvss(pvss, "blob.bin").unwrap(),
+        &[0xff, 0x00, 0xfe]
+    );
+
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.get_value_by_path("mca.foo"), Some(0x1));
+}
+
+#[cfg(feature = "archive_collateral_tree")]
+fn collateral_files(dir: &Path, files: &mut Vec<(String, Vec<u8>)>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collateral_files(&path, files);
+        } else {
+            let name = path.strip_prefix(COLLATERAL_TREE_PATH).unwrap();
+            let name = format!("collateral/{}", name.to_str().unwrap());
+            files.push((name, std::fs::read(&path).unwrap()));
+        }
+    }
+}
+
+#[cfg(feature = "archive_collateral_tree")]
+#[test]
+fn archive() {
+    use std::io::Write;
+
+    let mut files = Vec::new();
+    collateral_files(Path::new(COLLATERAL_TREE_PATH), &mut files);
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, content) in files.iter() {
+        zip.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(content).unwrap();
+    }
+    let zip = zip.finish().unwrap().into_inner();
+
+    let mut tar = tar::Builder::new(flate2::write::GzEncoder::new(
+        Vec::new(),
+        flate2::Compression::default(),
+    ));
+    for (name, content) in files.iter() {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, name, content.as_slice())
+            .unwrap();
+    }
+    let tar_gz = tar.into_inner().unwrap().finish().unwrap();
+
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let root = crashlog.decode(&mut cm);
+
+    for archive in [zip, tar_gz] {
+        let mut cm = CollateralManager::archive_tree(&archive).unwrap();
+        assert_eq!(cm.target_info.len(), 1);
+        assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+        assert_eq!(crashlog.decode(&mut cm), root);
+    }
+
+    assert!(CollateralManager::archive_tree(b"not an archive").is_err());
+}
+
+#[test]
+fn definition_cache() {
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let root = crashlog.decode(&mut cm);
+    for _ in 0..3 {
+        assert_eq!(crashlog.decode(&mut cm), root);
+    }
+    let stats = cm.cache_stats();
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.hits, 3);
+    assert_eq!(stats.entries, 1);
+
+    cm.set_cache_capacity(0);
+    assert_eq!(cm.cache_stats().entries, 0);
+    assert_eq!(cm.cache_stats().evictions, 1);
+    assert_eq!(crashlog.decode(&mut cm), root);
+    assert_eq!(cm.cache_stats().misses, 2);
+    assert_eq!(cm.cache_stats().entries, 0);
+}
+
+#[test]
+fn layout_override() {
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let root = crashlog.decode(&mut cm);
+    assert!(root.get_by_path("mca.hdr").is_some());
+    assert!(root.get_by_path("mca.foo").is_none());
+
+    cm.override_layout(
+        "mca",
+        b"name;offset;size;description\nmca.foo;0;8;".to_vec(),
+    )
+    .unwrap();
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.get_value_by_path("mca.foo"), Some(0x1));
+    assert!(root.get_by_path("mca.hdr").is_none());
+
+    assert!(matches!(
+        cm.override_layout("FOO", Vec::new()),
+        Err(Error::InvalidRecordTypeName(name)) if name == "FOO"
+    ));
+}
+
+#[test]
+fn definition_cache_invalidation() {
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(cm.cache_stats().entries, 1);
+
+    // The decode definitions are cached per stepping.
+    for target_info in cm.target_info.values_mut() {
+        target_info.stepping = "b0".into();
+    }
+    assert_eq!(crashlog.decode(&mut cm), root);
+    assert_eq!(cm.cache_stats().misses, 2);
+    assert_eq!(cm.cache_stats().entries, 2);
+
+    cm.override_layout(
+        "MCA",
+        b"name;offset;size;description\nmca.foo;0;8;".to_vec(),
+    )
+    .unwrap();
+    assert_eq!(cm.cache_stats().entries, 0);
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.get_value_by_path("mca.foo"), Some(0x1));
+}
+
+#[test]
+fn overlay() {
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+
+    let mut cm = CollateralManager::overlay(vec![
+        Box::new(FileSystemTree::new(Path::new(COLLATERAL_TREE_PATH))),
+        Box::new(EmbeddedTree::new()),
+    ])
+    .unwrap();
+    assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+    assert_eq!(cm.target_info.get(&0x052).unwrap().product, "LNC");
+    let mut fs = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    assert_eq!(crashlog.decode(&mut cm), crashlog.decode(&mut fs));
+
+    let bundle = br#"{"items": [{
+        "product": "XYZ", "variant": "all", "stepping": "all", "security": "green",
+        "path": "decode-defs/MCA/1/layout.csv",
+        "content": "name;offset;size;description\nmca.foo;0;8;"
+    }]}"#;
+    let mut cm = CollateralManager::overlay(vec![
+        Box::new(BundleTree::from_slice(bundle).unwrap()),
+        Box::new(FileSystemTree::new(Path::new(COLLATERAL_TREE_PATH))),
+    ])
+    .unwrap();
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.get_value_by_path("mca.foo"), Some(0x1));
+    assert!(root.get_by_path("mca.hdr").is_none());
+}
+
+#[cfg(feature = "collateral_verification")]
+#[test]
+fn verification() {
+    use ed25519_dalek::{Signer, SigningKey};
+    use intel_crashlog::collateral::{CollateralTree, ItemPath, ManifestStatus};
+    use sha2::{Digest, Sha256};
+
+    fn pvss() -> PVSS {
+        PVSS {
+            product: "XYZ".into(),
+            security: "green".into(),
+            ..PVSS::default()
+        }
+    }
+
+    let mut tree = MemoryCollateralTree::new();
+    let mut digests = Vec::new();
+    for item in ["target_info.json", "decode-defs/MCA/1/layout.csv"] {
+        let path = format!("{COLLATERAL_TREE_PATH}/XYZ/all/all/green/crashlog/{item}");
+        let content = std::fs::read(path).unwrap();
+        digests.push(format!("\"{item}\": \"{:x}\"", Sha256::digest(&content)));
+        tree.add_item(pvss(), item, content);
+    }
+    let manifest = format!(
+        "{{\"pvss\": \"XYZ/all/all/green\", \"items\": {{{}}}}}",
+        digests.join(", ")
+    );
+    let key = SigningKey::from_bytes(&[0x42; 32]);
+    let signature = key.sign(manifest.as_bytes()).to_bytes().to_vec();
+    tree.add_item(pvss(), "manifest.json", manifest);
+    tree.add_item(pvss(), "manifest.json.sig", signature);
+
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let mut fs = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let root = crashlog.decode(&mut fs);
+
+    let public_key = key.verifying_key().to_bytes();
+    let mut cm = CollateralManager::with_verifying_key(tree.clone(), &public_key).unwrap();
+    assert!(cm.verification().unwrap().is_verified());
+    assert_eq!(cm.target_info.get(&0x07A).unwrap().product, "XYZ");
+    assert_eq!(crashlog.decode(&mut cm), root);
+
+    // A tampered decode definition is refused.
+    let original = tree
+        .get(&pvss(), &"decode-defs/MCA/1/layout.csv".into())
+        .unwrap();
+    tree.add_item(
+        pvss(),
+        "decode-defs/MCA/1/layout.csv",
+        "name;offset;size;description\nmca.foo;0;8;",
+    );
+    let mut cm = CollateralManager::with_verifying_key(tree.clone(), &public_key).unwrap();
+    assert!(matches!(
+        cm.get_item_with_pvss(pvss(), "decode-defs/MCA/1/layout.csv"),
+        Err(Error::UnverifiedCollateral(..))
+    ));
+    assert_ne!(crashlog.decode(&mut cm), root);
+
+    // The resolved items must also match the verified manifest.
+    let mut partial = MemoryCollateralTree::new();
+    for item in ["target_info.json", "manifest.json", "manifest.json.sig"] {
+        partial.add_item(pvss(), item, tree.get(&pvss(), &item.into()).unwrap());
+    }
+    for (content, verified) in [
+        (original, true),
+        (
+            b"name;offset;size;description\nmca.foo;0;8;".to_vec(),
+            false,
+        ),
+    ] {
+        let mut cm = CollateralManager::with_verifying_key(partial.clone(), &public_key).unwrap();
+        cm.set_resolver(move |_: &Header, _: &ItemPath| Some(content.clone()));
+        assert_eq!(crashlog.decode(&mut cm) == root, verified);
+    }
+
+    // A manifest copied to another product is refused.
+    let other = PVSS {
+        product: "ABC".into(),
+        ..pvss()
+    };
+    let mut copied = MemoryCollateralTree::new();
+    for item in ["target_info.json", "manifest.json", "manifest.json.sig"] {
+        copied.add_item(
+            other.clone(),
+            item,
+            partial.get(&pvss(), &item.into()).unwrap(),
+        );
+    }
+    let mut cm = CollateralManager::with_verifying_key(copied, &public_key).unwrap();
+    let verification = cm.verification().unwrap();
+    assert!(!verification.is_verified());
+    assert_eq!(
+        verification.manifests().get(&other),
+        Some(&ManifestStatus::Mismatch("XYZ/all/all/green".into()))
+    );
+    assert!(matches!(
+        cm.get_item_with_pvss(other, "target_info.json"),
+        Err(Error::UnverifiedCollateral(..))
+    ));
+
+    // The manifest is not signed with the expected key.
+    let other_key = SigningKey::from_bytes(&[0x24; 32])
+        .verifying_key()
+        .to_bytes();
+    let cm = CollateralManager::with_verifying_key(tree, &other_key).unwrap();
+    let verification = cm.verification().unwrap();
+    assert!(!verification.is_verified());
+    assert_eq!(
+        verification.manifests().get(&pvss()),
+        Some(&ManifestStatus::InvalidSignature)
+    );
+    assert!(cm.target_info.is_empty());
+}
+
+#[test]
+fn stepping_and_security() {
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let pvss = |stepping: &str, security: &str| PVSS {
+        product: "XYZ".into(),
+        stepping: stepping.into(),
+        security: security.into(),
+        ..PVSS::default()
+    };
+    let layout = |name: &str| format!("name;offset;size;description\nmca.{name};0;8;");
+    let decode = |target_info: &str| {
+        let mut tree = MemoryCollateralTree::new();
+        tree.add_item(pvss("all", "all"), "target_info.json", target_info);
+        for (stepping, security) in [("all", "red"), ("all", "green"), ("B0", "green")] {
+            let path = "decode-defs/MCA/1/layout.csv";
+            let content = layout(&format!("{stepping}_{security}").to_lowercase());
+            tree.add_item(pvss(stepping, security), path, content);
+        }
+        let mut cm = CollateralManager::new(tree).unwrap();
+        let root = crashlog.decode(&mut cm);
+        root.get_by_path("mca")
+            .and_then(|mca| mca.children().next())
+            .map(|field| field.name.clone())
+    };
+
+    let target_info = r#"{"product": "XYZ", "product_id": "0x7A"}"#;
+    assert_eq!(decode(target_info).as_deref(), Some("all_red"));
+    let target_info = r#"{"product": "XYZ", "product_id": "0x7A", "stepping": "B0"}"#;
+    assert_eq!(decode(target_info).as_deref(), Some("b0_green"));
+    let target_info = r#"{"product": "XYZ", "product_id": "0x7A", "stepping": "A0"}"#;
+    assert_eq!(decode(target_info).as_deref(), Some("all_red"));
+    let target_info = r#"{"product": "XYZ", "product_id": "0x7A", "security": "green"}"#;
+    assert_eq!(decode(target_info).as_deref(), Some("all_green"));
+}
+
+#[test]
+fn resolver() {
+    use intel_crashlog::collateral::ItemPath;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let requests = Rc::new(RefCell::new(Vec::new()));
+
+    let mut fs = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let root = crashlog.decode(&mut fs);
+
+    // The resolver is not invoked when the decode definitions are available.
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    let log = requests.clone();
+    cm.set_resolver(move |_: &Header, path: &ItemPath| {
+        log.borrow_mut().push(path.to_string());
+        None
+    });
+    assert_eq!(crashlog.decode(&mut cm), root);
+    assert!(requests.borrow().is_empty());
+
+    // The decode definitions of the unknown products are requested from the resolver.
+    let mut cm = CollateralManager::new(MemoryCollateralTree::new()).unwrap();
+    let log = requests.clone();
+    cm.set_resolver(move |header: &Header, path: &ItemPath| {
+        log.borrow_mut().push(path.to_string());
+        let item = format!("{COLLATERAL_TREE_PATH}/XYZ/all/all/green/crashlog/{path}");
+        if header.product_id() == 0x07A {
+            std::fs::read(item).ok()
+        } else {
+            None
+        }
+    });
+    let resolved = crashlog.decode(&mut cm);
+    assert_eq!(
+        resolved.get_value_by_path("mca.hdr.version.revision"),
+        root.get_value_by_path("mca.hdr.version.revision")
+    );
+    assert_eq!(
+        *requests.borrow(),
+        ["target_info.json", "decode-defs/MCA/1/layout.csv"]
+    );
+
+    // The items that cannot be resolved are only requested once.
+    requests.borrow_mut().clear();
+    let mut cm = CollateralManager::new(MemoryCollateralTree::new()).unwrap();
+    let log = requests.clone();
+    cm.set_resolver(move |_: &Header, path: &ItemPath| {
+        log.borrow_mut().push(path.to_string());
+        None
+    });
+    crashlog.decode(&mut cm);
+    let count = requests.borrow().len();
+    assert!(count > 0);
+    crashlog.decode(&mut cm);
+    assert_eq!(requests.borrow().len(), count);
+}
+
+#[test]
+fn extended_section() {
+    let pvss = PVSS {
+        product: "XYZ".into(),
+        security: "green".into(),
+        ..PVSS::default()
+    };
+    let mut tree = MemoryCollateralTree::new();
+    tree.add_item(
+        pvss.clone(),
+        "target_info.json",
+        r#"{"product": "XYZ", "product_id": "0x7A"}"#,
+    );
+    tree.add_item(
+        pvss.clone(),
+        "decode-defs/MCA/1/layout.csv",
+        "name;offset;size;description\nmca.hdr.size;32;16;",
+    );
+
+    // Append an extended section of two dwords to the MCA record.
+    let mut data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let offset = data.len();
+    data[6..8].copy_from_slice(&2u16.to_le_bytes());
+    data.extend_from_slice(&[0x12, 0x34, 0, 0, 0, 0, 0, 0]);
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let header = &crashlog.regions[0].records[0].header;
+    assert_eq!(header.extended_record_offset(), Some(offset));
+
+    let mut cm = CollateralManager::new(tree.clone()).unwrap();
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.get_value_by_path("mca.hdr.size"), Some(0xd0));
+    assert!(root.get_by_path("mca.ext").is_none());
+
+    // The extended section is decoded relative to its offset.
+    tree.add_item(
+        pvss,
+        "decode-defs/MCA/1/layout_ext.csv",
+        "name;offset;size;description\nmca.ext.foo;0;8;\nmca.ext.bar;8;8;",
+    );
+    let mut cm = CollateralManager::new(tree).unwrap();
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.get_value_by_path("mca.hdr.size"), Some(0xd0));
+    assert_eq!(root.get_value_by_path("mca.ext.foo"), Some(0x12));
+    assert_eq!(root.get_value_by_path("mca.ext.bar"), Some(0x34));
+}
+
+#[test]
+fn timestamp_time() {
+    let pvss = PVSS {
+        product: "XYZ".into(),
+        ..PVSS::default()
+    };
+    let mut tree = MemoryCollateralTree::new();
+    tree.add_item(
+        pvss.clone(),
+        "target_info.json",
+        r#"{"product": "XYZ", "product_id": "0x7A", "timestamp_frequency": 100}"#,
+    );
+    tree.add_item(
+        pvss.clone(),
+        "decode-defs/MCA/1/layout.csv",
+        "name;offset;size;description\nmca.hdr.timestamp;64;64;",
+    );
+
+    let data = HeaderBuilder::new(record_types::MCA)
+        .product_id(0x7a)
+        .revision(1)
+        .type2()
+        .timestamp(372350)
+        .payload_size(8)
+        .build_record()
+        .unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+
+    // The date is unknown without the epoch of the product.
+    let mut cm = CollateralManager::new(tree.clone()).unwrap();
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.get_value_by_path("mca.hdr.timestamp"), Some(372350));
+    assert!(root.get_by_path("mca.hdr.time").is_none());
+
+    tree.add_item(
+        pvss,
+        "target_info.json",
+        r#"{"product": "XYZ", "product_id": "0x7A", "timestamp_frequency": 100,
+            "timestamp_epoch": 1735689600}"#,
+    );
+    let mut cm = CollateralManager::new(tree).unwrap();
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.get_value_by_path("mca.hdr.timestamp"), Some(372350));
+    assert_eq!(
+        root.get_str("mca.hdr.time").unwrap(),
+        "2025-01-01T01:02:03.500000000Z"
+    );
+}
+
+#[test]
+fn reasons() {
+    let pvss = PVSS {
+        product: "XYZ".into(),
+        ..PVSS::default()
+    };
+    let mut tree = MemoryCollateralTree::new();
+    tree.add_item(
+        pvss.clone(),
+        "target_info.json",
+        r#"{"product": "XYZ", "product_id": "0x7A",
+            "reason": {"0": "three_strike", "1": "machine_check", "4": "warm_reset", "x": "foo"}}"#,
+    );
+    tree.add_item(
+        pvss,
+        "decode-defs/MCA/1/layout.csv",
+        "name;offset;size;description\nmca.hdr.reason;160;32;",
+    );
+    let mut cm = CollateralManager::new(tree).unwrap();
+
+    let data = HeaderBuilder::new(record_types::MCA)
+        .product_id(0x7a)
+        .revision(1)
+        .type2()
+        .reason(0x13)
+        .payload_size(8)
+        .build_record()
+        .unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let header = &crashlog.regions[0].records[0].header;
+    assert_eq!(
+        header.reasons(&cm),
+        ["three_strike", "machine_check", "warm_reset"]
+    );
+
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.get_value_by_path("mca.hdr.reason"), Some(0x13));
+    assert!(root.get_bool("mca.hdr.reason.three_strike").unwrap());
+    assert!(root.get_bool("mca.hdr.reason.machine_check").unwrap());
+    assert!(root.get_bool("mca.hdr.reason.warm_reset").unwrap());
+    assert_eq!(
+        root.get_by_path("mca.hdr.reason")
+            .unwrap()
+            .children()
+            .count(),
+        3
+    );
+}
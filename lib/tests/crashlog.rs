@@ -1,7 +1,14 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use intel_crashlog::CrashLogBuilder;
+use intel_crashlog::analysis::{self, FailureClass};
+use intel_crashlog::header::{HeaderBuilder, record_types};
 use intel_crashlog::prelude::*;
+use intel_crashlog::record::{DecodeOptions, SortKey, SplitKey};
+use intel_crashlog::region::RegionBuilder;
+use intel_crashlog::severity::Severity;
+use intel_crashlog::timeline::Anchor;
 
 #[test]
 fn crashlog_from_slice() {
@@ -30,6 +37,54 @@ fn crashlog_decode() {
     assert!(crashlog_agent.is_some());
 }
 
+#[cfg(feature = "rayon")]
+#[test]
+fn crashlog_decode_parallel() {
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+    cm.add_post_decode_hook(|node, record| {
+        node.add(Node::field("size", record.data.len() as u64));
+    });
+
+    for sample in [
+        "tests/samples/dummy.bert",
+        "tests/samples/three_strike_timeout_box.crashlog",
+    ] {
+        let data = std::fs::read(sample).unwrap();
+        let crashlog = CrashLog::from_slice(&data).unwrap();
+        assert_eq!(crashlog.decode_parallel(&mut cm), crashlog.decode(&mut cm));
+    }
+}
+
+#[test]
+fn crashlog_decode_filtered() {
+    let data = std::fs::read("tests/samples/three_strike_timeout_box.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+    cm.set_decode_options(DecodeOptions {
+        record_types: vec!["PCORE".into()],
+        ..DecodeOptions::default()
+    });
+    let root = crashlog.decode(&mut cm);
+    assert!(root.get_by_path("processors.cpu0.die8.pcore").is_some());
+    assert!(root.get_by_path("processors.cpu0.die8.box").is_none());
+
+    cm.set_decode_options(DecodeOptions {
+        dies: vec![8],
+        ..DecodeOptions::default()
+    });
+    let root = crashlog.decode(&mut cm);
+    assert!(root.get_by_path("processors.cpu0.die8.pcore").is_some());
+
+    cm.set_decode_options(DecodeOptions {
+        sockets: vec![0],
+        dies: vec![9],
+        ..DecodeOptions::default()
+    });
+    let root = crashlog.decode(&mut cm);
+    assert_eq!(root.children().count(), 0);
+}
+
 #[test]
 fn invalid_box_record() {
     let data = [0x0, 0x0, 0x0, 0x3d, 0x1, 0x0, 0x0, 0x0, 0x0, 0xa];
@@ -93,3 +148,524 @@ fn legacy_type0_box() {
 
     assert_eq!(box_revision.kind, NodeType::Field { value: 0x81 });
 }
+
+#[test]
+fn sort_records() {
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let mut crashlog = CrashLog::from_slice(&bert).unwrap();
+
+    let record_types = |crashlog: &CrashLog| {
+        crashlog
+            .regions
+            .iter()
+            .flat_map(|region| region.records.iter())
+            .map(|record| record.header.version.record_type)
+            .collect::<Vec<u8>>()
+    };
+
+    crashlog.sort_records_by(SortKey::RecordType);
+    assert_eq!(record_types(&crashlog), vec![0x1c, 0x3e]);
+
+    crashlog.sort_records_by(SortKey::SocketDie);
+    assert_eq!(record_types(&crashlog), vec![0x3e, 0x1c]);
+
+    // The records stay in their region.
+    let mut crashlog = CrashLog::from_slice(&bert).unwrap();
+    let mca = crashlog.regions[0].records.remove(0);
+    crashlog.regions[1].records.push(mca);
+    crashlog.sort_records_by(SortKey::RecordType);
+    assert_eq!(crashlog.regions.len(), 2);
+    assert!(crashlog.regions[0].records.is_empty());
+    assert_eq!(crashlog.regions[1].records.len(), 2);
+    assert_eq!(record_types(&crashlog), vec![0x1c, 0x3e]);
+}
+
+#[test]
+fn rebuild() {
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let mut crashlog = CrashLog::from_slice(&bert).unwrap();
+
+    // Drop the MCA record and truncate the Crash Log agent record.
+    crashlog.regions[0].records.clear();
+    crashlog.regions[1].records[0].data.truncate(0x1e);
+    crashlog.rebuild().unwrap();
+
+    assert_eq!(crashlog.regions.len(), 1);
+    let header = &crashlog.regions[0].records[0].header;
+    assert_eq!(header.record_size(), 0x20);
+
+    let crashlog = CrashLog::from_slice(&crashlog.to_bytes()).unwrap();
+    assert_eq!(crashlog.regions.len(), 1);
+    assert_eq!(crashlog.regions[0].records[0].data.len(), 0x20);
+}
+
+#[test]
+fn rebuild_truncated_extended_section() {
+    let record = HeaderBuilder::new(record_types::MCA)
+        .product_id(0x7a)
+        .revision(0x10)
+        .type6(0, 0)
+        .payload_size(0x20)
+        .extended_size(0x40)
+        .build_record()
+        .unwrap();
+    let mut crashlog = CrashLog::from_slice(&record).unwrap();
+
+    // The extended section doesn't fit in the truncated record anymore.
+    crashlog.regions[0].records[0].data.truncate(0x30);
+    assert!(matches!(crashlog.rebuild(), Err(Error::InvalidCrashLog)));
+}
+
+#[test]
+fn dedup() {
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let region = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+    let mut dump: Vec<u8> = [0x11u32, 0x1234, 0, region.len() as u32 / 4]
+        .iter()
+        .flat_map(|dword| dword.to_le_bytes())
+        .collect();
+    dump.extend_from_slice(&region);
+
+    // The Crash Log agent record is extracted from both BERT and PMT.
+    let mut crashlog = CrashLog::from_slice(&bert).unwrap();
+    crashlog
+        .regions
+        .append(&mut CrashLog::from_slice(&dump).unwrap().regions);
+    assert_eq!(crashlog.regions.len(), 3);
+
+    assert_eq!(crashlog.dedup(), 1);
+    assert_eq!(crashlog.regions.len(), 2);
+    let record_types: Vec<&str> = crashlog
+        .regions
+        .iter()
+        .flat_map(|region| region.records.iter())
+        .map(|record| record.header.record_type().unwrap())
+        .collect();
+    assert_eq!(record_types, ["MCA", "CRASHLOG_AGENT"]);
+
+    assert_eq!(crashlog.dedup(), 0);
+}
+
+#[test]
+fn rebuild_without_records() {
+    let data = [0x0, 0x0, 0x0, 0x3d, 0x1, 0x0, 0x0, 0x0];
+    let mut crashlog = CrashLog::from_slice(&data).unwrap();
+    assert!(matches!(crashlog.rebuild(), Err(Error::InvalidCrashLog)));
+}
+
+#[test]
+fn minimize() {
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+    let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+
+    let path = "pcore.core0.thread0.thread.arch_state.mca.bank3.status";
+    let mut reproduces = |crashlog: &CrashLog| {
+        crashlog.decode(&mut cm).get_value_by_path(path) == Some(0xbe000000e1840400)
+    };
+    let minimized = crashlog.minimize(&mut reproduces).unwrap();
+    assert!(reproduces(&minimized));
+
+    // Only the PCORE record is kept, truncated to the shortest length that holds the field.
+    assert_eq!(crashlog.regions.len(), 2);
+    assert_eq!(minimized.regions.len(), 1);
+    let records = &minimized.regions[0].records;
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].header.record_type().unwrap(), "PCORE");
+    assert_eq!(crashlog.regions[1].records[0].data.len(), 1168);
+    assert_eq!(records[0].data.len(), 520);
+
+    assert!(crashlog.minimize(|_| false).is_none());
+}
+
+#[test]
+fn split_by_socket_and_die() {
+    let record = |die_id: u8, socket_id: u8| {
+        let mut record = vec![0; 0x20];
+        record[0..4].copy_from_slice(&0x1c001601u32.to_le_bytes());
+        record[4] = 0x8;
+        record[24] = die_id;
+        record[25] = socket_id;
+        record
+    };
+    let data = [record(0, 0), record(1, 0), record(0, 1)].concat();
+
+    let crashlogs = CrashLog::from_slice(&data)
+        .unwrap()
+        .split_by(SplitKey::Socket);
+    assert_eq!(
+        crashlogs.keys().copied().collect::<Vec<_>>(),
+        vec![(0, None), (1, None)]
+    );
+    assert_eq!(crashlogs[&(0, None)].regions[0].records.len(), 2);
+    assert_eq!(crashlogs[&(1, None)].regions[0].records.len(), 1);
+
+    let crashlogs = CrashLog::from_slice(&data).unwrap().split_by(SplitKey::Die);
+    assert_eq!(
+        crashlogs.keys().copied().collect::<Vec<_>>(),
+        vec![(0, Some(0)), (0, Some(1)), (1, Some(0))]
+    );
+}
+
+#[test]
+fn pmt_dump() {
+    let region = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+    let mut dump: Vec<u8> = [0x11u32, 0x1234, 0, region.len() as u32 / 4]
+        .iter()
+        .flat_map(|dword| dword.to_le_bytes())
+        .collect();
+    dump.extend_from_slice(&region);
+    let dump_entry = dump.clone();
+
+    let crashlog = CrashLog::from_slice(&dump).unwrap();
+    assert_eq!(crashlog.regions.len(), 1);
+    assert_eq!(crashlog.regions[0].records[0].data, region);
+    assert_eq!(crashlog.metadata.pmt_endpoints[0].guid, Some(0x1234));
+
+    // The endpoints stay aligned with the regions when a region cannot be parsed.
+    let mut dump: Vec<u8> = [0x11u32, 0x5678, 0, 8]
+        .iter()
+        .flat_map(|dword| dword.to_le_bytes())
+        .collect();
+    dump.extend_from_slice(&[0; 32]);
+    dump.extend_from_slice(&dump_entry);
+    let crashlog = CrashLog::from_slice(&dump).unwrap();
+    assert_eq!(crashlog.regions.len(), 1);
+    assert_eq!(crashlog.metadata.pmt_endpoints.len(), 1);
+    assert_eq!(crashlog.metadata.pmt_endpoints[0].name, "crashlog1");
+    assert_eq!(crashlog.metadata.pmt_endpoints[0].guid, Some(0x1234));
+}
+
+#[test]
+fn align_timestamps() {
+    let record = |record_type: u32, socket_id: u8, timestamp: u64| {
+        let mut record = vec![0; 0x20];
+        let version = (record_type << 24) | (0x7a << 12) | (6 << 8) | 2;
+        record[0..4].copy_from_slice(&version.to_le_bytes());
+        record[4] = 0x8;
+        record[8..16].copy_from_slice(&timestamp.to_le_bytes());
+        record[25] = socket_id;
+        record
+    };
+    let data = [
+        record(0x3e, 0, 1000),
+        record(0x1c, 0, 1100),
+        record(0x3e, 1, 5000),
+        record(0x1c, 1, 5050),
+    ]
+    .concat();
+
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let cm = CollateralManager::file_system_tree(std::path::Path::new("tests/collateral")).unwrap();
+    let timestamps = crashlog.align_timestamps(&cm, Anchor::RecordType(0x1c));
+
+    assert_eq!(
+        timestamps
+            .iter()
+            .map(|timestamp| (timestamp.socket_id, timestamp.ticks, timestamp.nanoseconds))
+            .collect::<Vec<_>>(),
+        vec![
+            (0, -100, Some(-1000)),
+            (0, 0, Some(0)),
+            (1, -50, Some(-500)),
+            (1, 0, Some(0))
+        ]
+    );
+    assert!(timestamps.iter().all(|timestamp| !timestamp.uncertain));
+
+    let timestamps = crashlog.align_timestamps(&cm, Anchor::RecordType(0x3d));
+    assert_eq!(timestamps[1].ticks, 100);
+    assert!(timestamps.iter().all(|timestamp| timestamp.uncertain));
+}
+
+#[test]
+fn windows_report() {
+    let cper = std::fs::read("tests/samples/cper.whea").unwrap();
+    let report = [[0x42; 0x100].as_slice(), &cper, &[0x0; 0x10]].concat();
+
+    let crashlogs = CrashLog::from_windows_report(&report).unwrap();
+    assert_eq!(crashlogs.len(), 1);
+    assert_eq!(crashlogs[0].regions.len(), 3);
+
+    let crashlogs = CrashLog::from_windows_report(&[0x42; 0x100]);
+    assert!(matches!(crashlogs, Err(Error::NoCrashLogFound)));
+}
+
+#[test]
+fn windows_dump() {
+    let cper = std::fs::read("tests/samples/cper.whea").unwrap();
+    let dump = [
+        b"PAGEDU64".as_slice(),
+        &[0x0; 0x1000],
+        &cper,
+        &[0x0; 0x10],
+        &cper,
+    ]
+    .concat();
+
+    let crashlogs = CrashLog::from_windows_report(&dump).unwrap();
+    assert_eq!(crashlogs.len(), 2);
+    let crashlogs = CrashLog::from_windows_dump(&dump).unwrap();
+    assert_eq!(crashlogs.len(), 1);
+    assert_eq!(crashlogs[0].regions.len(), 3);
+
+    let crashlogs = CrashLog::from_windows_dump(b"PAGEDU64");
+    assert!(matches!(crashlogs, Err(Error::NoCrashLogFound)));
+}
+
+#[test]
+fn carve_from_slice() {
+    let mca = std::fs::read("tests/samples/dummy_mca_rev2.crashlog").unwrap();
+    let agent = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+    let blob = [
+        [0xff; 0x104].as_slice(),
+        &mca,
+        &[0x0; 0x8],
+        &[0xff; 0x20],
+        &agent,
+        &[0xff; 0x10],
+    ]
+    .concat();
+
+    let crashlog = CrashLog::carve_from_slice(&blob).unwrap();
+    assert_eq!(crashlog.regions.len(), 2);
+    // The trailing termination markers are not part of the record
+    assert_eq!(crashlog.regions[0].to_bytes(), mca[..0x300]);
+    assert_eq!(crashlog.regions[1].to_bytes(), agent);
+
+    let crashlog = CrashLog::carve_from_slice(&[0xff; 0x100]);
+    assert!(matches!(crashlog, Err(Error::NoCrashLogFound)));
+}
+
+#[test]
+fn severity() {
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+
+    let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    assert_eq!(crashlog.severity(&mut cm), Severity::Fatal);
+    let cper = crashlog.to_bytes_with_cm(&mut cm);
+    assert_eq!(u32::from_le_bytes(cper[12..16].try_into().unwrap()), 1);
+
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    assert_eq!(crashlog.severity(&mut cm), Severity::Informational);
+    let cper = crashlog.to_bytes_with_cm(&mut cm);
+    assert_eq!(u32::from_le_bytes(cper[12..16].try_into().unwrap()), 3);
+}
+
+#[test]
+fn triage() {
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+
+    let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let findings = crashlog.triage(&mut cm);
+    assert_eq!(findings[0].class, FailureClass::ThreeStrikeTimeout);
+    assert!(findings[0].evidence.contains(&(
+        "pcore.core0.thread0.thread.arch_state.mca.bank3.status".to_string(),
+        0xbe000000e1840400
+    )));
+}
+
+#[test]
+fn three_strike() {
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+
+    let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let threads = analysis::three_strike(&crashlog, &mut cm);
+    let thread = threads
+        .iter()
+        .find(|thread| thread.path == "pcore.core0.thread0.thread")
+        .unwrap();
+    assert_eq!(thread.core_id, Some(0));
+    assert_eq!(thread.thread_id, Some(0));
+    assert_eq!(thread.module_id, None);
+    assert_eq!(
+        thread.bank,
+        "pcore.core0.thread0.thread.arch_state.mca.bank3"
+    );
+    assert_eq!(thread.lip, Some(0xfffff80577036530));
+
+    // The same threads are identified in the decoded register tree.
+    assert!(analysis::stuck_threads(&crashlog.decode(&mut cm)).contains(thread));
+}
+
+#[cfg(feature = "compression")]
+#[test]
+fn compressed_crashlog() {
+    use intel_crashlog::compression;
+    use std::io::Write;
+
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let expected = CrashLog::from_slice(&bert).unwrap().to_bytes();
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(&bert).unwrap();
+    let gzip = encoder.finish().unwrap();
+    let crashlog = CrashLog::from_slice(&gzip).unwrap();
+    assert_eq!(crashlog.to_bytes(), expected);
+
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    writer.start_file("README.txt", options).unwrap();
+    writer.write_all(b"Support bundle").unwrap();
+    writer.start_file("dummy.bert.gz", options).unwrap();
+    writer.write_all(&gzip).unwrap();
+    let zip = writer.finish().unwrap().into_inner();
+    let crashlog = CrashLog::from_slice(&zip).unwrap();
+    assert_eq!(crashlog.to_bytes(), expected);
+
+    // The binaries that cannot be decompressed are decoded as is.
+    let truncated = &gzip[..gzip.len() / 2];
+    assert!(matches!(
+        compression::decompress(truncated),
+        Err(Error::InvalidArchive(_))
+    ));
+    assert!(matches!(
+        CrashLog::from_slice(truncated),
+        Err(Error::InvalidHeaderType(_))
+    ));
+
+    // The nested archives are decompressed up to the nesting limit.
+    let compress = |data: &[u8]| {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    };
+    let mut nested = bert.clone();
+    for _ in 0..compression::MAX_ARCHIVE_NESTING {
+        nested = compress(&nested);
+    }
+    assert_eq!(CrashLog::from_slice(&nested).unwrap().to_bytes(), expected);
+    assert!(CrashLog::from_slice(&compress(&nested)).is_err());
+
+    // The decompressed data is limited in size.
+    let bomb = compress(&vec![0; compression::MAX_DECOMPRESSED_SIZE + 1]);
+    assert!(matches!(
+        compression::decompress(&bomb),
+        Err(Error::InvalidArchive(_))
+    ));
+    assert!(CrashLog::from_slice(&bomb).is_err());
+}
+
+#[cfg(feature = "physical_memory")]
+#[test]
+fn physical_memory() {
+    use intel_crashlog::extract::PhysicalMemory;
+
+    struct Memory(Vec<u8>);
+
+    impl PhysicalMemory for Memory {
+        fn read(&mut self, address: u64, buf: &mut [u8]) -> Result<(), Error> {
+            let address = address as usize;
+            let src = self.0.get(address..address + buf.len());
+            buf.copy_from_slice(src.ok_or(Error::InvalidBootErrorRecordRegion)?);
+            Ok(())
+        }
+    }
+
+    const RSDP: usize = 0xe0040;
+    const XSDT: usize = 0x100000;
+    const BERT: usize = 0x100100;
+    const BERR: usize = 0x101000;
+
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let (table, berr) = bert.split_at(48);
+    let mut memory = vec![0; BERR + berr.len()];
+
+    let mut rsdp = [b"RSD PTR ".as_slice(), &[0; 7], &[2]].concat();
+    rsdp.extend_from_slice(&0u32.to_le_bytes());
+    rsdp.extend_from_slice(&36u32.to_le_bytes());
+    rsdp.extend_from_slice(&(XSDT as u64).to_le_bytes());
+    memory[RSDP..RSDP + rsdp.len()].copy_from_slice(&rsdp);
+
+    memory[XSDT..XSDT + 4].copy_from_slice(b"XSDT");
+    memory[XSDT + 4..XSDT + 8].copy_from_slice(&44u32.to_le_bytes());
+    memory[XSDT + 36..XSDT + 44].copy_from_slice(&(BERT as u64).to_le_bytes());
+
+    memory[BERT..BERT + 48].copy_from_slice(table);
+    memory[BERT + 4..BERT + 8].copy_from_slice(&48u32.to_le_bytes());
+    memory[BERT + 36..BERT + 40].copy_from_slice(&(berr.len() as u32).to_le_bytes());
+    memory[BERT + 40..BERT + 48].copy_from_slice(&(BERR as u64).to_le_bytes());
+    memory[BERR..].copy_from_slice(berr);
+
+    let mut memory = Memory(memory);
+    let crashlog = CrashLog::from_rsdp(&mut memory, RSDP as u64).unwrap();
+    assert_eq!(
+        crashlog.to_bytes(),
+        CrashLog::from_slice(&bert).unwrap().to_bytes()
+    );
+
+    // The RSDP is only found in the BIOS area when its checksum is valid.
+    assert!(matches!(
+        CrashLog::from_physical_memory(&mut memory),
+        Err(Error::NoCrashLogFound)
+    ));
+}
+
+#[test]
+fn builder() {
+    let mca = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let agent = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+
+    let crashlog = CrashLogBuilder::new()
+        .add_region(RegionBuilder::new().add_record(mca.clone()))
+        .add_region(
+            RegionBuilder::new()
+                .add_record(agent.clone())
+                .add_record(mca.clone()),
+        )
+        .build()
+        .unwrap();
+    assert_eq!(crashlog.regions.len(), 2);
+    assert_eq!(crashlog.regions[1].records[0].data, agent);
+
+    let crashlog = CrashLog::from_slice(&crashlog.to_bytes()).unwrap();
+    let records: Vec<&str> = crashlog
+        .regions
+        .iter()
+        .flat_map(|region| region.records.iter())
+        .map(|record| record.header.record_type().unwrap())
+        .collect();
+    assert_eq!(records, ["MCA", "CRASHLOG_AGENT", "MCA"]);
+
+    assert!(matches!(
+        CrashLogBuilder::new().build(),
+        Err(Error::InvalidCrashLog)
+    ));
+}
+
+#[test]
+fn split_box_records() {
+    let record = |record_type: u8, die_id: u8, payload: &[u8]| {
+        let mut record = vec![0; 28];
+        record[0..4].copy_from_slice(&(0x1601 | (record_type as u32) << 24).to_le_bytes());
+        record[24] = die_id;
+        record.extend_from_slice(payload);
+        record[4] = (record.len() / 4) as u8;
+        record
+    };
+    let children = [record(0x1c, 1, &[]), record(0x1c, 2, &[])].concat();
+    let data = [record(0x3d, 0, &children), record(0x1c, 3, &[])].concat();
+
+    let crashlogs = CrashLog::from_slice(&data).unwrap().split_by(SplitKey::Die);
+    assert_eq!(
+        crashlogs.keys().copied().collect::<Vec<_>>(),
+        vec![(0, Some(0)), (0, Some(3))]
+    );
+
+    // The Box record carries its child region.
+    let crashlog = &crashlogs[&(0, Some(0))];
+    assert_eq!(crashlog.regions.len(), 2);
+    assert_eq!(crashlog.regions[1].records.len(), 2);
+    let bytes: Vec<u8> = crashlog
+        .regions
+        .iter()
+        .flat_map(|region| region.records.iter())
+        .filter(|record| record.context.parent_header.is_none())
+        .flat_map(|record| record.data.iter().copied())
+        .collect();
+    assert_eq!(bytes, data[..28 + children.len()]);
+}
@@ -1,6 +1,9 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use intel_crashlog::header::{
+    AgentVersion, HeaderBuilder, HeaderType, RecordSize, Version, record_types,
+};
 use intel_crashlog::prelude::*;
 use std::fs;
 use std::path::Path;
@@ -42,7 +45,7 @@ fn decode_header_product() {
 
     assert_eq!(
         header.to_string(),
-        "MCA - (product_id=0x7a, record_type=0x3e, revision=0x8, ..)"
+        "MCA - (product_id=0x7a, record_type=0x3e, revision=0x8)"
     )
 }
 
@@ -70,6 +73,12 @@ fn decode_die_id_header() {
 
     let die = header.die(&cm).unwrap();
     assert_eq!(die, "io1");
+
+    assert_eq!(
+        header.to_string(),
+        "MCA - (product_id=0x7a, record_type=0x3e, revision=0x2, die_id=1, socket_id=0, \
+         timestamp=0x0, agent_version=0x0, reason=0x0, collection_complete=false)"
+    );
 }
 
 #[test]
@@ -83,3 +92,247 @@ fn decode_legacy_header_type0() {
     let die_id = header.die_id().unwrap();
     assert_eq!(die_id, 10);
 }
+
+#[test]
+fn decode_large_record_size() {
+    // 0x3e07a610
+    let mut data = vec![0; 32];
+    data[0..4].copy_from_slice(&[0x10, 0xa6, 0x07, 0x3e]);
+    data[4..8].copy_from_slice(&[0xff, 0xff, 0xff, 0xff]);
+    let header = Header::from_slice(&data).unwrap().unwrap();
+
+    // The sizes are not truncated when the main and extended sections are added up.
+    assert_eq!(header.size.record_size, 0xffff);
+    assert_eq!(header.size.extended_record_size, 0xffff);
+    assert_eq!(header.record_size(), 0x1fffe * 4);
+    assert_eq!(header.extended_record_offset(), Some(0xffff * 4));
+
+    // The size fields are 16-bit wide in all the revisions of the Type6 header.
+    data[28..32].copy_from_slice(&[0x01, 0x02, 0x00, 0x00]);
+    let header = Header::from_slice(&data).unwrap().unwrap();
+    assert_eq!(header.size.record_size, 0xffff);
+    assert_eq!(header.header_size(), 28);
+}
+
+#[test]
+fn header_to_bytes() {
+    for sample in [
+        "dummy_crashlog_agent_rev1.crashlog",
+        "dummy_mca_rev1.crashlog",
+        "dummy_mca_rev1_box.crashlog",
+        "dummy_mca_rev2.crashlog",
+        "invalid_recipe.crashlog",
+        "legacy_type0.crashlog",
+        "legacy_type0_box.crashlog",
+        "three_strike_timeout.crashlog",
+        "three_strike_timeout_box.crashlog",
+    ] {
+        let data = fs::read(Path::new("tests/samples").join(sample)).unwrap();
+        let header = Header::from_slice(&data).unwrap().unwrap();
+        assert_eq!(header.to_bytes(), data[..header.header_size()], "{sample}");
+    }
+
+    let headers = [
+        (
+            2,
+            HeaderType::Type2 {
+                timestamp: 0x1122334455667788,
+                agent_version: 0x10,
+                reason: 0x20,
+            },
+        ),
+        (
+            4,
+            HeaderType::Type4 {
+                timestamp: 0x1122334455667788,
+                agent_version: 0x10,
+                reason: 0x20,
+                whoami: 0x30,
+                misc: 0x40,
+            },
+        ),
+        (
+            5,
+            HeaderType::Type5 {
+                timestamp: 0x1122334455667788,
+                agent_version: 0x10,
+                reason: 0x20,
+                completion_status: 0x50,
+                collection_complete: true,
+                error_status: 0x60,
+            },
+        ),
+        (
+            6,
+            HeaderType::Type6 {
+                timestamp: 0x1122334455667788,
+                agent_version: 0x10,
+                reason: 0x20,
+                die_id: 3,
+                socket_id: 1,
+                completion_status_size: 2,
+                completion_status: vec![0x70, 0x80],
+                collection_complete: true,
+            },
+        ),
+    ];
+    for (header_type_value, header_type) in headers {
+        let header = Header {
+            version: Version {
+                revision: 1,
+                header_type: header_type_value,
+                product_id: 0x7a,
+                record_type: 0x3e,
+                ..Version::default()
+            },
+            size: RecordSize {
+                record_size: 0x10,
+                ..RecordSize::default()
+            },
+            header_type,
+        };
+
+        let bytes = header.to_bytes();
+        assert_eq!(bytes.len(), header.header_size());
+        let parsed = Header::from_slice(&bytes).unwrap().unwrap();
+        assert_eq!(parsed.to_bytes(), bytes);
+        assert_eq!(parsed.record_size(), 0x40);
+        assert_eq!(parsed.timestamp(), Some(0x1122334455667788));
+        assert_eq!(parsed.reason(), Some(0x20));
+    }
+}
+
+#[test]
+fn header_builder() {
+    let header = HeaderBuilder::new(record_types::MCA)
+        .product_id(0x7a)
+        .revision(1)
+        .type3()
+        .timestamp(0x1234)
+        .completion_status([0x5])
+        .collection_complete(true)
+        .payload_size(0x30)
+        .build()
+        .unwrap();
+    let parsed = Header::from_slice(&header.to_bytes()).unwrap().unwrap();
+    assert_eq!(parsed.version.as_u32(), 0x3e07a301);
+    assert_eq!(parsed.record_size(), 28 + 0x30);
+    assert_eq!(parsed.timestamp(), Some(0x1234));
+    assert_eq!(parsed.collection_complete(), Some(true));
+
+    // The size fields are rounded up to the granularity of the record type.
+    let header = HeaderBuilder::new(record_types::PCORE)
+        .product_id(0x70)
+        .type4(0x1, 0x2)
+        .payload_size(5)
+        .build()
+        .unwrap();
+    assert_eq!(header.record_size(), 32 + 5);
+    let header = HeaderBuilder::new(record_types::MCA)
+        .payload_size(5)
+        .extended_size(6)
+        .build()
+        .unwrap();
+    assert_eq!(header.size.record_size, 4);
+    assert_eq!(header.size.extended_record_size, 2);
+
+    // The size fields cannot describe the records larger than 64K dwords.
+    assert!(
+        HeaderBuilder::new(record_types::PUNIT)
+            .type3()
+            .payload_size(0x100000)
+            .build()
+            .is_err()
+    );
+
+    // The die ID of the legacy server headers is encoded into the revision.
+    let record = HeaderBuilder::new(record_types::MCA)
+        .product_id(0x2f)
+        .revision(0x70)
+        .type0_legacy_server(10, 1)
+        .completion_status([0x3])
+        .payload_size(0x10)
+        .build_record()
+        .unwrap();
+    assert_eq!(record.len(), 32 + 0x10);
+    let parsed = Header::from_slice(&record).unwrap().unwrap();
+    assert_eq!(parsed.die_id(), Some(10));
+    assert_eq!(parsed.socket_id(), 1);
+    assert_eq!(parsed.record_size(), record.len());
+
+    assert!(
+        HeaderBuilder::new(record_types::MCA)
+            .product_id(0x7a)
+            .type0_legacy_server(0, 0)
+            .build()
+            .is_err()
+    );
+    assert!(
+        HeaderBuilder::new(record_types::MCA)
+            .product_id(0x2f)
+            .type0()
+            .build()
+            .is_err()
+    );
+    assert!(HeaderBuilder::new(0x40).build().is_err());
+}
+
+#[test]
+fn unknown_header_type() {
+    // 0x3e07a901
+    let mut data = vec![0; 0x40];
+    data[0..4].copy_from_slice(&[0x01, 0xa9, 0x07, 0x3e]);
+    data[4..8].copy_from_slice(&[0x10, 0x00, 0x00, 0x00]);
+    data[8..12].copy_from_slice(&[0xaa, 0xbb, 0xcc, 0xdd]);
+    let header = Header::from_slice(&data).unwrap().unwrap();
+
+    assert!(matches!(
+        header.header_type,
+        HeaderType::Unknown { type_value: 9, .. }
+    ));
+    assert_eq!(header.record_type().unwrap(), "MCA");
+    assert_eq!(header.record_size(), 0x40);
+    assert_eq!(header.header_size(), 32);
+    assert_eq!(header.to_bytes(), data[..32]);
+    assert!(header.to_string().ends_with("header_type=9)"));
+
+    let node = Node::from(&header);
+    let NodeType::Bytes { value } = &node.get("raw").unwrap().kind else {
+        panic!("raw header bytes expected");
+    };
+    assert_eq!(value[..4], [0xaa, 0xbb, 0xcc, 0xdd]);
+
+    // The payload of the record is preserved.
+    let region = Region::from_slice(&data).unwrap();
+    assert_eq!(region.records[0].data, data);
+}
+
+#[test]
+fn agent_version() {
+    let header = HeaderBuilder::new(record_types::MCA)
+        .type6(0, 0)
+        .agent_version(0x0203_0010)
+        .build()
+        .unwrap();
+    let agent_version = header.agent_version().unwrap();
+    assert_eq!(
+        agent_version,
+        AgentVersion {
+            major: 2,
+            minor: 3,
+            build: 0x10
+        }
+    );
+    assert_eq!(agent_version.as_u32(), 0x0203_0010);
+    assert!(agent_version < AgentVersion::from(0x0210_0000));
+    assert!(agent_version > AgentVersion::from(0x0202_ffff));
+
+    let node = Node::from(&header);
+    assert_eq!(node.get_value_by_path("agent_version"), Some(0x0203_0010));
+    assert_eq!(node.get_value_by_path("agent_version.major"), Some(2));
+    assert_eq!(node.get_value_by_path("agent_version.minor"), Some(3));
+    assert_eq!(node.get_value_by_path("agent_version.build"), Some(0x10));
+
+    let header = HeaderBuilder::new(record_types::MCA).build().unwrap();
+    assert!(header.agent_version().is_none());
+}
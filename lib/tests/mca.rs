@@ -1,6 +1,9 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use intel_crashlog::analysis::mca::{
+    CacheLevel, ErrorCode, MemoryTransaction, Participation, Request,
+};
 use intel_crashlog::prelude::*;
 
 #[test]
@@ -15,3 +18,54 @@ fn lnl() {
     let status = nodes.get_by_path("mca.core7.bank0.ctl").unwrap();
     assert_eq!(status.kind, NodeType::Field { value: 0x1de });
 }
+
+#[test]
+fn machine_check_errors() {
+    let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+
+    let banks = crashlog.machine_check_errors(&mut cm);
+    let (_, bank) = banks
+        .iter()
+        .find(|(path, _)| path == "pcore.core0.thread0.thread.arch_state.mca.bank3")
+        .unwrap();
+    assert_eq!(bank.status, 0xbe000000e1840400);
+    assert_eq!(bank.error_code(), ErrorCode::InternalTimer);
+    assert_eq!(bank.mscod(), 0xe184);
+    assert!(bank.uncorrected());
+    assert!(banks.iter().all(|(_, bank)| bank.valid()));
+}
+
+#[test]
+fn mcacod() {
+    assert_eq!(
+        ErrorCode::from_mcacod(0x0090),
+        ErrorCode::MemoryController {
+            transaction: MemoryTransaction::Read,
+            channel: Some(0)
+        }
+    );
+    assert_eq!(
+        ErrorCode::from_mcacod(0x109f).to_string(),
+        "Memory controller error (read)"
+    );
+
+    let bus = ErrorCode::from_mcacod(0x0913);
+    assert_eq!(
+        bus,
+        ErrorCode::BusInterconnect {
+            participation: Participation::Source,
+            timeout: true,
+            request: Request::Read,
+            io: false,
+            level: CacheLevel::Generic,
+        }
+    );
+    assert_eq!(
+        bus.to_string(),
+        "Bus and interconnect error (generic level memory read, source, timeout)"
+    );
+
+    assert_eq!(ErrorCode::from_mcacod(0x0007), ErrorCode::Unknown(0x0007));
+}
@@ -1,6 +1,7 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
 
+use intel_crashlog::node::{ChildOrder, NodeVisitor};
 use intel_crashlog::prelude::*;
 use serde_json::json;
 
@@ -28,6 +29,30 @@ fn path() {
     assert!(root.get_by_path("foo.does_no_exist").is_none());
 }
 
+#[test]
+fn escaped_paths() {
+    let mut root = Node::root();
+    root.create_hierarchy(r"pmc.version\.major").kind = NodeType::Field { value: 1 };
+    root.create_hierarchy(r"pmc.back\\slash").kind = NodeType::Field { value: 2 };
+
+    let pmc = root.get("pmc").unwrap();
+    assert!(pmc.get("version.major").is_some());
+    assert!(pmc.get(r"back\slash").is_some());
+    assert_eq!(root.get_value_by_path(r"pmc.version\.major"), Some(1));
+    assert_eq!(root.get_value_by_path(r"pmc.back\\slash"), Some(2));
+    assert!(root.get_by_path("pmc.version.major").is_none());
+    assert!(matches!(
+        root.try_get_by_path(r"pmc.version\.minor.foo"),
+        Err(Error::MissingNode(_, ancestor)) if ancestor == "pmc"
+    ));
+
+    let paths: Vec<String> = root.walk().map(|(path, _)| path).collect();
+    assert_eq!(paths, ["pmc", r"pmc.back\\slash", r"pmc.version\.major"]);
+    for path in paths {
+        assert!(root.get_by_path(&path).is_some());
+    }
+}
+
 #[test]
 fn create_hierarchy() {
     let mut root = Node::root();
@@ -63,6 +88,164 @@ fn export_json() {
     );
 }
 
+#[test]
+fn export_json_values() {
+    let mut root = Node::root();
+    root.add(Node::signed("temperature", -5));
+    root.add(Node::boolean("valid", true));
+    let mut flag = Node::boolean("enabled", false);
+    flag.add(Node::field("mask", 3));
+    root.add(flag);
+    let json = serde_json::to_value(&root).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "crashlog_data": {
+                "temperature": "-5",
+                "valid": true,
+                "enabled": {
+                    "_value": false,
+                    "mask": "0x3"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn export_json_with_descriptions() {
+    let mut root = Node::root();
+    let section = root.create_hierarchy("foo");
+    section.description = "Foo section".into();
+    let field = root.create_hierarchy("foo.bar");
+    field.kind = NodeType::Field { value: 42 };
+    field.description = "Bar field".into();
+    let field = root.create_hierarchy("foo.baz");
+    field.kind = NodeType::Field { value: 1 };
+
+    let json = serde_json::to_value(root.with_descriptions()).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "crashlog_data": {
+                "foo": {
+                    "_description": "Foo section",
+                    "bar": {
+                        "_value": "0x2a",
+                        "_description": "Bar field"
+                    },
+                    "baz": "0x1"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn flat_csv() {
+    let mut root = Node::root();
+    let status = root.create_hierarchy("mca.bank0.status");
+    status.kind = NodeType::Field { value: 0x80 };
+    status.description = "Status \"MCi_STATUS\"".into();
+    status.add(Node::boolean("val", true));
+    root.create_hierarchy("mca.bank0.addr").kind = NodeType::Truncated;
+    root.add(Node::signed("temperature", -5));
+    root.add(Node::string("version", "1,2"));
+
+    assert_eq!(
+        root.to_flat_csv(),
+        "path,value,description
+mca.bank0.addr,truncated,
+mca.bank0.status,0x80,\"Status \"\"MCi_STATUS\"\"\"
+mca.bank0.status.val,true,
+temperature,-5,
+version,\"1,2\",
+"
+    );
+    assert_eq!(Node::root().to_flat_csv(), "path,value,description\n");
+}
+
+#[test]
+fn child_order() {
+    let mut first = Node::root();
+    first.create_hierarchy("foo.zed").kind = NodeType::Field { value: 1 };
+    first.create_hierarchy("foo.bar").kind = NodeType::Field { value: 2 };
+    let mut second = Node::root();
+    second.create_hierarchy("foo.bar").kind = NodeType::Field { value: 2 };
+    second.create_hierarchy("foo.zed").kind = NodeType::Field { value: 1 };
+
+    // The sorted output does not depend on the insertion order.
+    assert_eq!(first, second);
+    assert_eq!(
+        serde_json::to_string(&first).unwrap(),
+        serde_json::to_string(&second).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_string(&first).unwrap(),
+        r#"{"crashlog_data":{"foo":{"bar":"0x2","zed":"0x1"}}}"#
+    );
+
+    assert_eq!(
+        serde_json::to_string(&first.with_order(ChildOrder::Insertion)).unwrap(),
+        r#"{"crashlog_data":{"foo":{"zed":"0x1","bar":"0x2"}}}"#
+    );
+
+    // The merged children keep their insertion order.
+    let mut merged = Node::root();
+    merged.create_hierarchy("foo.qux").kind = NodeType::Field { value: 3 };
+    merged.merge(first);
+    assert_eq!(
+        serde_json::to_string(&merged.with_order(ChildOrder::Insertion)).unwrap(),
+        r#"{"crashlog_data":{"foo":{"qux":"0x3","zed":"0x1","bar":"0x2"}}}"#
+    );
+}
+
+#[test]
+fn retain_and_prune() {
+    let tree = || {
+        let mut root = Node::root();
+        root.create_hierarchy("mca.bank0.status").kind = NodeType::Field { value: 1 };
+        root.create_hierarchy("mca.bank0.addr").kind = NodeType::Field { value: 0x1000 };
+        root.create_hierarchy("mca.bank1.status").kind = NodeType::Field { value: 2 };
+        root.create_hierarchy("pmc.version").kind = NodeType::String {
+            value: "1.2.3".into(),
+        };
+        root.create_hierarchy("raw.data").kind = NodeType::Field { value: 0 };
+        root
+    };
+
+    let mut root = tree();
+    root.retain(&["mca.bank0.status", "pmc", "foo.bar"]);
+    assert_eq!(
+        serde_json::to_value(&root).unwrap(),
+        json!({
+            "crashlog_data": {
+                "mca": { "bank0": { "status": "0x1" } },
+                "pmc": { "version": "1.2.3" }
+            }
+        })
+    );
+
+    let mut root = tree();
+    root.retain::<&str>(&[]);
+    assert_eq!(root, Node::root());
+
+    let mut root = tree();
+    root.prune(|path, _| path == "raw" || path.ends_with(".addr"));
+    assert_eq!(
+        serde_json::to_value(&root).unwrap(),
+        json!({
+            "crashlog_data": {
+                "mca": {
+                    "bank0": { "status": "0x1" },
+                    "bank1": { "status": "0x2" }
+                },
+                "pmc": { "version": "1.2.3" }
+            }
+        })
+    );
+}
+
 #[test]
 fn merge() {
     let mut root0 = Node::root();
@@ -116,3 +299,105 @@ fn merge_section() {
     assert_eq!(root0.get("foo").unwrap().kind, NodeType::Section);
     assert!(root0.get("foo0").is_none());
 }
+
+#[test]
+fn typed_accessors() {
+    let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+    let root = crashlog.decode(&mut cm);
+
+    let mca = &root["pcore"]["core0"]["thread0"]["thread"]["arch_state"]["mca"];
+    assert_eq!(mca["bank3"]["status"].value(), Some(0xbe000000e1840400));
+    assert_eq!(mca.get_u64("bank3.status").unwrap(), 0xbe000000e1840400);
+    assert!(matches!(
+        mca.get_u64("bank3"),
+        Err(Error::InvalidNodeType(_))
+    ));
+    assert!(matches!(
+        mca.get_u64("bank99.status"),
+        Err(Error::MissingNode(path, ancestor)) if path == "bank99.status" && ancestor.is_empty()
+    ));
+    assert!(matches!(
+        mca.get_bool("bank3.status"),
+        Err(Error::InvalidNodeValue(_, 0xbe000000e1840400))
+    ));
+    assert!(matches!(
+        mca.get_str("bank3.status"),
+        Err(Error::InvalidNodeType(_))
+    ));
+    assert!(matches!(
+        root.try_get_by_path("pcore.core0.thread0.thread.arch_state.mca.bank99.status"),
+        Err(Error::MissingNode(_, ancestor)) if ancestor == "pcore.core0.thread0.thread.arch_state.mca"
+    ));
+
+    let mut node = Node::root();
+    node.add(Node::string("product", "XYZ"));
+    assert_eq!(node.get_str("product").unwrap(), "XYZ");
+}
+
+#[test]
+fn walk() {
+    let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+    let root = crashlog.decode(&mut cm);
+
+    for (path, node) in root.walk() {
+        assert!(std::ptr::eq(root.get_by_path(&path).unwrap(), node));
+    }
+
+    let status = "pcore.core0.thread0.thread.arch_state.mca.bank3.status";
+    let (_, node) = root.walk().find(|(path, _)| path == status).unwrap();
+    assert_eq!(node.value(), Some(0xbe000000e1840400));
+
+    struct Visitor {
+        depth: usize,
+        max_depth: usize,
+        visited: usize,
+    }
+
+    impl NodeVisitor for Visitor {
+        fn enter(&mut self, _path: &str, _node: &Node) -> bool {
+            self.depth += 1;
+            self.max_depth = self.max_depth.max(self.depth);
+            self.visited += 1;
+            true
+        }
+
+        fn leave(&mut self, _path: &str, _node: &Node) {
+            self.depth -= 1;
+        }
+    }
+
+    let mut visitor = Visitor {
+        depth: 0,
+        max_depth: 0,
+        visited: 0,
+    };
+    root.visit(&mut visitor);
+    assert_eq!(visitor.depth, 0);
+    assert_eq!(visitor.visited, root.walk().count());
+    assert_eq!(
+        visitor.max_depth,
+        root.walk()
+            .map(|(path, _)| path.split('.').count())
+            .max()
+            .unwrap()
+    );
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml() {
+    let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let crashlog = CrashLog::from_slice(&data).unwrap();
+    let mut cm = CollateralManager::embedded_tree().unwrap();
+    let root = crashlog.decode(&mut cm);
+
+    let yaml = root.to_yaml().unwrap();
+    assert_eq!(
+        serde_yaml_ng::from_str::<serde_json::Value>(&yaml).unwrap(),
+        serde_json::to_value(&root).unwrap()
+    );
+}
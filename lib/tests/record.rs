@@ -1,7 +1,11 @@
 // Copyright (C) 2025 Intel Corporation
 // SPDX-License-Identifier: MIT
+use intel_crashlog::collateral::{MemoryCollateralTree, PVSS};
 use intel_crashlog::header::{RecordSize, Version};
 use intel_crashlog::prelude::*;
+use intel_crashlog::record::{
+    DecodeDefinition, DecodeDefinitionIssue, DecodeOptions, DecodeWarning, LazyRecord,
+};
 use std::fs;
 use std::path::Path;
 
@@ -74,6 +78,264 @@ foo.ddd.eee;32;8;;0
     assert_eq!(field.kind, NodeType::Field { value: 0x86 });
 }
 
+#[test]
+fn named_values() {
+    let record = Record {
+        header: Header::default(),
+        data: vec![0x01, 0x02],
+        ..Default::default()
+    };
+
+    let csv = "name;offset;size;description;bitfield;values
+foo;0;16;;0;
+.status;0;8;;0;0=OK,1=TIMEOUT
+..error;8;8;;0;0x0=NONE,0x1=PARITY
+..raw;8;8;;0;";
+
+    let root = record.decode_with_csv(csv.as_bytes(), 0).unwrap();
+    let status = root.get_by_path("foo.status").unwrap();
+    assert_eq!(status.kind, NodeType::Field { value: 1 });
+    assert_eq!(status.value_name.as_deref(), Some("TIMEOUT"));
+    let error = root.get_by_path("foo.error").unwrap();
+    assert_eq!(error.kind, NodeType::Field { value: 2 });
+    assert_eq!(error.value_name, None);
+    let raw = root.get_by_path("foo.raw").unwrap();
+    assert_eq!(raw.value_name, None);
+
+    assert_eq!(
+        serde_json::to_value(status).unwrap(),
+        serde_json::json!({ "_value": "0x1", "_name": "TIMEOUT" })
+    );
+
+    let csv = "name;offset;size;description;values
+foo;0;8;;0:OK";
+    assert!(matches!(
+        record.decode_with_csv(csv.as_bytes(), 0),
+        Err(Error::InvalidDecodeDefinition(_))
+    ));
+}
+
+#[test]
+fn conditional_fields() {
+    let record = Record {
+        header: Header::default(),
+        data: vec![0x01, 0xAA, 0xBB],
+        ..Default::default()
+    };
+
+    let csv = "name;offset;size;description;valid_if
+foo;0;24;;
+foo.hdr.mode;0;8;;
+foo.big.value;8;16;;hdr.mode==1
+foo.small.value;8;8;;hdr.mode==0x0
+foo.other;16;8;;hdr.mode!=0
+foo.missing;16;8;;hdr.unknown==0";
+
+    let root = record.decode_with_csv(csv.as_bytes(), 0).unwrap();
+    assert_eq!(root.get_value_by_path("foo.big.value"), Some(0xBBAA));
+    assert_eq!(root.get_by_path("foo.small"), None);
+    assert_eq!(root.get_value_by_path("foo.other"), Some(0xBB));
+    assert_eq!(root.get_by_path("foo.missing"), None);
+
+    let csv = "name;offset;size;description;valid_if
+foo;0;8;;hdr.mode>1";
+    assert!(matches!(
+        record.decode_with_csv(csv.as_bytes(), 0),
+        Err(Error::InvalidDecodeDefinition(_))
+    ));
+}
+
+#[test]
+fn big_endian_fields() {
+    let record = Record {
+        header: Header::default(),
+        data: vec![0x12, 0x34, 0x56, 0x78],
+        ..Default::default()
+    };
+
+    let csv = "name;offset;size;description;endianness
+foo;0;32;;
+foo.le;0;32;;little
+foo.be;0;32;;big
+foo.be16;8;16;;be";
+
+    let root = record.decode_with_csv(csv.as_bytes(), 0).unwrap();
+    assert_eq!(root.get_value_by_path("foo.le"), Some(0x78563412));
+    assert_eq!(root.get_value_by_path("foo.be"), Some(0x12345678));
+    assert_eq!(root.get_value_by_path("foo.be16"), Some(0x3456));
+
+    let csv = "name;offset;size;description;endianness
+foo;0;4;;big";
+    assert!(matches!(
+        record.decode_with_csv(csv.as_bytes(), 0),
+        Err(Error::InvalidDecodeDefinition(_))
+    ));
+}
+
+#[test]
+fn escaped_names() {
+    let record = Record {
+        header: Header::default(),
+        data: vec![0x12, 0x34],
+        ..Default::default()
+    };
+
+    let csv = r"name;offset;size;description;valid_if
+foo;0;16;;
+foo.version\.major;0;8;;
+.minor;8;8;;version\.major==0x12";
+
+    let root = record.decode_with_csv(csv.as_bytes(), 0).unwrap();
+    let foo = root.get("foo").unwrap();
+    assert_eq!(foo.get("version.major").unwrap().value(), Some(0x12));
+    assert_eq!(
+        root.get_value_by_path(r"foo.version\.major.minor"),
+        Some(0x34)
+    );
+
+    let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    assert_eq!(definition.encode(&root).unwrap(), record.data);
+
+    let lazy = LazyRecord::new(&record, definition, 0);
+    assert_eq!(lazy.get_value(r"foo.version\.major.minor"), Some(0x34));
+}
+
+#[test]
+fn string_fields() {
+    let mut data = b"PMC 1.2.3".to_vec();
+    data.extend_from_slice(&[0, 0xFF, 0x2A]);
+    let record = Record {
+        header: Header::default(),
+        data,
+        ..Default::default()
+    };
+
+    let csv = "name;offset;size;description;type
+foo;0;96;;
+foo.version;0;88;;string
+foo.short;0;24;;string
+foo.value;88;8;;uint";
+
+    let root = record.decode_with_csv(csv.as_bytes(), 0).unwrap();
+    assert_eq!(
+        root.get_by_path("foo.version").unwrap().kind,
+        NodeType::String {
+            value: "PMC 1.2.3".into()
+        }
+    );
+    assert_eq!(
+        root.get_by_path("foo.short").unwrap().kind,
+        NodeType::String {
+            value: "PMC".into()
+        }
+    );
+    assert_eq!(root.get_value_by_path("foo.value"), Some(0x2A));
+    assert_eq!(
+        serde_json::to_value(root.get_by_path("foo.version").unwrap()).unwrap(),
+        serde_json::json!("PMC 1.2.3")
+    );
+
+    let csv = "name;offset;size;description;type
+foo;4;8;;string";
+    assert!(matches!(
+        record.decode_with_csv(csv.as_bytes(), 0),
+        Err(Error::InvalidDecodeDefinition(_))
+    ));
+}
+
+#[test]
+fn signed_and_bool_fields() {
+    let record = Record {
+        header: Header::default(),
+        data: vec![0xFE, 0x0F, 0x01],
+        ..Default::default()
+    };
+
+    let csv = "name;offset;size;description;type
+foo;0;24;;
+foo.neg;0;8;;int
+foo.nibble;8;4;;int
+foo.pos;12;4;;int
+foo.flag;16;1;;bool
+foo.off;17;7;;bool";
+
+    let root = record.decode_with_csv(csv.as_bytes(), 0).unwrap();
+    let kind = |path: &str| &root.get_by_path(path).unwrap().kind;
+    assert_eq!(kind("foo.neg"), &NodeType::Signed { value: -2 });
+    assert_eq!(kind("foo.nibble"), &NodeType::Signed { value: -1 });
+    assert_eq!(kind("foo.pos"), &NodeType::Signed { value: 0 });
+    assert_eq!(kind("foo.flag"), &NodeType::Bool { value: true });
+    assert_eq!(kind("foo.off"), &NodeType::Bool { value: false });
+    assert!(root.get_bool("foo.flag").unwrap());
+    assert_eq!(
+        serde_json::to_value(root.get_by_path("foo.neg").unwrap()).unwrap(),
+        serde_json::json!("-2")
+    );
+
+    // Round trip
+    let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    assert_eq!(definition.encode(&root).unwrap(), record.data);
+
+    let mut root = root;
+    root.create_hierarchy("foo.nibble").kind = NodeType::Signed { value: -9 };
+    assert!(matches!(
+        definition.encode(&root),
+        Err(Error::InvalidNodeValue(_, _))
+    ));
+
+    let csv = "name;offset;size;description;type
+foo;0;0;;int";
+    assert!(matches!(
+        record.decode_with_csv(csv.as_bytes(), 0),
+        Err(Error::InvalidDecodeDefinition(_))
+    ));
+}
+
+#[test]
+fn validate_decode_definition() {
+    let csv = "name;offset;size;description;valid_if
+foo;0;64;;
+foo.hdr.mode;0;8;;
+foo.hdr.mode.low;0;4;;
+foo.a;8;8;;hdr.mode==0
+foo.a;8;16;;hdr.mode==1
+foo.b;x;8;;
+foo.c;16;8;;
+foo.d;20;8;;
+foo.c;32;8;;
+foo.e;60;8;;";
+
+    let issues = DecodeDefinition::validate(csv.as_bytes(), Some(8));
+    assert_eq!(
+        issues,
+        vec![
+            DecodeDefinitionIssue::Malformed {
+                line: 7,
+                reason: "Error while parsing integer: invalid digit found in string".into()
+            },
+            DecodeDefinitionIssue::DuplicatePath {
+                line: 10,
+                path: "foo.c".into(),
+                previous: 8
+            },
+            DecodeDefinitionIssue::OutOfBounds {
+                line: 11,
+                path: "foo.e".into(),
+                end_bit: 68,
+                record_bits: 64
+            },
+            DecodeDefinitionIssue::Overlap {
+                line: 9,
+                path: "foo.d".into(),
+                other: 8
+            },
+        ]
+    );
+
+    assert!(DecodeDefinition::validate(csv.as_bytes(), None).len() == 3);
+    assert!(DecodeDefinition::from_csv(csv.as_bytes()).is_err());
+}
+
 #[test]
 fn decode() {
     let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
@@ -91,6 +353,372 @@ fn decode() {
     assert_eq!(version.kind, NodeType::Field { value: 1 });
 }
 
+#[test]
+fn decode_bitfields() {
+    let pvss = PVSS {
+        product: "XYZ".into(),
+        security: "green".into(),
+        ..PVSS::default()
+    };
+    let crashlog = format!("{COLLATERAL_TREE_PATH}/XYZ/all/all/green/crashlog");
+    let target_info = fs::read(format!("{crashlog}/target_info.json")).unwrap();
+    let layout = fs::read_to_string(format!("{crashlog}/decode-defs/MCA/1/layout.csv")).unwrap();
+    let layout = layout.replace(
+        "mca.core0.bank0.status;256;64;;0",
+        "mca.core0.bank0.status;256;64;;mca_status",
+    );
+    let bitfields = "bitfield;bit;name;description
+mca_status;61;uc;Uncorrected error
+mca_status;63;val;Valid";
+
+    let mut tree = MemoryCollateralTree::new();
+    tree.add_item(pvss.clone(), "target_info.json", target_info)
+        .add_item(pvss.clone(), "decode-defs/MCA/1/layout.csv", layout)
+        .add_item(pvss, "decode-defs/MCA/1/bitfields.csv", bitfields);
+    let mut cm = CollateralManager::new(tree).unwrap();
+
+    let data = fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let header = Header::from_slice(&data).unwrap().unwrap();
+    let record = Record {
+        header,
+        data,
+        ..Default::default()
+    };
+
+    let root = record.decode(&mut cm);
+    let status = root.get_value_by_path("mca.core0.bank0.status").unwrap();
+    let valid = root.get_by_path("mca.core0.bank0.status.val").unwrap();
+    assert_eq!(valid.value(), Some(status >> 63));
+    assert_eq!(valid.description, "Valid");
+    let uc = root.get_by_path("mca.core0.bank0.status.uc").unwrap();
+    assert_eq!(uc.value(), Some((status >> 61) & 1));
+    assert!(root.get_by_path("mca.core0.bank0.status.bit0").is_none());
+
+    let record = Record {
+        header: Header::default(),
+        data: vec![0x05],
+        ..Default::default()
+    };
+    let csv = "name;offset;size;description;bitfield
+foo.bar;0;4;;unknown
+foo.baz;0;4;;1";
+    let root = record.decode_with_csv(csv.as_bytes(), 0).unwrap();
+    assert_eq!(root.get_value_by_path("foo.bar.bit0"), Some(1));
+    assert_eq!(root.get_value_by_path("foo.bar.bit1"), Some(0));
+    assert_eq!(root.get_value_by_path("foo.bar.bit2"), Some(1));
+    assert_eq!(root.get_value_by_path("foo.bar.bit3"), Some(0));
+    assert!(root.get_by_path("foo.baz.bit0").is_none());
+}
+
+#[test]
+fn decode_includes() {
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+
+    let data = fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+    let header = Header::from_slice(&data).unwrap().unwrap();
+    let record = Record {
+        header,
+        data,
+        ..Default::default()
+    };
+
+    let root = record.decode(&mut cm);
+    let agent = root
+        .get_by_path("processors.cpu0.io0.crashlog_agent")
+        .unwrap();
+    // Defined in the included decode definition
+    assert_eq!(agent.get_value_by_path("hdr.version.revision"), Some(1));
+    assert!(agent.get_value_by_path("section.module_register").is_some());
+
+    let csv = "name;offset;size;description
+include=common.csv;;;";
+    assert!(matches!(
+        record.decode_with_csv(csv.as_bytes(), 0),
+        Err(Error::InvalidDecodeDefinition(_))
+    ));
+}
+
+#[test]
+fn decode_wide_fields() {
+    let record = Record {
+        header: Header::default(),
+        data: (0..24).collect(),
+        ..Default::default()
+    };
+
+    let csv = "name;offset;size;description;endianness
+foo;0;192;;
+foo.le;0;128;;
+foo.be;128;64;;big
+foo.sec;64;128;;
+foo.sec.bar;64;8;;
+foo.odd;4;100;;";
+    let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    let root = record.decode_with_definition(&definition, 0);
+
+    assert_eq!(
+        root.get_by_path("foo.le").unwrap().kind,
+        NodeType::Bytes {
+            value: (0..16).collect()
+        }
+    );
+    assert_eq!(
+        root.get_by_path("foo.be").unwrap().kind,
+        NodeType::Field {
+            value: 0x1011121314151617
+        }
+    );
+    assert_eq!(root.get_by_path("foo.sec").unwrap().kind, NodeType::Section);
+    assert_eq!(root.get_value_by_path("foo.sec.bar"), Some(0x08));
+    assert_eq!(
+        root.get_by_path("foo.odd").unwrap().kind,
+        NodeType::Bytes {
+            value: vec![
+                0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80, 0x90, 0xa0, 0xb0, 0xc0, 0x00
+            ]
+        }
+    );
+
+    assert_eq!(definition.encode(&root).unwrap(), record.data);
+}
+
+#[test]
+fn decode_raw() {
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    cm.override_layout(
+        "MCA",
+        b"name;offset;size;description\nmca.hdr.version;0;32;\nmca.foo;64;8;".to_vec(),
+    )
+    .unwrap();
+
+    let data = fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let header = Header::from_slice(&data).unwrap().unwrap();
+    let record = Record {
+        header,
+        data,
+        ..Default::default()
+    };
+
+    let root = record.decode(&mut cm);
+    assert!(root.get_by_path("mca.raw").is_none());
+
+    cm.set_decode_options(DecodeOptions {
+        raw: true,
+        ..DecodeOptions::default()
+    });
+    let root = record.decode(&mut cm);
+    let raw = root.get_by_path("mca.raw").unwrap();
+    let hex = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    assert_eq!(raw.children().count(), 2);
+    assert_eq!(
+        raw["0x004"].kind,
+        NodeType::String {
+            value: hex(&record.data[4..8])
+        }
+    );
+    assert_eq!(
+        raw["0x009"].kind,
+        NodeType::String {
+            value: hex(&record.data[9..])
+        }
+    );
+}
+
+#[test]
+fn decode_tolerant() {
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+
+    let data = fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let header = Header::from_slice(&data).unwrap().unwrap();
+    let record = Record {
+        header,
+        data: data[..0x100].to_vec(),
+        ..Default::default()
+    };
+
+    let root = record.decode(&mut cm);
+    let field = root.get_by_path("mca.core2.bank1.status").unwrap();
+    assert_ne!(field.kind, NodeType::Truncated);
+
+    cm.set_decode_options(DecodeOptions {
+        tolerant: true,
+        ..DecodeOptions::default()
+    });
+    let root = record.decode(&mut cm);
+    let field = root.get_by_path("mca.core2.bank1.status").unwrap();
+    assert_eq!(field.kind, NodeType::Truncated);
+    let field = root.get_by_path("mca.core2.bank0.misc").unwrap();
+    assert_eq!(
+        field.kind,
+        NodeType::Field {
+            value: u64::from_le_bytes(data[0xf8..0x100].try_into().unwrap())
+        }
+    );
+    assert_eq!(root.get_value_by_path("mca.hdr.version.revision"), Some(1));
+}
+
+#[test]
+fn decode_lazy() {
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+
+    let data = fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let header = Header::from_slice(&data).unwrap().unwrap();
+    let record = Record {
+        header,
+        data,
+        ..Default::default()
+    };
+
+    let root = record.decode(&mut cm);
+    let lazy = record.decode_lazy(&mut cm).unwrap();
+    assert_eq!(lazy.get_value("mca.hdr.version.revision"), Some(1));
+    assert_eq!(
+        lazy.get("mca.core0.bank0").as_ref(),
+        root.get_by_path("mca.core0.bank0")
+    );
+    assert_eq!(
+        lazy.get("mca.core0.bank0.status.val").as_ref(),
+        root.get_by_path("mca.core0.bank0.status.val")
+    );
+    assert!(lazy.get("mca.core0.bank").is_none());
+
+    let record = Record {
+        header: Header::default(),
+        data: vec![0x01, 0xAA, 0xBB],
+        ..Default::default()
+    };
+
+    let csv = "name;offset;size;description;valid_if
+foo;0;24;;
+foo.hdr.mode;0;8;;
+foo.big.value;8;16;;hdr.mode==1
+foo.small.value;8;8;;hdr.mode==0";
+    let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    let lazy = LazyRecord::new(&record, definition, 0);
+    assert_eq!(lazy.get_value("foo.big.value"), Some(0xBBAA));
+    assert!(lazy.get("foo.small").is_none());
+}
+
+#[test]
+fn decode_with_report() {
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+
+    let mut data = fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let end = data.len() - 4;
+    let sum = data[..end]
+        .chunks(4)
+        .map(|dword| u32::from_le_bytes(dword.try_into().unwrap()))
+        .fold(0u32, |acc, dword| acc.wrapping_add(dword));
+    data[end..].copy_from_slice(&0u32.wrapping_sub(sum).to_le_bytes());
+
+    let header = Header::from_slice(&data).unwrap().unwrap();
+    let mut record = Record {
+        header,
+        data,
+        ..Default::default()
+    };
+
+    let (root, warnings) = record.decode_with_report(&mut cm);
+    assert_eq!(root, record.decode(&mut cm));
+    assert!(warnings.is_empty());
+
+    record.data[0x20] ^= 1;
+    let (_, warnings) = record.decode_with_report(&mut cm);
+    assert!(matches!(warnings[..], [DecodeWarning::InvalidChecksum]));
+
+    record.data.truncate(0x100);
+    let (_, warnings) = record.decode_with_report(&mut cm);
+    assert!(matches!(
+        warnings[0],
+        DecodeWarning::Truncated {
+            expected: 832,
+            actual: 0x100
+        }
+    ));
+
+    // No decode definitions for the PUNIT records
+    record.data[3] = 0x43;
+    record.header = Header::from_slice(&record.data).unwrap().unwrap();
+    let (_, warnings) = record.decode_with_report(&mut cm);
+    assert!(matches!(
+        warnings[0],
+        DecodeWarning::DecodeFailed(Error::MissingDecodeDefinitions(_))
+    ));
+}
+
+#[test]
+fn encode() {
+    let layout = fs::read(
+        Path::new(COLLATERAL_TREE_PATH)
+            .join("XYZ/all/all/green/crashlog/decode-defs/MCA/1/layout.csv"),
+    )
+    .unwrap();
+    let definition = DecodeDefinition::from_csv(&layout).unwrap();
+
+    let data = fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let record = Record {
+        data,
+        ..Default::default()
+    };
+
+    // Round trip
+    let mut root = record.decode_with_definition(&definition, 0);
+    let encoded = Record {
+        data: definition.encode(&root).unwrap(),
+        ..Default::default()
+    };
+    assert_eq!(encoded.decode_with_definition(&definition, 0), root);
+
+    // Patch a field of the record
+    root.create_hierarchy("mca.hdr.version.revision").kind = NodeType::Field { value: 2 };
+    let mut data = record.data.clone();
+    definition.encode_into(&root, &mut data, 0).unwrap();
+    assert_eq!(data[0], 2);
+    assert_eq!(data[1..], record.data[1..]);
+
+    root.create_hierarchy("mca.hdr.version.revision").kind = NodeType::Field { value: 0x100 };
+    assert!(matches!(
+        definition.encode(&root),
+        Err(Error::InvalidNodeValue(_, 0x100))
+    ));
+}
+
+#[test]
+fn decode_bit_offset() {
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+
+    let data = fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    let header = Header::from_slice(&data).unwrap().unwrap();
+    let record = Record {
+        header,
+        data,
+        ..Default::default()
+    };
+
+    let root = record
+        .decode_with_decode_def_bits(&mut cm, "layout.csv", 0, Some(32))
+        .unwrap();
+    assert_eq!(root.get_value_by_path("mca.hdr.version.revision"), Some(1));
+    assert!(root.get_by_path("mca.hdr.record_size").is_none());
+
+    // Shifted by 4 bits
+    let root = record
+        .decode_with_decode_def_bits(&mut cm, "layout.csv", 4, None)
+        .unwrap();
+    let version = u32::from_le_bytes(record.data[0..4].try_into().unwrap()) as u64;
+    let next = record.data[4] as u64;
+    assert_eq!(
+        root.get_value_by_path("mca.hdr.version"),
+        Some((version >> 4) | ((next & 0xf) << 28))
+    );
+}
+
 #[test]
 fn decode_generic() {
     let record = Record {
@@ -183,6 +811,140 @@ fn header_checksum() {
     }
 }
 
+#[test]
+fn compute_checksum() {
+    let data = fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let mut crashlog = CrashLog::from_slice(&data).unwrap();
+
+    for record in crashlog.regions[0].records.iter_mut() {
+        let end = record.data.len();
+        assert_eq!(
+            record.compute_checksum().unwrap().to_le_bytes(),
+            record.data[end - 4..]
+        );
+        record.data[end - 8] ^= 0x5a;
+        assert_eq!(record.checksum(), Some(false));
+    }
+
+    // The corrupted checksums are kept by the rebuild, and only updated on request.
+    crashlog.rebuild().unwrap();
+    for record in crashlog.regions[0].records.iter() {
+        assert_eq!(record.checksum(), Some(false));
+    }
+    let count = crashlog.regions[0].records.len();
+    assert_eq!(crashlog.update_checksums(), count);
+    for record in crashlog.regions[0].records.iter() {
+        assert_eq!(record.checksum(), Some(true));
+    }
+
+    let mca = fs::read("tests/samples/dummy_mca_rev2.crashlog").unwrap();
+    let mut record = Region::from_slice(&mca).unwrap().records.remove(0);
+    assert_eq!(record.compute_checksum(), None);
+    assert!(!record.update_checksum());
+
+    // The record is left unchanged when the checksum cannot be added.
+    let mut short = Record {
+        header: record.header.clone(),
+        data: mca[..4].to_vec(),
+        ..Default::default()
+    };
+    assert!(!short.add_checksum());
+    assert_eq!(short.data, mca[..4]);
+    assert!(!short.header.version.cldic);
+
+    assert!(record.add_checksum());
+    assert!(record.header.version.cldic);
+    assert_eq!(record.checksum(), Some(true));
+
+    // The record can be parsed back.
+    let region = Region::from_slice(&record.data).unwrap();
+    assert_eq!(region.records[0].data, record.data);
+    assert_eq!(region.records[0].checksum(), Some(true));
+}
+
+#[cfg(feature = "compression")]
+#[test]
+fn compressed_payload() {
+    use flate2::Compression;
+    use flate2::write::ZlibEncoder;
+    use intel_crashlog::record::Context;
+    use std::io::Write;
+
+    let mut cm = CollateralManager::file_system_tree(Path::new(COLLATERAL_TREE_PATH)).unwrap();
+    cm.override_layout(
+        "MCA",
+        b"name;offset;size;description\nmca.core0.bank0.status;256;64;".to_vec(),
+    )
+    .unwrap();
+
+    let mut data = fs::read("tests/samples/dummy_mca_rev2.crashlog").unwrap();
+    let header_size = Header::from_slice(&data).unwrap().unwrap().header_size();
+    data[32..40].copy_from_slice(&0xdeadbeef_u64.to_le_bytes());
+
+    // Compresses the payload. The compression is not flagged in the header.
+    let mut encoder = ZlibEncoder::new(data[..header_size].to_vec(), Compression::default());
+    encoder.write_all(&data[header_size..]).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let mut record = Record {
+        header: Header::from_slice(&compressed).unwrap().unwrap(),
+        data: compressed,
+        context: Context {
+            payload_compressed: true,
+            ..Context::default()
+        },
+    };
+    assert_eq!(record.payload(), &record.data[header_size..]);
+    assert_eq!(*record.decompressed_payload().unwrap(), data[header_size..]);
+
+    let root = record.decode(&mut cm);
+    let status = root.get_value_by_path("processors.cpu0.io1.mca.core0.bank0.status");
+    assert_eq!(status, Some(0xdeadbeef));
+
+    let root = record.decode_without_cm();
+    let flag = root.get_value_by_path("processors.cpu0.die1.mca.hdr.payload_compressed");
+    assert_eq!(flag, Some(1));
+
+    // Only the header is decoded if the payload cannot be decompressed.
+    record.data[header_size] ^= 0xff;
+    assert!(record.decompressed().is_err());
+    assert!(record.decompressed_payload().is_err());
+    let (root, warnings) = record.decode_with_report(&mut cm);
+    assert!(matches!(warnings[0], DecodeWarning::DecodeFailed(_)));
+    assert!(root.get_by_path("processors.cpu0.io1.mca.core0").is_none());
+}
+
+#[test]
+fn tor_dump() {
+    let record = Record {
+        header: Header::default(),
+        data: vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88],
+        ..Default::default()
+    };
+
+    // All the entries are decoded without valid field.
+    let csv = "name;offset;size;description
+tor;0;64;TOR dump
+tor.entry;0;24;
+tor.entry.opcode;0;8;
+tor.entry.address;8;16;";
+    let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    let tor = record.decode_tor(&definition).unwrap();
+    assert_eq!(tor.description, "TOR dump");
+    assert_eq!(tor.children().count(), 2);
+    assert_eq!(tor.get_value_by_path("entry1.opcode"), Some(0x44));
+    assert_eq!(tor.get_value_by_path("entry1.address"), Some(0x6655));
+
+    let csv = "name;offset;size;description
+tor;0;64;
+tor.opcode;0;8;";
+    let definition = DecodeDefinition::from_csv(csv.as_bytes()).unwrap();
+    assert!(matches!(
+        record.decode_tor(&definition),
+        Err(Error::InvalidDecodeDefinition(_))
+    ));
+}
+
 #[test]
 fn invalid_decode_defs() {
     let record = Record {
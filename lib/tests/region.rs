@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: MIT
 
 use intel_crashlog::prelude::*;
+use intel_crashlog::region::RegionBuilder;
 use std::io::Read;
 
 #[test]
@@ -21,3 +22,154 @@ fn empty() {
     let region = Region::from_slice(&[]);
     assert!(region.is_err());
 }
+
+#[test]
+fn parse_metrics() {
+    let record = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+
+    let mut data = record.clone();
+    data.extend_from_slice(&[0x0, 0x0, 0x0, 0x0, 0xef, 0xbe, 0xad, 0xde, 0x42, 0x42]);
+    let region = Region::from_slice(&data).unwrap();
+    assert_eq!(region.metrics.record_bytes, record.len());
+    assert_eq!(region.metrics.termination_markers, 2);
+    assert_eq!(region.metrics.trailing_bytes, 2);
+    assert_eq!(region.metrics.skipped_bytes, 0);
+    assert_eq!(region.metrics.missing_bytes, 0);
+
+    let region = Region::from_slice(&record[..0x20]).unwrap();
+    assert_eq!(region.metrics.record_bytes, 0x20);
+    assert_eq!(region.metrics.missing_bytes, record.len() - 0x20);
+}
+
+#[test]
+fn rebuild_termination_marker() {
+    let record = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+    let mut region = Region::from_slice(&record).unwrap();
+    assert_eq!(region.metrics.termination_markers, 0);
+    assert_eq!(region.to_bytes(), record);
+
+    region.records[0].data.truncate(0x20);
+    region.rebuild();
+    let bytes = region.to_bytes();
+    assert_eq!(bytes.len(), 0x24);
+    assert_eq!(bytes[0x20..], [0x0; 4]);
+
+    let region = Region::from_slice(&bytes).unwrap();
+    assert_eq!(region.records.len(), 1);
+    assert_eq!(region.records[0].header.record_size(), 0x20);
+    assert_eq!(region.metrics.termination_markers, 1);
+    assert_eq!(region.metrics.trailing_bytes, 0);
+    // The termination markers of the parsed regions are not serialized.
+    assert_eq!(region.to_bytes(), bytes[..0x20]);
+}
+
+#[test]
+fn builder() {
+    let mca = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+
+    // The size of the truncated record is regenerated.
+    let builder = RegionBuilder::new()
+        .add_record(&mca[..0x40])
+        .add_record(mca.clone())
+        .size(mca.len() + 0x50);
+    let bytes = builder.to_bytes().unwrap();
+    assert_eq!(bytes.len(), mca.len() + 0x50);
+
+    let region = builder.build().unwrap();
+    assert_eq!(region.records.len(), 2);
+    assert_eq!(region.records[0].header.record_size(), 0x40);
+    assert_eq!(region.records[1].data, mca);
+    assert_eq!(region.metrics.termination_markers, 4);
+    assert_eq!(region.metrics.trailing_bytes, 0);
+
+    let region = RegionBuilder::new()
+        .add_record(mca.clone())
+        .build()
+        .unwrap();
+    assert_eq!(region.metrics.termination_markers, 1);
+
+    assert!(matches!(
+        RegionBuilder::new().add_record(mca.clone()).size(0x40).build(),
+        Err(Error::RegionOverflow(size, 0x40)) if size == mca.len()
+    ));
+    let oversized = [mca.as_slice(), &[0; 0x40000]].concat();
+    assert!(matches!(
+        RegionBuilder::new().add_record(oversized).build(),
+        Err(Error::InvalidRecordSize(size)) if size == mca.len() + 0x40000
+    ));
+    assert!(matches!(
+        RegionBuilder::new().add_record([0u8; 8]).build(),
+        Err(Error::InvalidHeader)
+    ));
+    assert!(matches!(
+        RegionBuilder::new().build(),
+        Err(Error::EmptyRegion)
+    ));
+
+    // The corrupted checksums are only updated on request.
+    let data = std::fs::read("tests/samples/three_strike_timeout.crashlog").unwrap();
+    let mut record = Region::from_slice(&data).unwrap().records.remove(0).data;
+    record[0x20] ^= 0xff;
+    let region = RegionBuilder::new()
+        .add_record(record.clone())
+        .build()
+        .unwrap();
+    assert_eq!(region.records[0].checksum(), Some(false));
+    let region = RegionBuilder::new()
+        .add_record(record)
+        .update_checksums()
+        .build()
+        .unwrap();
+    assert_eq!(region.records[0].checksum(), Some(true));
+}
+
+#[test]
+fn recovery() {
+    let agent = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+    let mca = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+
+    // The second record has a bogus size that overruns the region.
+    let mut corrupted = mca.clone();
+    corrupted[4..6].copy_from_slice(&[0xff, 0xff]);
+    let data = [agent.as_slice(), &corrupted, &mca, &[0; 4]].concat();
+
+    let region = Region::from_slice(&data).unwrap();
+    assert_eq!(region.records.len(), 2);
+    assert!(region.metrics.missing_bytes > 0);
+    assert!(region.metrics.gaps.is_empty());
+
+    let region = Region::from_slice_with_recovery(&data).unwrap();
+    assert_eq!(region.records.len(), 2);
+    assert_eq!(region.records[0].data, agent);
+    assert_eq!(region.records[1].data, mca);
+    assert_eq!(
+        region.metrics.gaps,
+        vec![agent.len()..agent.len() + corrupted.len()]
+    );
+    assert_eq!(region.metrics.skipped_bytes, corrupted.len());
+    assert_eq!(region.metrics.missing_bytes, 0);
+    assert_eq!(region.metrics.termination_markers, 1);
+
+    let crashlog = CrashLog::from_slice_with_recovery(&data).unwrap();
+    assert_eq!(crashlog.regions[0].records.len(), 2);
+    assert_eq!(crashlog.regions[0].metrics.gaps.len(), 1);
+}
+
+#[test]
+fn trailing_garbage() {
+    let mca = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+
+    // Unknown header type in an MCA record that overruns the region
+    let mut overrun = [0; 0x20];
+    overrun[0..8].copy_from_slice(&[0x01, 0x09, 0x07, 0x3e, 0xff, 0xff, 0x00, 0x00]);
+
+    for garbage in [[0xff; 0x20], overrun] {
+        let data = [mca.as_slice(), &garbage].concat();
+        let region = Region::from_slice(&data).unwrap();
+        assert_eq!(region.records.len(), 1);
+        assert_eq!(region.records[0].data, mca);
+        assert_eq!(region.metrics.skipped_bytes, garbage.len());
+
+        assert!(Region::from_slice(&garbage).is_err());
+    }
+}
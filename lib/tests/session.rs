@@ -0,0 +1,53 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use intel_crashlog::prelude::*;
+use intel_crashlog::session::{INDEX_FILE_NAME, Session};
+
+#[test]
+fn write_read() {
+    let dir = std::env::temp_dir().join(format!("crashlog-session-{}", std::process::id()));
+
+    let mut session = Session::default();
+    session.metadata.insert("incident".into(), "1234".into());
+    for (name, source, file) in [
+        ("acpi", "acpi", "tests/samples/dummy.bert"),
+        ("pmt", "pmt", "tests/samples/dummy_mca_rev1.crashlog"),
+    ] {
+        let crashlog = CrashLog::from_slice(&std::fs::read(file).unwrap()).unwrap();
+        session.add(name, source, crashlog);
+    }
+    session.write(&dir).unwrap();
+    assert!(dir.join(INDEX_FILE_NAME).exists());
+
+    let read = Session::read(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(read.metadata.get("incident").unwrap(), "1234");
+    assert_eq!(read.captures.len(), 2);
+    for capture in session.captures.iter() {
+        let other = read.get(&capture.name).unwrap();
+        assert_eq!(other.source, capture.source);
+        assert_eq!(other.crashlog.regions.len(), capture.crashlog.regions.len());
+        for (a, b) in other
+            .crashlog
+            .regions
+            .iter()
+            .zip(capture.crashlog.regions.iter())
+        {
+            assert_eq!(a.records.len(), b.records.len());
+        }
+    }
+}
+
+#[test]
+fn invalid_capture_name() {
+    let dir = std::env::temp_dir().join(format!("crashlog-session-invalid-{}", std::process::id()));
+
+    let mut session = Session::default();
+    let data = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+    session.add("../mca", "pmt", CrashLog::from_slice(&data).unwrap());
+
+    assert!(matches!(session.write(&dir), Err(Error::InvalidSession(_))));
+    assert!(!dir.exists());
+}
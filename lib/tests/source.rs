@@ -0,0 +1,87 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use intel_crashlog::extract::{Availability, ExtractionSource, SourceRegistry, UefiVariable};
+use intel_crashlog::prelude::*;
+
+struct Source {
+    name: &'static str,
+    availability: Availability,
+}
+
+impl ExtractionSource for Source {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn probe(&self) -> Availability {
+        self.availability.clone()
+    }
+
+    fn extract(&self) -> Result<Vec<CrashLog>, Error> {
+        match self.availability {
+            Availability::Available => {
+                let bert = std::fs::read("tests/samples/dummy.bert")?;
+                Ok(vec![CrashLog::from_slice(&bert)?])
+            }
+            _ => Err(Error::NoCrashLogFound),
+        }
+    }
+}
+
+#[test]
+fn registry() {
+    let mut registry = SourceRegistry::empty();
+    registry.register(Source {
+        name: "available",
+        availability: Availability::Available,
+    });
+    registry.register(Source {
+        name: "unavailable",
+        availability: Availability::Unavailable("denied".into()),
+    });
+    registry.register(Source {
+        name: "unsupported",
+        availability: Availability::Unsupported,
+    });
+
+    let availabilities: Vec<(&str, Availability)> = registry
+        .probe_all()
+        .into_iter()
+        .map(|(source, availability)| (source.name(), availability))
+        .collect();
+    assert_eq!(availabilities.len(), 3);
+    assert_eq!(availabilities[0], ("available", Availability::Available));
+    assert_eq!(
+        availabilities[2],
+        ("unsupported", Availability::Unsupported)
+    );
+
+    // The unsupported sources are not extracted.
+    let extracted = registry.extract_all();
+    assert_eq!(extracted.len(), 2);
+    assert_eq!(extracted[0].0.name(), "available");
+    assert_eq!(extracted[0].1.as_ref().unwrap()[0].regions.len(), 2);
+    assert!(matches!(extracted[1].1, Err(Error::NoCrashLogFound)));
+
+    assert!(SourceRegistry::default().sources().count() > 0);
+}
+
+#[test]
+fn uefi_variable() {
+    let variable: UefiVariable = "CrashLog-8be4df61-93ca-11d2-aa0d-00e098032b8c"
+        .parse()
+        .unwrap();
+    assert_eq!(variable.name, "CrashLog");
+    assert_eq!(
+        variable.vendor.to_string(),
+        "8be4df61-93ca-11d2-aa0d-00e098032b8c"
+    );
+
+    assert!(
+        "8be4df61-93ca-11d2-aa0d-00e098032b8c"
+            .parse::<UefiVariable>()
+            .is_err()
+    );
+    assert!("CrashLog-8be4df61".parse::<UefiVariable>().is_err());
+}
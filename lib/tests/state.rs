@@ -0,0 +1,50 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use intel_crashlog::prelude::*;
+use intel_crashlog::state::{ExtractionState, MAX_SEEN};
+
+#[test]
+fn load_save() {
+    let path = std::env::temp_dir().join(format!("crashlog-state-{}.json", std::process::id()));
+    let bert = std::fs::read("tests/samples/dummy.bert").unwrap();
+    let mca = std::fs::read("tests/samples/dummy_mca_rev1.crashlog").unwrap();
+
+    let mut state = ExtractionState::load(&path).unwrap();
+    assert!(state.mark(&CrashLog::from_slice(&bert).unwrap()));
+    state.save(&path).unwrap();
+
+    let mut state = ExtractionState::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(!state.is_new(&CrashLog::from_slice(&bert).unwrap()));
+    let crashlogs = state.filter_new(vec![
+        CrashLog::from_slice(&bert).unwrap(),
+        CrashLog::from_slice(&mca).unwrap(),
+    ]);
+    assert_eq!(crashlogs.len(), 1);
+    assert!(!state.is_new(&crashlogs[0]));
+
+    state.clear();
+    assert!(state.is_new(&crashlogs[0]));
+}
+
+#[test]
+fn bounded() {
+    let agent = std::fs::read("tests/samples/dummy_crashlog_agent_rev1.crashlog").unwrap();
+    let crashlog = |i: usize| {
+        let mut crashlog = CrashLog::from_slice(&agent).unwrap();
+        crashlog.regions[0].records[0].data[0x10..0x18].copy_from_slice(&(i as u64).to_le_bytes());
+        crashlog
+    };
+
+    let mut state = ExtractionState::default();
+    for i in 0..=MAX_SEEN {
+        assert!(state.mark(&crashlog(i)));
+    }
+
+    // The oldest Crash Log is forgotten first.
+    assert!(state.is_new(&crashlog(0)));
+    assert!(!state.is_new(&crashlog(1)));
+    assert!(!state.is_new(&crashlog(MAX_SEEN)));
+}
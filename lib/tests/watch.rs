@@ -0,0 +1,67 @@
+// Copyright (C) 2025 Intel Corporation
+// SPDX-License-Identifier: MIT
+
+use intel_crashlog::extract::{Availability, ExtractionSource, SourceRegistry, Watcher};
+use intel_crashlog::prelude::*;
+use std::time::Duration;
+
+struct Samples;
+
+impl ExtractionSource for Samples {
+    fn name(&self) -> &str {
+        "samples"
+    }
+
+    fn probe(&self) -> Availability {
+        Availability::Available
+    }
+
+    fn extract(&self) -> Result<Vec<CrashLog>, Error> {
+        [
+            "dummy_mca_rev1.crashlog",
+            "dummy_crashlog_agent_rev1.crashlog",
+        ]
+        .iter()
+        .map(|sample| CrashLog::from_slice(&std::fs::read(format!("tests/samples/{sample}"))?))
+        .collect()
+    }
+}
+
+fn watcher() -> Watcher {
+    let mut registry = SourceRegistry::empty();
+    registry.register(Samples);
+    Watcher::new(Duration::from_secs(60)).with_sources(registry)
+}
+
+fn record_type(crashlog: &CrashLog) -> &str {
+    crashlog.regions[0].records[0].header.record_type().unwrap()
+}
+
+#[test]
+fn poll() {
+    let mut watcher = watcher();
+    let crashlogs = watcher.poll();
+    assert_eq!(crashlogs.len(), 2);
+    assert_eq!(crashlogs[0].0, "samples");
+    assert_eq!(record_type(&crashlogs[0].1), "MCA");
+    assert_eq!(record_type(&crashlogs[1].1), "CRASHLOG_AGENT");
+
+    assert!(watcher.poll().is_empty());
+    assert!(!watcher.state().is_new(&crashlogs[1].1));
+}
+
+#[test]
+fn run() {
+    let mut watcher = watcher();
+
+    // The Crash Logs not passed to the callback are reported by the next run.
+    for expected in ["MCA", "CRASHLOG_AGENT"] {
+        let mut delivered = Vec::new();
+        watcher.run(|_, crashlog| {
+            delivered.push(record_type(&crashlog).to_string());
+            false
+        });
+        assert_eq!(delivered, [expected]);
+    }
+    assert!(watcher.poll().is_empty());
+}